@@ -1,5 +1,5 @@
 use clap::Parser;
-use daylight::server;
+use daylight::{classes, fonts, server, sessions, themes};
 use init_tracing_opentelemetry::TracingConfig;
 
 #[derive(Parser)]
@@ -25,6 +25,31 @@ struct Cli {
         default_value = "60000"
     )]
     max_timeout_ms: u64,
+
+    /// A directory of Helix (.toml), VS Code (.json) or TextMate (.tmTheme) themes to serve.
+    #[arg(long, env = "DAYLIGHT_THEMES_DIR")]
+    themes_dir: Option<std::path::PathBuf>,
+
+    /// A monospace TrueType or OpenType font to draw PNG images with, instead of the bundled one.
+    #[arg(long, env = "DAYLIGHT_FONT")]
+    font: Option<std::path::PathBuf>,
+
+    /// How HTML classes are named when a request doesn't say: capture, split, abbreviated,
+    /// pygments, highlightjs or prism.
+    #[arg(long, env = "DAYLIGHT_CLASS_STYLE", default_value = "capture", value_parser = classes::parse_style)]
+    class_style: classes::ClassStyle,
+
+    /// A prefix for every HTML class name when a request doesn't give one, like `dl-`.
+    #[arg(long, env = "DAYLIGHT_CLASS_PREFIX", default_value = "", value_parser = classes::parse_prefix)]
+    class_prefix: String,
+
+    /// How long an editing session may go unused before it's closed.
+    #[arg(long, env = "DAYLIGHT_SESSION_IDLE_TIMEOUT_MS", default_value = "600000")]
+    session_idle_timeout_ms: u64,
+
+    /// Roughly how many megabytes all editing sessions together may take.
+    #[arg(long, env = "DAYLIGHT_SESSION_MEMORY_MB", default_value = "512")]
+    session_memory_mb: usize,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -55,6 +80,26 @@ fn main() -> anyhow::Result<()> {
         };
         let _ = tracing_config.init_subscriber().expect("Couldn't initialize tracing");
 
+        if let Some(dir) = &cli.themes_dir {
+            let loaded = themes::load_directory(dir)?;
+            tracing::info!("Loaded {} themes from {}", loaded.len(), dir.display());
+        }
+
+        if let Some(path) = &cli.font {
+            fonts::load_file(path)?;
+            tracing::info!("Loaded font from {}", path.display());
+        }
+
+        classes::set_default(classes::ClassNames {
+            style: cli.class_style,
+            prefix: cli.class_prefix.clone(),
+        });
+
+        sessions::set_limits(sessions::SessionLimits {
+            idle_timeout: std::time::Duration::from_millis(cli.session_idle_timeout_ms),
+            max_memory: cli.session_memory_mb * 1024 * 1024,
+        });
+
         let default_timeout = tokio::time::Duration::from_millis(cli.default_timeout_ms);
         let max_timeout = tokio::time::Duration::from_millis(cli.max_timeout_ms);
         server::run(cli.port, default_timeout, max_timeout).await
@@ -84,6 +84,7 @@ async fn main() -> anyhow::Result<()> {
                 options: None,
                 language: common::Language::Unspecified, // Auto-detect from extension
                 include_injections: args.include_injections,
+                ..Default::default()
             },
         );
 
@@ -96,6 +97,7 @@ async fn main() -> anyhow::Result<()> {
         &RequestArgs {
             files: Some(files_vec),
             timeout_ms: args.timeout_ms,
+            ..Default::default()
         },
     );
 
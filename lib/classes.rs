@@ -0,0 +1,275 @@
+use std::sync::{LazyLock, RwLock};
+
+use crate::languages::ALL_HIGHLIGHT_NAMES;
+
+pub use crate::daylight_generated::daylight::html::ClassStyle;
+
+/// How highlights are named in `class="..."` attributes, so that stylesheets written for other
+/// highlighters can be used unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassNames {
+    pub style: ClassStyle,
+    /// Prepended to every class name. highlight.js's trailing-underscore modifiers (`function_`)
+    /// are the exception, because highlight.js never prefixes them either.
+    pub prefix: String,
+}
+
+impl Default for ClassNames {
+    fn default() -> Self {
+        ClassNames {
+            style: ClassStyle::Capture,
+            prefix: String::new(),
+        }
+    }
+}
+
+static DEFAULT: LazyLock<RwLock<ClassNames>> = LazyLock::new(Default::default);
+
+/// Set the class names used when a request doesn't choose its own.
+pub fn set_default(names: ClassNames) {
+    *DEFAULT.write().unwrap_or_else(|e| e.into_inner()) = names;
+}
+
+/// The class names used when a request doesn't choose its own.
+pub fn server_default() -> ClassNames {
+    DEFAULT.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Parse a style by name, ignoring case: `capture`, `split`, `abbreviated`, `pygments`,
+/// `highlightjs` or `prism`.
+pub fn parse_style(name: &str) -> Result<ClassStyle, String> {
+    ClassStyle::ENUM_VALUES
+        .iter()
+        .copied()
+        .filter(|style| *style != ClassStyle::ServerDefault)
+        .find(|style| style.variant_name().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+        .ok_or_else(|| format!("unknown class style `{name}`"))
+}
+
+/// Check a class prefix. It goes into `class="..."` attributes and CSS selectors unescaped, so
+/// it has to look like the start of a CSS identifier: `[A-Za-z_-][A-Za-z0-9_-]*`, or be empty.
+pub fn parse_prefix(prefix: &str) -> Result<String, String> {
+    let mut chars = prefix.chars();
+    let valid = chars.next().is_none_or(|c| c.is_ascii_alphabetic() || c == '_' || c == '-')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if valid {
+        Ok(prefix.to_string())
+    } else {
+        Err(format!("invalid class prefix `{prefix}`"))
+    }
+}
+
+impl ClassNames {
+    /// The naming for a request, falling back to the server's for whatever it leaves out.
+    pub fn for_request(style: ClassStyle, prefix: Option<&str>) -> Result<ClassNames, String> {
+        let default = server_default();
+        Ok(ClassNames {
+            style: if style == ClassStyle::ServerDefault { default.style } else { style },
+            prefix: prefix.map_or(Ok(default.prefix), parse_prefix)?,
+        })
+    }
+
+    /// The classes for an index into `ALL_HIGHLIGHT_NAMES`.
+    pub fn classes(&self, highlight: usize) -> Vec<String> {
+        let capture = ALL_HIGHLIGHT_NAMES[highlight];
+        let prefixed = |name: &str| format!("{}{name}", self.prefix);
+        match self.style {
+            ClassStyle::Split => capture.split('.').map(prefixed).collect(),
+            ClassStyle::Abbreviated => {
+                let parts: Vec<&str> = capture.split('.').map(abbreviation).collect();
+                vec![prefixed(&parts.join("-"))]
+            }
+            // A capture that the table doesn't map gets no classes at all, rather than a bare
+            // prefix or a bare `token`.
+            ClassStyle::Pygments => lookup(PYGMENTS, capture).split_whitespace().map(prefixed).collect(),
+            ClassStyle::HighlightJs => {
+                let mut names = lookup(HIGHLIGHT_JS, capture).split_whitespace();
+                let Some(scope) = names.next() else {
+                    return vec![];
+                };
+                let mut classes = vec![prefixed(&format!("hljs-{scope}"))];
+                classes.extend(names.map(String::from));
+                classes
+            }
+            ClassStyle::Prism => {
+                let names = lookup(PRISM, capture);
+                if names.is_empty() {
+                    return vec![];
+                }
+                let mut classes = vec![prefixed("token")];
+                classes.extend(names.split_whitespace().map(prefixed));
+                classes
+            }
+            _ => vec![prefixed(capture)],
+        }
+    }
+
+    /// The `class` attribute value for every capture, in `ALL_HIGHLIGHT_NAMES` order.
+    pub fn attributes(&self) -> Vec<String> {
+        (0..ALL_HIGHLIGHT_NAMES.len()).map(|i| self.classes(i).join(" ")).collect()
+    }
+
+    /// A CSS selector matching the element for a capture, or nothing if it has no classes.
+    pub fn selector(&self, highlight: usize) -> String {
+        self.classes(highlight)
+            .iter()
+            // Capture names contain dots, which have to be escaped to stay part of a single class.
+            .map(|class| format!(".{}", class.replace('.', "\\.")))
+            .collect()
+    }
+}
+
+/// Find a capture's entry in a mapping table, falling back to its dotted parent.
+fn lookup(table: &[(&str, &'static str)], mut capture: &str) -> &'static str {
+    loop {
+        if let Some((_, class)) = table.iter().find(|(name, _)| *name == capture) {
+            return class;
+        }
+        match capture.rsplit_once('.') {
+            Some((parent, _)) => capture = parent,
+            None => return "",
+        }
+    }
+}
+
+fn abbreviation(part: &str) -> &str {
+    match part {
+        "attribute" => "at",
+        "bracket" => "br",
+        "builtin" => "bi",
+        "comment" => "c",
+        "constant" => "const",
+        "constructor" => "ctor",
+        "delimiter" => "delim",
+        "documentation" => "doc",
+        "embedded" => "emb",
+        "error" => "err",
+        "escape" => "esc",
+        "function" => "fn",
+        "include" => "inc",
+        "keyword" => "kw",
+        "label" => "lbl",
+        "macro" => "mac",
+        "method" => "m",
+        "module" => "mod",
+        "number" => "num",
+        "operator" => "op",
+        "parameter" => "param",
+        "property" => "prop",
+        "punctuation" => "p",
+        "regex" => "re",
+        "special" => "sp",
+        "string" => "str",
+        "symbol" => "sym",
+        "type" => "ty",
+        "variable" => "var",
+        part => part,
+    }
+}
+
+/// Pygments token classes, as written by its `HtmlFormatter`.
+static PYGMENTS: &[(&str, &str)] = &[
+    ("attribute", "nd"),
+    ("comment", "c1"),
+    ("comment.documentation", "sd"),
+    ("constant", "no"),
+    ("constant.builtin", "kc"),
+    ("constructor", "nc"),
+    ("delimiter", "p"),
+    ("embedded", "x"),
+    ("escape", "se"),
+    ("function", "nf"),
+    ("function.builtin", "nb"),
+    ("function.macro", "fm"),
+    ("function.method.builtin", "nb"),
+    ("function.special", "fm"),
+    ("include", "kn"),
+    ("keyword", "k"),
+    ("label", "nl"),
+    ("module", "nn"),
+    ("number", "m"),
+    ("operator", "o"),
+    ("property", "py"),
+    ("punctuation", "p"),
+    ("string", "s2"),
+    ("string.escape", "se"),
+    ("string.special", "ss"),
+    ("string.special.key", "nt"),
+    ("string.special.regex", "sr"),
+    ("tag", "nt"),
+    ("tag.error", "err"),
+    ("type", "nc"),
+    ("type.builtin", "kt"),
+    ("variable", "n"),
+    ("variable.builtin", "bp"),
+];
+
+/// highlight.js scopes, followed by any modifiers.
+static HIGHLIGHT_JS: &[(&str, &str)] = &[
+    ("attribute", "meta"),
+    ("comment", "comment"),
+    ("comment.documentation", "doctag"),
+    ("constant", "variable constant_"),
+    ("constant.builtin", "literal"),
+    ("constant.macro", "meta"),
+    ("constructor", "title class_"),
+    ("delimiter", "punctuation"),
+    ("embedded", "subst"),
+    ("escape", "char escape_"),
+    ("function", "title function_"),
+    ("function.builtin", "built_in"),
+    ("function.method.builtin", "built_in"),
+    ("include", "keyword"),
+    ("keyword", "keyword"),
+    ("label", "symbol"),
+    ("module", "title"),
+    ("number", "number"),
+    ("operator", "operator"),
+    ("property", "property"),
+    ("property.builtin", "built_in"),
+    ("punctuation", "punctuation"),
+    ("string", "string"),
+    ("string.escape", "char escape_"),
+    ("string.special.key", "attr"),
+    ("string.special.regex", "regexp"),
+    ("string.special.symbol", "symbol"),
+    ("tag", "name"),
+    ("type", "type"),
+    ("type.builtin", "built_in"),
+    ("variable", "variable"),
+    ("variable.builtin", "variable language_"),
+    ("variable.parameter", "params"),
+];
+
+/// Prism token types, which follow a `token` class.
+static PRISM: &[(&str, &str)] = &[
+    ("attribute", "attr-name"),
+    ("comment", "comment"),
+    ("constant", "constant"),
+    ("constant.builtin", "boolean"),
+    ("constructor", "class-name"),
+    ("delimiter", "punctuation"),
+    ("embedded", ""),
+    ("escape", "char"),
+    ("function", "function"),
+    ("function.builtin", "builtin"),
+    ("function.macro", "macro property"),
+    ("function.method.builtin", "builtin"),
+    ("include", "keyword"),
+    ("keyword", "keyword"),
+    ("label", "symbol"),
+    ("module", "namespace"),
+    ("number", "number"),
+    ("operator", "operator"),
+    ("property", "property"),
+    ("punctuation", "punctuation"),
+    ("string", "string"),
+    ("string.escape", "char"),
+    ("string.special.key", "property"),
+    ("string.special.regex", "regex"),
+    ("string.special.symbol", "symbol"),
+    ("tag", "tag"),
+    ("type", "class-name"),
+    ("type.builtin", "builtin"),
+    ("variable", "variable"),
+];
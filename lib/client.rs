@@ -27,6 +27,12 @@ pub enum Error {
     TimeoutTooLarge(u128),
 }
 
+impl Default for Client<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> Client<'a> {
     pub fn new() -> Self {
         Self {
@@ -52,6 +58,7 @@ impl<'a> Client<'a> {
             options: None,
             language: language.fb_language,
             include_injections,
+            ..Default::default()
         };
         self.files.push(common::File::create(&mut self.builder, &file))
     }
@@ -66,6 +73,8 @@ impl<'a> Client<'a> {
                     .as_millis()
                     .try_into()
                     .map_err(|_| Error::TimeoutTooLarge(timeout.as_millis()))?,
+                full_document: true,
+                ..Default::default()
             },
         );
         self.builder.finish(request, None);
@@ -111,28 +120,26 @@ pub async fn main(
     let fb_response = flatbuffers::root::<html::Response>(&response_bytes)?;
 
     // Process documents
-    if let Some(documents) = fb_response.documents() {
-        if documents.len() > 0 {
-            let doc = documents.get(0);
-
-            // Check for errors
-            let error_code = doc.error_code();
-            if error_code.0 != 0 {
-                anyhow::bail!("Highlighting failed with error code: {:?}", error_code);
-            }
-
-            // Write to /tmp/${FILENAME}.html line by line
-            let output_path = format!("/tmp/{}.html", filename);
-            let mut file = std::fs::File::create(&output_path)?;
-            if let Some(lines) = doc.lines() {
-                use std::io::Write;
-                for i in 0..lines.len() {
-                    let line = lines.get(i);
-                    file.write_all(line.as_bytes())?;
-                }
-            }
-            println!("Wrote highlighted output to: {}", output_path);
+    if let Some(documents) = fb_response.documents()
+        && !documents.is_empty()
+    {
+        let doc = documents.get(0);
+
+        // Check for errors
+        let error_code = doc.error_code();
+        if error_code.0 != 0 {
+            anyhow::bail!("Highlighting failed with error code: {:?}", error_code);
         }
+
+        // Write to /tmp/${FILENAME}.html, styled by the server's default theme
+        let output_path = format!("/tmp/{}.html", filename);
+        let stylesheet = format!("{}/v1/themes/{}.css", client.url, crate::themes::DEFAULT_THEME);
+        let page = format!(
+            "<!DOCTYPE html>\n<meta charset=\"utf-8\">\n<title>{filename}</title>\n<link rel=\"stylesheet\" href=\"{stylesheet}\">\n{}\n",
+            doc.rendered().unwrap_or_default()
+        );
+        std::fs::write(&output_path, page)?;
+        println!("Wrote highlighted output to: {}", output_path);
     }
 
     Ok(())
@@ -0,0 +1,170 @@
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::{HeaderMap, StatusCode, header};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::errors::FatalError;
+
+/// A wire encoding for requests and responses. Flatbuffers is the default, and the only
+/// encoding whose file contents are highlighted without being copied out of the request.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Encoding {
+    #[default]
+    Flatbuffers,
+    Json,
+    Cbor,
+    MessagePack,
+}
+
+impl Encoding {
+    /// Look up the encoding for a media type, ignoring any parameters (like `charset`).
+    pub fn from_media_type(media_type: &str) -> Option<Self> {
+        let essence = media_type.split(';').next().unwrap_or_default().trim();
+        match essence.to_ascii_lowercase().as_str() {
+            "application/x-flatbuffers" | "application/flatbuffers" | "application/octet-stream" => {
+                Some(Self::Flatbuffers)
+            }
+            "application/json" => Some(Self::Json),
+            "application/cbor" => Some(Self::Cbor),
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Self::MessagePack)
+            }
+            _ => None,
+        }
+    }
+
+    /// The media type sent back in the `Content-Type` of responses in this encoding.
+    pub fn media_type(self) -> &'static str {
+        match self {
+            Self::Flatbuffers => "application/x-flatbuffers",
+            Self::Json => "application/json",
+            Self::Cbor => "application/cbor",
+            Self::MessagePack => "application/msgpack",
+        }
+    }
+
+    /// The encoding of a request body, from its `Content-Type`. Requests without one are
+    /// taken to be Flatbuffers, as they always have been.
+    pub fn of_request(headers: &HeaderMap) -> Result<Self, FatalError> {
+        let Some(content_type) = headers.get(header::CONTENT_TYPE) else {
+            return Ok(Self::Flatbuffers);
+        };
+        let content_type = content_type.to_str().unwrap_or_default();
+        Self::from_media_type(content_type)
+            .ok_or_else(|| FatalError::UnsupportedMediaType(content_type.to_string()))
+    }
+
+    /// The encoding to respond with, from the most preferred supported type in `Accept`.
+    /// Responses use the request's encoding if `Accept` is missing, is a wildcard, or names
+    /// nothing supported.
+    pub fn of_response(headers: &HeaderMap, request: Self) -> Self {
+        accepted(headers)
+            .into_iter()
+            .find_map(Self::from_media_type)
+            .unwrap_or(request)
+    }
+
+    /// Deserialize a request body. Not for use with Flatbuffers, which are read in place.
+    pub fn decode<T: DeserializeOwned>(self, body: &[u8]) -> Result<T, FatalError> {
+        let result = match self {
+            Self::Json => serde_json::from_slice(body).map_err(|e| e.to_string()),
+            Self::Cbor => ciborium::from_reader(body).map_err(|e| e.to_string()),
+            Self::MessagePack => rmp_serde::from_slice(body).map_err(|e| e.to_string()),
+            Self::Flatbuffers => unreachable!("Flatbuffers requests are not deserialized"),
+        };
+        result.map_err(|reason| FatalError::InvalidRequest(self, reason))
+    }
+
+    /// Serialize a response body. Not for use with Flatbuffers, which processors build themselves.
+    pub fn encode<T: Serialize>(self, value: &T) -> Result<axum::response::Response, FatalError> {
+        let result = match self {
+            Self::Json => serde_json::to_vec(value).map_err(|e| e.to_string()),
+            Self::Cbor => {
+                let mut bytes = vec![];
+                ciborium::into_writer(value, &mut bytes).map(|()| bytes).map_err(|e| e.to_string())
+            }
+            Self::MessagePack => rmp_serde::to_vec_named(value).map_err(|e| e.to_string()),
+            Self::Flatbuffers => unreachable!("Flatbuffers responses are not serialized"),
+        };
+        let bytes = result.map_err(|reason| FatalError::EncodeError(self, reason))?;
+        Ok((StatusCode::OK, [(header::CONTENT_TYPE, self.media_type())], Bytes::from(bytes)).into_response())
+    }
+}
+
+/// The media ranges in `Accept`, most preferred first, leaving out those the client refuses.
+fn accepted(headers: &HeaderMap) -> Vec<&str> {
+    let accept = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','));
+    let mut candidates: Vec<(f32, &str)> = accept
+        .map(|media_range| {
+            let quality = media_range
+                .split(';')
+                .skip(1)
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.parse().ok())
+                .unwrap_or(1.0);
+            (quality, media_range)
+        })
+        .filter(|(quality, _)| *quality > 0.0)
+        .collect();
+    // A stable sort keeps the client's order among types of equal preference.
+    candidates.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    candidates.into_iter().map(|(_, media_range)| media_range).collect()
+}
+
+/// A streamed response, where each document is written as soon as it's done instead of all of
+/// them at the end, followed by a trailer summing up the batch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Streaming {
+    /// Size-prefixed Flatbuffers `Frame`s.
+    Flatbuffers,
+    /// JSON frames, one per line.
+    Ndjson,
+    /// JSON frames as server-sent events.
+    EventStream,
+}
+
+impl Streaming {
+    pub fn from_media_type(media_type: &str) -> Option<Self> {
+        let essence = media_type.split(';').next().unwrap_or_default().trim();
+        match essence.to_ascii_lowercase().as_str() {
+            "application/x-flatbuffers-stream" => Some(Self::Flatbuffers),
+            "application/x-ndjson" | "application/jsonl" => Some(Self::Ndjson),
+            "text/event-stream" => Some(Self::EventStream),
+            _ => None,
+        }
+    }
+
+    pub fn media_type(self) -> &'static str {
+        match self {
+            Self::Flatbuffers => "application/x-flatbuffers-stream",
+            Self::Ndjson => "application/x-ndjson",
+            Self::EventStream => "text/event-stream",
+        }
+    }
+
+    /// How to stream a response, if the most preferred supported type in `Accept` is a stream.
+    pub fn of_request(headers: &HeaderMap) -> Option<Self> {
+        accepted(headers).into_iter().find_map(|media_range| {
+            match (Self::from_media_type(media_range), Encoding::from_media_type(media_range)) {
+                (None, None) => None,
+                (streaming, _) => Some(streaming),
+            }
+        })?
+    }
+}
+
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Flatbuffers => "Flatbuffers",
+            Self::Json => "JSON",
+            Self::Cbor => "CBOR",
+            Self::MessagePack => "MessagePack",
+        })
+    }
+}
@@ -7,6 +7,7 @@ use tracing_opentelemetry::OpenTelemetrySpanExt;
 use tree_sitter_highlight as ts;
 
 use crate::daylight_generated::daylight::common;
+use crate::encoding::Encoding;
 
 /// Hard errors (those that fail with a non-200 HTTP error).
 #[derive(Debug, Error)]
@@ -15,11 +16,35 @@ pub enum FatalError {
     DecodeError(#[from] flatbuffers::InvalidFlatbuffer),
     #[error("Timeout too large (max supported: {max}ms)", max = .0.as_millis())]
     TimeoutTooLarge(Duration),
+    #[error("Unknown theme: {0}")]
+    UnknownTheme(String),
+    #[error("Unsupported Content-Type: {0}")]
+    UnsupportedMediaType(String),
+    #[error("Invalid {0} request: {1}")]
+    InvalidRequest(Encoding, String),
+    #[error("Could not encode {0} response: {1}")]
+    EncodeError(Encoding, String),
+    #[error("Unknown or expired session: {0}")]
+    UnknownSession(String),
+    #[error("Invalid edit: {0}")]
+    InvalidEdit(String),
+    #[error("Unknown endpoint: {0}")]
+    UnknownEndpoint(String),
+    #[error("Request ID already in use: {0}")]
+    RequestIdInUse(u64),
+    #[error("Too many requests in flight (max supported: {0})")]
+    TooManyRequests(usize),
 }
 
 impl IntoResponse for FatalError {
     fn into_response(self) -> axum::response::Response {
-        (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+        let status = match self {
+            Self::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::UnknownSession(_) => StatusCode::NOT_FOUND,
+            Self::EncodeError(..) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        };
+        (status, self.to_string()).into_response()
     }
 }
 
@@ -70,14 +95,14 @@ impl From<tokio::task::JoinError> for NonFatalError {
     }
 }
 
-impl Into<common::ErrorCode> for NonFatalError {
-    fn into(self) -> common::ErrorCode {
-        match self {
-            Self::TimedOut | Self::Cancelled => common::ErrorCode::TimedOut,
-            Self::ThreadError | Self::UnknownError => common::ErrorCode::UnknownError,
-            Self::InvalidLanguage => common::ErrorCode::UnknownLanguage,
-            Self::FileTooLarge => common::ErrorCode::FileTooLarge,
-            Self::EmptyFile => common::ErrorCode::NoError,
+impl From<NonFatalError> for common::ErrorCode {
+    fn from(err: NonFatalError) -> Self {
+        match err {
+            NonFatalError::TimedOut | NonFatalError::Cancelled => Self::TimedOut,
+            NonFatalError::ThreadError | NonFatalError::UnknownError => Self::UnknownError,
+            NonFatalError::InvalidLanguage => Self::UnknownLanguage,
+            NonFatalError::FileTooLarge => Self::FileTooLarge,
+            NonFatalError::EmptyFile => Self::NoError,
         }
     }
 }
@@ -0,0 +1,34 @@
+use std::path::Path;
+use std::sync::{LazyLock, RwLock};
+
+use ab_glyph::{FontArc, InvalidFont};
+use thiserror::Error;
+
+/// DejaVu Sans Mono (see `fonts/LICENSE-DejaVu`), used for images unless another font is loaded.
+static BUNDLED: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+
+static FONT: LazyLock<RwLock<FontArc>> = LazyLock::new(|| {
+    RwLock::new(FontArc::try_from_slice(BUNDLED).expect("the bundled font should parse"))
+});
+
+/// Errors encountered while loading font files.
+#[derive(Debug, Error)]
+pub enum FontError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Invalid font: {0}")]
+    Invalid(#[from] InvalidFont),
+}
+
+/// Replace the font used to draw images with a TrueType or OpenType file. It should be
+/// monospaced: every character is laid out in a cell as wide as a space.
+pub fn load_file(path: &Path) -> Result<(), FontError> {
+    let font = FontArc::try_from_vec(std::fs::read(path)?)?;
+    *FONT.write().unwrap_or_else(|e| e.into_inner()) = font;
+    Ok(())
+}
+
+/// The font used to draw images.
+pub fn current() -> FontArc {
+    FONT.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
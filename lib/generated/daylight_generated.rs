@@ -29,10 +29,10 @@ pub mod common {
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_LANGUAGE: u16 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_LANGUAGE: u16 = 16;
+pub const ENUM_MAX_LANGUAGE: u16 = 18;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_LANGUAGE: [Language; 17] = [
+pub const ENUM_VALUES_LANGUAGE: [Language; 19] = [
   Language::Unspecified,
   Language::Agda,
   Language::Bash,
@@ -50,6 +50,8 @@ pub const ENUM_VALUES_LANGUAGE: [Language; 17] = [
   Language::Rust,
   Language::TypeScript,
   Language::Tsx,
+  Language::Markdown,
+  Language::Diff,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -74,9 +76,11 @@ impl Language {
   pub const Rust: Self = Self(14);
   pub const TypeScript: Self = Self(15);
   pub const Tsx: Self = Self(16);
+  pub const Markdown: Self = Self(17);
+  pub const Diff: Self = Self(18);
 
   pub const ENUM_MIN: u16 = 0;
-  pub const ENUM_MAX: u16 = 16;
+  pub const ENUM_MAX: u16 = 18;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::Unspecified,
     Self::Agda,
@@ -95,6 +99,8 @@ impl Language {
     Self::Rust,
     Self::TypeScript,
     Self::Tsx,
+    Self::Markdown,
+    Self::Diff,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -116,6 +122,8 @@ impl Language {
       Self::Rust => Some("Rust"),
       Self::TypeScript => Some("TypeScript"),
       Self::Tsx => Some("Tsx"),
+      Self::Markdown => Some("Markdown"),
+      Self::Diff => Some("Diff"),
       _ => None,
     }
   }
@@ -174,15 +182,16 @@ impl flatbuffers::SimpleToVerifyInSlice for Language {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_ERROR_CODE: i8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_ERROR_CODE: i8 = 4;
+pub const ENUM_MAX_ERROR_CODE: i8 = 5;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_ERROR_CODE: [ErrorCode; 5] = [
+pub const ENUM_VALUES_ERROR_CODE: [ErrorCode; 6] = [
   ErrorCode::NoError,
   ErrorCode::TimedOut,
   ErrorCode::UnknownLanguage,
   ErrorCode::FileTooLarge,
   ErrorCode::UnknownError,
+  ErrorCode::InvalidQuery,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -195,15 +204,19 @@ impl ErrorCode {
   pub const UnknownLanguage: Self = Self(2);
   pub const FileTooLarge: Self = Self(3);
   pub const UnknownError: Self = Self(4);
+  /// The request's query for the file's language doesn't compile. `/v1/query` responses say why
+  /// in their `query_errors`.
+  pub const InvalidQuery: Self = Self(5);
 
   pub const ENUM_MIN: i8 = 0;
-  pub const ENUM_MAX: i8 = 4;
+  pub const ENUM_MAX: i8 = 5;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NoError,
     Self::TimedOut,
     Self::UnknownLanguage,
     Self::FileTooLarge,
     Self::UnknownError,
+    Self::InvalidQuery,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -213,6 +226,7 @@ impl ErrorCode {
       Self::UnknownLanguage => Some("UnknownLanguage"),
       Self::FileTooLarge => Some("FileTooLarge"),
       Self::UnknownError => Some("UnknownError"),
+      Self::InvalidQuery => Some("InvalidQuery"),
       _ => None,
     }
   }
@@ -268,6 +282,99 @@ impl<'a> flatbuffers::Verifiable for ErrorCode {
 }
 
 impl flatbuffers::SimpleToVerifyInSlice for ErrorCode {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_OFFSET_ENCODING: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_OFFSET_ENCODING: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_OFFSET_ENCODING: [OffsetEncoding; 3] = [
+  OffsetEncoding::Utf8,
+  OffsetEncoding::Utf16,
+  OffsetEncoding::Utf32,
+];
+
+/// How positions within a line are counted. These match the LSP `PositionEncodingKind`s.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct OffsetEncoding(pub i8);
+#[allow(non_upper_case_globals)]
+impl OffsetEncoding {
+  /// UTF-8 code units, i.e. bytes.
+  pub const Utf8: Self = Self(0);
+  /// UTF-16 code units, as used by JavaScript, Java and (by default) LSP.
+  pub const Utf16: Self = Self(1);
+  /// Unicode code points.
+  pub const Utf32: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Utf8,
+    Self::Utf16,
+    Self::Utf32,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Utf8 => Some("Utf8"),
+      Self::Utf16 => Some("Utf16"),
+      Self::Utf32 => Some("Utf32"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for OffsetEncoding {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for OffsetEncoding {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for OffsetEncoding {
+    type Output = OffsetEncoding;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for OffsetEncoding {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for OffsetEncoding {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for OffsetEncoding {}
 pub enum FileOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -291,6 +398,8 @@ impl<'a> File<'a> {
   pub const VT_LANGUAGE: flatbuffers::VOffsetT = 10;
   pub const VT_INCLUDE_INJECTIONS: flatbuffers::VOffsetT = 12;
   pub const VT_OPTIONS: flatbuffers::VOffsetT = 14;
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 16;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 18;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -302,6 +411,8 @@ impl<'a> File<'a> {
     args: &'args FileArgs<'args>
   ) -> flatbuffers::WIPOffset<File<'bldr>> {
     let mut builder = FileBuilder::new(_fbb);
+    builder.add_end_line(args.end_line);
+    builder.add_start_line(args.start_line);
     if let Some(x) = args.options { builder.add_options(x); }
     if let Some(x) = args.contents { builder.add_contents(x); }
     if let Some(x) = args.filename { builder.add_filename(x); }
@@ -366,6 +477,24 @@ impl<'a> File<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(File::VT_OPTIONS, None)}
   }
+  /// With `end_line`, a window of lines to return output for, numbered from 1 and inclusive. The
+  /// whole file is still parsed, so the window is highlighted correctly. Zero means the first line.
+  /// Only `/v1/html` and `/v1/spans` honor windows; other endpoints return the whole file.
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(File::VT_START_LINE, Some(0)).unwrap()}
+  }
+  /// The last line of the window. Zero means the last line of the file.
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(File::VT_END_LINE, Some(0)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for File<'_> {
@@ -381,6 +510,8 @@ impl flatbuffers::Verifiable for File<'_> {
      .visit_field::<Language>("language", Self::VT_LANGUAGE, false)?
      .visit_field::<bool>("include_injections", Self::VT_INCLUDE_INJECTIONS, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("options", Self::VT_OPTIONS, false)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
      .finish();
     Ok(())
   }
@@ -392,6 +523,8 @@ pub struct FileArgs<'a> {
     pub language: Language,
     pub include_injections: bool,
     pub options: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub start_line: u32,
+    pub end_line: u32,
 }
 impl<'a> Default for FileArgs<'a> {
   #[inline]
@@ -403,6 +536,8 @@ impl<'a> Default for FileArgs<'a> {
       language: Language::Unspecified,
       include_injections: false,
       options: None,
+      start_line: 0,
+      end_line: 0,
     }
   }
 }
@@ -437,6 +572,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FileBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(File::VT_OPTIONS, options);
   }
   #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(File::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(File::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FileBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     FileBuilder {
@@ -460,411 +603,13098 @@ impl core::fmt::Debug for File<'_> {
       ds.field("language", &self.language());
       ds.field("include_injections", &self.include_injections());
       ds.field("options", &self.options());
+      ds.field("start_line", &self.start_line());
+      ds.field("end_line", &self.end_line());
       ds.finish()
   }
 }
-}  // pub mod common
-
-#[allow(unused_imports, dead_code)]
-pub mod html {
-
-  use core::mem;
-  use core::cmp::Ordering;
-
-  extern crate flatbuffers;
-  use self::flatbuffers::{EndianScalar, Follow};
-
-pub enum RequestOffset {}
+pub enum LineRangeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-/// A request to highlight some files as HTML.
-pub struct Request<'a> {
+/// A range of lines, numbered from 1. Both ends are inclusive.
+pub struct LineRange<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Request<'a> {
-  type Inner = Request<'a>;
+impl<'a> flatbuffers::Follow<'a> for LineRange<'a> {
+  type Inner = LineRange<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Request<'a> {
-  pub const VT_FILES: flatbuffers::VOffsetT = 4;
-  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+impl<'a> LineRange<'a> {
+  pub const VT_START: flatbuffers::VOffsetT = 4;
+  pub const VT_END: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Request { _tab: table }
+    LineRange { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args RequestArgs<'args>
-  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
-    let mut builder = RequestBuilder::new(_fbb);
-    builder.add_timeout_ms(args.timeout_ms);
-    if let Some(x) = args.files { builder.add_files(x); }
+    args: &'args LineRangeArgs
+  ) -> flatbuffers::WIPOffset<LineRange<'bldr>> {
+    let mut builder = LineRangeBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+  pub fn start(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+    unsafe { self._tab.get::<u32>(LineRange::VT_START, Some(0)).unwrap()}
   }
-  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
-  /// Passing a size larger than the server's supported per-file timeout produces a `400 Bad Request`.
   #[inline]
-  pub fn timeout_ms(&self) -> u64 {
+  pub fn end(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u32>(LineRange::VT_END, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Request<'_> {
+impl flatbuffers::Verifiable for LineRange<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
-     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<u32>("start", Self::VT_START, false)?
+     .visit_field::<u32>("end", Self::VT_END, false)?
      .finish();
     Ok(())
   }
 }
-pub struct RequestArgs<'a> {
-    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
-    pub timeout_ms: u64,
+pub struct LineRangeArgs {
+    pub start: u32,
+    pub end: u32,
 }
-impl<'a> Default for RequestArgs<'a> {
+impl<'a> Default for LineRangeArgs {
   #[inline]
   fn default() -> Self {
-    RequestArgs {
-      files: None,
-      timeout_ms: 0,
+    LineRangeArgs {
+      start: 0,
+      end: 0,
     }
   }
 }
 
-pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LineRangeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LineRangeBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  pub fn add_start(&mut self, start: u32) {
+    self.fbb_.push_slot::<u32>(LineRange::VT_START, start, 0);
   }
   #[inline]
-  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
-    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  pub fn add_end(&mut self, end: u32) {
+    self.fbb_.push_slot::<u32>(LineRange::VT_END, end, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LineRangeBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    RequestBuilder {
+    LineRangeBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<LineRange<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Request<'_> {
+impl core::fmt::Debug for LineRange<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Request");
-      ds.field("files", &self.files());
-      ds.field("timeout_ms", &self.timeout_ms());
+    let mut ds = f.debug_struct("LineRange");
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
       ds.finish()
   }
 }
-pub enum ResponseOffset {}
+pub enum RequestOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-/// A response containing either highlighted documents or failures.
-/// Both documents and failures can appear in any order, regardless of how they were ordered in the request.
-pub struct Response<'a> {
+/// The fields every request table starts with, in this order. Each endpoint's Request extends
+/// this with its own options, which lets the server read files and timeouts without knowing
+/// which kind of request it was sent.
+pub struct Request<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Response<'a> {
-  type Inner = Response<'a>;
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Response<'a> {
-  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Response { _tab: table }
+    Request { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
-    let mut builder = ResponseBuilder::new(_fbb);
-    if let Some(x) = args.documents { builder.add_documents(x); }
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<File<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Response<'_> {
+impl flatbuffers::Verifiable for Request<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ResponseArgs<'a> {
-    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<File<'a>>>>>,
+    pub timeout_ms: u64,
 }
-impl<'a> Default for ResponseArgs<'a> {
+impl<'a> Default for RequestArgs<'a> {
   #[inline]
   fn default() -> Self {
-    ResponseArgs {
-      documents: None,
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
     }
   }
 }
 
-pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ResponseBuilder {
+    RequestBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Response<'_> {
+impl core::fmt::Debug for Request<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Response");
-      ds.field("documents", &self.documents());
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
       ds.finish()
   }
 }
-pub enum DocumentOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-/// A successfully highlighted document.
-pub struct Document<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for Document<'a> {
-  type Inner = Document<'a>;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
-  }
-}
+}  // pub mod common
 
-impl<'a> Document<'a> {
-  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
-  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
-  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
-  pub const VT_LINES: flatbuffers::VOffsetT = 10;
-  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+#[allow(unused_imports, dead_code)]
+pub mod html {
 
-  #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Document { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args DocumentArgs<'args>
-  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
-    let mut builder = DocumentBuilder::new(_fbb);
-    if let Some(x) = args.lines { builder.add_lines(x); }
-    if let Some(x) = args.filename { builder.add_filename(x); }
-    builder.add_language(args.language);
-    builder.add_ident(args.ident);
-    builder.add_error_code(args.error_code);
-    builder.finish()
-  }
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_CLASS_STYLE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_CLASS_STYLE: i8 = 6;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CLASS_STYLE: [ClassStyle; 7] = [
+  ClassStyle::ServerDefault,
+  ClassStyle::Capture,
+  ClassStyle::Split,
+  ClassStyle::Abbreviated,
+  ClassStyle::Pygments,
+  ClassStyle::HighlightJs,
+  ClassStyle::Prism,
+];
+
+/// How highlights are named in `class="..."` attributes.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ClassStyle(pub i8);
+#[allow(non_upper_case_globals)]
+impl ClassStyle {
+  /// Whatever the server is configured to use, which is `Capture` unless it says otherwise.
+  pub const ServerDefault: Self = Self(0);
+  /// The capture name as a single class: `class="function.method"`.
+  pub const Capture: Self = Self(1);
+  /// One class per part of the capture name: `class="function method"`.
+  pub const Split: Self = Self(2);
+  /// A short name for each part of the capture name, joined by dashes: `class="fn-m"`.
+  pub const Abbreviated: Self = Self(3);
+  /// The short token classes of Pygments stylesheets: `class="k"`, `class="s2"`, `class="c1"`.
+  pub const Pygments: Self = Self(4);
+  /// highlight.js scopes: `class="hljs-keyword"`, `class="hljs-title function_"`.
+  pub const HighlightJs: Self = Self(5);
+  /// Prism tokens: `class="token keyword"`.
+  pub const Prism: Self = Self(6);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 6;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::ServerDefault,
+    Self::Capture,
+    Self::Split,
+    Self::Abbreviated,
+    Self::Pygments,
+    Self::HighlightJs,
+    Self::Prism,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::ServerDefault => Some("ServerDefault"),
+      Self::Capture => Some("Capture"),
+      Self::Split => Some("Split"),
+      Self::Abbreviated => Some("Abbreviated"),
+      Self::Pygments => Some("Pygments"),
+      Self::HighlightJs => Some("HighlightJs"),
+      Self::Prism => Some("Prism"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ClassStyle {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ClassStyle {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ClassStyle {
+    type Output = ClassStyle;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for ClassStyle {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ClassStyle {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ClassStyle {}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to highlight some files as HTML.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_THEME: flatbuffers::VOffsetT = 8;
+  pub const VT_INLINE_STYLES: flatbuffers::VOffsetT = 10;
+  pub const VT_WRAP_IN_PRE: flatbuffers::VOffsetT = 12;
+  pub const VT_FULL_DOCUMENT: flatbuffers::VOffsetT = 14;
+  pub const VT_EMPHASIZED_LINES: flatbuffers::VOffsetT = 16;
+  pub const VT_CLASS_STYLE: flatbuffers::VOffsetT = 18;
+  pub const VT_CLASS_PREFIX: flatbuffers::VOffsetT = 20;
+  pub const VT_LINK_REFERENCES: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.class_prefix { builder.add_class_prefix(x); }
+    if let Some(x) = args.emphasized_lines { builder.add_emphasized_lines(x); }
+    if let Some(x) = args.theme { builder.add_theme(x); }
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_link_references(args.link_references);
+    builder.add_class_style(args.class_style);
+    builder.add_full_document(args.full_document);
+    builder.add_wrap_in_pre(args.wrap_in_pre);
+    builder.add_inline_styles(args.inline_styles);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  /// Passing a size larger than the server's supported per-file timeout produces a `400 Bad Request`.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// The theme used by `inline_styles` and `wrap_in_pre`. If not provided, the server's default theme is used.
+  /// Passing a theme the server does not know about produces a `400 Bad Request`.
+  #[inline]
+  pub fn theme(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Request::VT_THEME, None)}
+  }
+  /// If true, highlights carry `style="..."` attributes resolved from `theme` instead of `class="..."`
+  /// attributes, for destinations (email, chat, wikis) that strip stylesheets and classes.
+  #[inline]
+  pub fn inline_styles(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_INLINE_STYLES, Some(false)).unwrap()}
+  }
+  /// If true, the first line starts with a `<pre>` tag carrying the theme's foreground and background
+  /// colors and the last line ends with `</pre>`, so the concatenated lines stand on their own.
+  #[inline]
+  pub fn wrap_in_pre(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_WRAP_IN_PRE, Some(false)).unwrap()}
+  }
+  /// If true, each Document's `rendered` field holds a complete `<pre class="daylight"><code>` block
+  /// with a `data-language` attribute, laid out as one row per line: a line-number gutter linking
+  /// to the line's `id="L42"` anchor, then the line itself. `lines` are returned unchanged.
+  #[inline]
+  pub fn full_document(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_FULL_DOCUMENT, Some(false)).unwrap()}
+  }
+  /// Lines of `rendered` to emphasize with an `emphasized` class (or a background, with `inline_styles`).
+  #[inline]
+  pub fn emphasized_lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::LineRange<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::LineRange>>>>(Request::VT_EMPHASIZED_LINES, None)}
+  }
+  /// How `class="..."` attributes are named. Ignored with `inline_styles`.
+  #[inline]
+  pub fn class_style(&self) -> ClassStyle {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ClassStyle>(Request::VT_CLASS_STYLE, Some(ClassStyle::ServerDefault)).unwrap()}
+  }
+  /// A prefix for every class name, like `dl-`. If not provided, the server's default prefix is used.
+  #[inline]
+  pub fn class_prefix(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Request::VT_CLASS_PREFIX, None)}
+  }
+  /// If true, in languages with a locals query, each definition of a local variable is wrapped in
+  /// a `<span id="def-N">` and each reference to it in an `<a href="#def-N">`, so that readers can
+  /// click through to where it was bound. `N` indexes the definitions `/v1/references` returns.
+  #[inline]
+  pub fn link_references(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_LINK_REFERENCES, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("theme", Self::VT_THEME, false)?
+     .visit_field::<bool>("inline_styles", Self::VT_INLINE_STYLES, false)?
+     .visit_field::<bool>("wrap_in_pre", Self::VT_WRAP_IN_PRE, false)?
+     .visit_field::<bool>("full_document", Self::VT_FULL_DOCUMENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::LineRange>>>>("emphasized_lines", Self::VT_EMPHASIZED_LINES, false)?
+     .visit_field::<ClassStyle>("class_style", Self::VT_CLASS_STYLE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("class_prefix", Self::VT_CLASS_PREFIX, false)?
+     .visit_field::<bool>("link_references", Self::VT_LINK_REFERENCES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub theme: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub inline_styles: bool,
+    pub wrap_in_pre: bool,
+    pub full_document: bool,
+    pub emphasized_lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::LineRange<'a>>>>>,
+    pub class_style: ClassStyle,
+    pub class_prefix: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub link_references: bool,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      theme: None,
+      inline_styles: false,
+      wrap_in_pre: false,
+      full_document: false,
+      emphasized_lines: None,
+      class_style: ClassStyle::ServerDefault,
+      class_prefix: None,
+      link_references: false,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_theme(&mut self, theme: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_THEME, theme);
+  }
+  #[inline]
+  pub fn add_inline_styles(&mut self, inline_styles: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_INLINE_STYLES, inline_styles, false);
+  }
+  #[inline]
+  pub fn add_wrap_in_pre(&mut self, wrap_in_pre: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_WRAP_IN_PRE, wrap_in_pre, false);
+  }
+  #[inline]
+  pub fn add_full_document(&mut self, full_document: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_FULL_DOCUMENT, full_document, false);
+  }
+  #[inline]
+  pub fn add_emphasized_lines(&mut self, emphasized_lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::LineRange<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_EMPHASIZED_LINES, emphasized_lines);
+  }
+  #[inline]
+  pub fn add_class_style(&mut self, class_style: ClassStyle) {
+    self.fbb_.push_slot::<ClassStyle>(Request::VT_CLASS_STYLE, class_style, ClassStyle::ServerDefault);
+  }
+  #[inline]
+  pub fn add_class_prefix(&mut self, class_prefix: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_CLASS_PREFIX, class_prefix);
+  }
+  #[inline]
+  pub fn add_link_references(&mut self, link_references: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_LINK_REFERENCES, link_references, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("theme", &self.theme());
+      ds.field("inline_styles", &self.inline_styles());
+      ds.field("wrap_in_pre", &self.wrap_in_pre());
+      ds.field("full_document", &self.full_document());
+      ds.field("emphasized_lines", &self.emphasized_lines());
+      ds.field("class_style", &self.class_style());
+      ds.field("class_prefix", &self.class_prefix());
+      ds.field("link_references", &self.link_references());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing highlighted documents.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A successfully highlighted document.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_LINES: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+  pub const VT_RENDERED: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.rendered { builder.add_rendered(x); }
+    if let Some(x) = args.lines { builder.add_lines(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this highlighted document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// Lines of HTML. If this operation errored, this may be empty, but this is not guaranteed.
+  /// If the file asked for a window of lines, these are that window's lines, starting with `start_line`.
+  #[inline]
+  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Document::VT_LINES, None)}
+  }
+  /// If no error occurred, the code will be NoError. Natch.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+  /// The complete rendered document, if the request asked for `full_document`.
+  #[inline]
+  pub fn rendered(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_RENDERED, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("lines", Self::VT_LINES, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("rendered", Self::VT_RENDERED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub error_code: super::common::ErrorCode,
+    pub rendered: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      lines: None,
+      error_code: super::common::ErrorCode::NoError,
+      rendered: None,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_LINES, lines);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn add_rendered(&mut self, rendered: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_RENDERED, rendered);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("lines", &self.lines());
+      ds.field("error_code", &self.error_code());
+      ds.field("rendered", &self.rendered());
+      ds.finish()
+  }
+}
+}  // pub mod html
+
+#[allow(unused_imports, dead_code)]
+pub mod spans {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to highlight documents returning only byte spans and the class
+/// of the captured highlighting group.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_OFFSET_ENCODING: flatbuffers::VOffsetT = 8;
+  pub const VT_INCLUDE_POSITIONS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_include_positions(args.include_positions);
+    builder.add_offset_encoding(args.offset_encoding);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// How `start`, `end`, columns and `line_starts` are counted. Defaults to UTF-8 bytes.
+  #[inline]
+  pub fn offset_encoding(&self) -> super::common::OffsetEncoding {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::OffsetEncoding>(Request::VT_OFFSET_ENCODING, Some(super::common::OffsetEncoding::Utf8)).unwrap()}
+  }
+  /// If true, each span also carries the line and column of its start and end.
+  #[inline]
+  pub fn include_positions(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_INCLUDE_POSITIONS, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<super::common::OffsetEncoding>("offset_encoding", Self::VT_OFFSET_ENCODING, false)?
+     .visit_field::<bool>("include_positions", Self::VT_INCLUDE_POSITIONS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub offset_encoding: super::common::OffsetEncoding,
+    pub include_positions: bool,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      offset_encoding: super::common::OffsetEncoding::Utf8,
+      include_positions: false,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_offset_encoding(&mut self, offset_encoding: super::common::OffsetEncoding) {
+    self.fbb_.push_slot::<super::common::OffsetEncoding>(Request::VT_OFFSET_ENCODING, offset_encoding, super::common::OffsetEncoding::Utf8);
+  }
+  #[inline]
+  pub fn add_include_positions(&mut self, include_positions: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_INCLUDE_POSITIONS, include_positions, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("offset_encoding", &self.offset_encoding());
+      ds.field("include_positions", &self.include_positions());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing highlighted documents and capture name information.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_HIGHLIGHT_NAMES: flatbuffers::VOffsetT = 4;
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    if let Some(x) = args.highlight_names { builder.add_highlight_names(x); }
+    builder.finish()
+  }
+
+
+  /// The list of configured highlight captures to which this response's
+  /// documents refer. The right for the members of this list to change
+  /// is explicitly reserved.
+  #[inline]
+  pub fn highlight_names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Response::VT_HIGHLIGHT_NAMES, None)}
+  }
+  /// The documents that were highlighted.
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("highlight_names", Self::VT_HIGHLIGHT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub highlight_names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      highlight_names: None,
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_highlight_names(&mut self, highlight_names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_HIGHLIGHT_NAMES, highlight_names);
+  }
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("highlight_names", &self.highlight_names());
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum SpanOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Range and highlight-capture information about a particular identifier.
+pub struct Span<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Span<'a> {
+  type Inner = Span<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Span<'a> {
+  pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+  pub const VT_START: flatbuffers::VOffsetT = 6;
+  pub const VT_END: flatbuffers::VOffsetT = 8;
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 10;
+  pub const VT_START_COLUMN: flatbuffers::VOffsetT = 12;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 14;
+  pub const VT_END_COLUMN: flatbuffers::VOffsetT = 16;
+  pub const VT_SCOPES: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Span { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SpanArgs<'args>
+  ) -> flatbuffers::WIPOffset<Span<'bldr>> {
+    let mut builder = SpanBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    if let Some(x) = args.scopes { builder.add_scopes(x); }
+    builder.add_end_column(args.end_column);
+    builder.add_end_line(args.end_line);
+    builder.add_start_column(args.start_column);
+    builder.add_start_line(args.start_line);
+    builder.add_index(args.index);
+    builder.finish()
+  }
+
+
+  /// An offset into the `highlight_names` array in the Response in which
+  /// this span is present. This is the innermost capture covering the span.
+  #[inline]
+  pub fn index(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Span::VT_INDEX, Some(0)).unwrap()}
+  }
+  /// The start of this highlight, as an offset into its source in the request's `offset_encoding`.
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Span::VT_START, Some(0)).unwrap()}
+  }
+  /// The end of this highlight, as an offset into its source in the request's `offset_encoding`.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Span::VT_END, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `start`. Only set if the request asked for `include_positions`.
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Span::VT_START_LINE, Some(0)).unwrap()}
+  }
+  /// The column of `start` within its line, in the request's `offset_encoding`.
+  #[inline]
+  pub fn start_column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Span::VT_START_COLUMN, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `end`.
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Span::VT_END_LINE, Some(0)).unwrap()}
+  }
+  /// The column of `end` within its line.
+  #[inline]
+  pub fn end_column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Span::VT_END_COLUMN, Some(0)).unwrap()}
+  }
+  /// Every capture enclosing this span, as offsets into `highlight_names`, from outermost to
+  /// innermost. The last entry is always `index`. Nested captures (like `string.escape` inside
+  /// `string`, or anything inside an injected language) appear here rather than being dropped.
+  #[inline]
+  pub fn scopes(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(Span::VT_SCOPES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Span<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("index", Self::VT_INDEX, false)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("start_column", Self::VT_START_COLUMN, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
+     .visit_field::<u32>("end_column", Self::VT_END_COLUMN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("scopes", Self::VT_SCOPES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpanArgs<'a> {
+    pub index: u16,
+    pub start: u64,
+    pub end: u64,
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    pub scopes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+}
+impl<'a> Default for SpanArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpanArgs {
+      index: 0,
+      start: 0,
+      end: 0,
+      start_line: 0,
+      start_column: 0,
+      end_line: 0,
+      end_column: 0,
+      scopes: None,
+    }
+  }
+}
+
+pub struct SpanBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpanBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_index(&mut self, index: u16) {
+    self.fbb_.push_slot::<u16>(Span::VT_INDEX, index, 0);
+  }
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Span::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u64) {
+    self.fbb_.push_slot::<u64>(Span::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(Span::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_start_column(&mut self, start_column: u32) {
+    self.fbb_.push_slot::<u32>(Span::VT_START_COLUMN, start_column, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(Span::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
+  pub fn add_end_column(&mut self, end_column: u32) {
+    self.fbb_.push_slot::<u32>(Span::VT_END_COLUMN, end_column, 0);
+  }
+  #[inline]
+  pub fn add_scopes(&mut self, scopes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Span::VT_SCOPES, scopes);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SpanBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SpanBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Span<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Span<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Span");
+      ds.field("index", &self.index());
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.field("start_line", &self.start_line());
+      ds.field("start_column", &self.start_column());
+      ds.field("end_line", &self.end_line());
+      ds.field("end_column", &self.end_column());
+      ds.field("scopes", &self.scopes());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A document for span-based highlighting.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_SPANS: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+  pub const VT_LINE_STARTS: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.line_starts { builder.add_line_starts(x); }
+    if let Some(x) = args.spans { builder.add_spans(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// A unique numeric identifier used to correlate files in a request with files in a response.
+  /// Often this will be an array index. If two files are passed with the same ident, the behavior
+  /// is undefined. Invariant: for each file in a request, there will be either a Document or a Failure
+  /// with that ident.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// An optional filename for this file.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language used to parse and highlight this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The occurrences of highlighting within the passed document.
+  #[inline]
+  pub fn spans(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span>>>>(Document::VT_SPANS, None)}
+  }
+  /// The error associated with this document,
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+  /// The offset at which each line starts, in the request's `offset_encoding`. Lines end at `\n`;
+  /// the first line always starts at 0. If the file asked for a window of lines, only those lines
+  /// are listed, and only spans touching them are returned, clipped to the window. Offsets still
+  /// count from the file's start.
+  #[inline]
+  pub fn line_starts(&self) -> Option<flatbuffers::Vector<'a, u64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u64>>>(Document::VT_LINE_STARTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Span>>>>("spans", Self::VT_SPANS, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("line_starts", Self::VT_LINE_STARTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub spans: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span<'a>>>>>,
+    pub error_code: super::common::ErrorCode,
+    pub line_starts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      spans: None,
+      error_code: super::common::ErrorCode::NoError,
+      line_starts: None,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_spans(&mut self, spans: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Span<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_SPANS, spans);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn add_line_starts(&mut self, line_starts: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_LINE_STARTS, line_starts);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("spans", &self.spans());
+      ds.field("error_code", &self.error_code());
+      ds.field("line_starts", &self.line_starts());
+      ds.finish()
+  }
+}
+}  // pub mod spans
+
+#[allow(unused_imports, dead_code)]
+pub mod ansi {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_COLOR_MODE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_COLOR_MODE: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_COLOR_MODE: [ColorMode; 3] = [
+  ColorMode::TrueColor,
+  ColorMode::Ansi256,
+  ColorMode::Ansi16,
+];
+
+/// How many colors the receiving terminal can display. Theme colors are
+/// downsampled to the nearest available color for the 256- and 16-color modes.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ColorMode(pub i8);
+#[allow(non_upper_case_globals)]
+impl ColorMode {
+  /// 24-bit `ESC[38;2;R;G;Bm` sequences.
+  pub const TrueColor: Self = Self(0);
+  /// The xterm 256-color palette (`ESC[38;5;Nm`).
+  pub const Ansi256: Self = Self(1);
+  /// The 8 basic and 8 bright ANSI colors (`ESC[3Xm` and `ESC[9Xm`).
+  pub const Ansi16: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::TrueColor,
+    Self::Ansi256,
+    Self::Ansi16,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::TrueColor => Some("TrueColor"),
+      Self::Ansi256 => Some("Ansi256"),
+      Self::Ansi16 => Some("Ansi16"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ColorMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ColorMode {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ColorMode {
+    type Output = ColorMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for ColorMode {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ColorMode {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ColorMode {}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to highlight some files as text containing ANSI escape sequences.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_THEME: flatbuffers::VOffsetT = 8;
+  pub const VT_COLOR_MODE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.theme { builder.add_theme(x); }
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_color_mode(args.color_mode);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// The name of the theme used to pick colors. If not provided, the server's default theme is used.
+  /// Passing a theme the server does not know about produces a `400 Bad Request`.
+  #[inline]
+  pub fn theme(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Request::VT_THEME, None)}
+  }
+  /// The color depth to emit.
+  #[inline]
+  pub fn color_mode(&self) -> ColorMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ColorMode>(Request::VT_COLOR_MODE, Some(ColorMode::TrueColor)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("theme", Self::VT_THEME, false)?
+     .visit_field::<ColorMode>("color_mode", Self::VT_COLOR_MODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub theme: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub color_mode: ColorMode,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      theme: None,
+      color_mode: ColorMode::TrueColor,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_theme(&mut self, theme: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_THEME, theme);
+  }
+  #[inline]
+  pub fn add_color_mode(&mut self, color_mode: ColorMode) {
+    self.fbb_.push_slot::<ColorMode>(Request::VT_COLOR_MODE, color_mode, ColorMode::TrueColor);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("theme", &self.theme());
+      ds.field("color_mode", &self.color_mode());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing highlighted documents.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A document highlighted for display in a terminal.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_LINES: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.lines { builder.add_lines(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this highlighted document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// Lines of escaped text, each terminated by a newline. Every line resets its styles
+  /// before the newline, so lines can be printed independently of one another.
+  #[inline]
+  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Document::VT_LINES, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("lines", Self::VT_LINES, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      lines: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_LINES, lines);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("lines", &self.lines());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod ansi
+
+#[allow(unused_imports, dead_code)]
+pub mod semantic_tokens {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to highlight some files as LSP semantic tokens (`textDocument/semanticTokens/full`).
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_POSITION_ENCODING: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_position_encoding(args.position_encoding);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// The position encoding negotiated with the editor. Defaults to UTF-16, as LSP does.
+  #[inline]
+  pub fn position_encoding(&self) -> super::common::OffsetEncoding {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::OffsetEncoding>(Request::VT_POSITION_ENCODING, Some(super::common::OffsetEncoding::Utf16)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<super::common::OffsetEncoding>("position_encoding", Self::VT_POSITION_ENCODING, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub position_encoding: super::common::OffsetEncoding,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      position_encoding: super::common::OffsetEncoding::Utf16,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_position_encoding(&mut self, position_encoding: super::common::OffsetEncoding) {
+    self.fbb_.push_slot::<super::common::OffsetEncoding>(Request::VT_POSITION_ENCODING, position_encoding, super::common::OffsetEncoding::Utf16);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("position_encoding", &self.position_encoding());
+      ds.finish()
+  }
+}
+pub enum LegendOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The token types and modifiers to announce in the server's `SemanticTokensLegend`.
+pub struct Legend<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Legend<'a> {
+  type Inner = Legend<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Legend<'a> {
+  pub const VT_TOKEN_TYPES: flatbuffers::VOffsetT = 4;
+  pub const VT_TOKEN_MODIFIERS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Legend { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args LegendArgs<'args>
+  ) -> flatbuffers::WIPOffset<Legend<'bldr>> {
+    let mut builder = LegendBuilder::new(_fbb);
+    if let Some(x) = args.token_modifiers { builder.add_token_modifiers(x); }
+    if let Some(x) = args.token_types { builder.add_token_types(x); }
+    builder.finish()
+  }
+
+
+  /// Standard LSP token types; `tokenType` in `data` is an index into this list.
+  #[inline]
+  pub fn token_types(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Legend::VT_TOKEN_TYPES, None)}
+  }
+  /// Standard LSP token modifiers; bit `n` of `tokenModifiers` in `data` refers to entry `n`.
+  #[inline]
+  pub fn token_modifiers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Legend::VT_TOKEN_MODIFIERS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Legend<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("token_types", Self::VT_TOKEN_TYPES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("token_modifiers", Self::VT_TOKEN_MODIFIERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LegendArgs<'a> {
+    pub token_types: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub token_modifiers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for LegendArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LegendArgs {
+      token_types: None,
+      token_modifiers: None,
+    }
+  }
+}
+
+pub struct LegendBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LegendBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_token_types(&mut self, token_types: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Legend::VT_TOKEN_TYPES, token_types);
+  }
+  #[inline]
+  pub fn add_token_modifiers(&mut self, token_modifiers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Legend::VT_TOKEN_MODIFIERS, token_modifiers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LegendBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LegendBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Legend<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Legend<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Legend");
+      ds.field("token_types", &self.token_types());
+      ds.field("token_modifiers", &self.token_modifiers());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing semantic tokens and the legend they are encoded against.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_LEGEND: flatbuffers::VOffsetT = 4;
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    if let Some(x) = args.legend { builder.add_legend(x); }
+    builder.finish()
+  }
+
+
+  /// The legend for every document in this response. It depends only on the server version.
+  #[inline]
+  pub fn legend(&self) -> Option<Legend<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Legend>>(Response::VT_LEGEND, None)}
+  }
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Legend>>("legend", Self::VT_LEGEND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub legend: Option<flatbuffers::WIPOffset<Legend<'a>>>,
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      legend: None,
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_legend(&mut self, legend: flatbuffers::WIPOffset<Legend<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Legend>>(Response::VT_LEGEND, legend);
+  }
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("legend", &self.legend());
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A document's semantic tokens.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_DATA: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.data { builder.add_data(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// Tokens as LSP encodes them: five integers per token, `[deltaLine, deltaStartChar, length,
+  /// tokenType, tokenModifiers]`, relative to the previous token. Tokens never span lines.
+  /// Captures without a standard equivalent (like punctuation) produce no tokens.
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(Document::VT_DATA, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("data", Self::VT_DATA, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      data: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_DATA, data);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("data", &self.data());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod semantic_tokens
+
+#[allow(unused_imports, dead_code)]
+pub mod latex {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_LATEX_STYLE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_LATEX_STYLE: i8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LATEX_STYLE: [LatexStyle; 2] = [
+  LatexStyle::Verbatim,
+  LatexStyle::TextColor,
+];
+
+/// How highlighted lines are meant to be typeset.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct LatexStyle(pub i8);
+#[allow(non_upper_case_globals)]
+impl LatexStyle {
+  /// For a fancyvrb `\begin{Verbatim}[commandchars=\\\{\}]` environment, which keeps whitespace.
+  /// Only backslashes and braces are escaped.
+  pub const Verbatim: Self = Self(0);
+  /// For running text (like `\texttt{...}`), with every LaTeX special character escaped and
+  /// spaces made explicit so indentation survives.
+  pub const TextColor: Self = Self(1);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Verbatim,
+    Self::TextColor,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Verbatim => Some("Verbatim"),
+      Self::TextColor => Some("TextColor"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for LatexStyle {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for LatexStyle {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for LatexStyle {
+    type Output = LatexStyle;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for LatexStyle {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for LatexStyle {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for LatexStyle {}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to highlight some files as LaTeX. Highlights are macros like `\DaylightKeyword{...}`,
+/// defined by the theme preamble at `GET /v1/themes/{name}.tex`.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_STYLE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_style(args.style);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn style(&self) -> LatexStyle {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<LatexStyle>(Request::VT_STYLE, Some(LatexStyle::Verbatim)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<LatexStyle>("style", Self::VT_STYLE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub style: LatexStyle,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      style: LatexStyle::Verbatim,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_style(&mut self, style: LatexStyle) {
+    self.fbb_.push_slot::<LatexStyle>(Request::VT_STYLE, style, LatexStyle::Verbatim);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("style", &self.style());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing highlighted documents.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A document highlighted as LaTeX.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_LINES: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.lines { builder.add_lines(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this highlighted document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// Lines of LaTeX, without line terminators. Every macro opened on a line is closed on it.
+  #[inline]
+  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Document::VT_LINES, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("lines", Self::VT_LINES, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      lines: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_LINES, lines);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("lines", &self.lines());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod latex
+
+#[allow(unused_imports, dead_code)]
+pub mod typst {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to highlight some files as Typst. Highlights are function calls like
+/// `#daylight-keyword[...]`, defined by the theme preamble at `GET /v1/themes/{name}.typ`.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing highlighted documents.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A document highlighted as Typst.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_LINES: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.lines { builder.add_lines(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this highlighted document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// Lines of Typst markup, without line terminators. Every call opened on a line is closed on it,
+  /// and source text is always quoted, so lines can be joined with `\` line breaks.
+  #[inline]
+  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Document::VT_LINES, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("lines", Self::VT_LINES, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      lines: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_LINES, lines);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("lines", &self.lines());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod typst
+
+#[allow(unused_imports, dead_code)]
+pub mod image {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_IMAGE_FORMAT: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_IMAGE_FORMAT: i8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_IMAGE_FORMAT: [ImageFormat; 2] = [
+  ImageFormat::Svg,
+  ImageFormat::Png,
+];
+
+/// The kind of image to draw.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ImageFormat(pub i8);
+#[allow(non_upper_case_globals)]
+impl ImageFormat {
+  /// SVG with a `<text>` element per line. Viewers draw the text with their own monospace font.
+  pub const Svg: Self = Self(0);
+  /// PNG, rasterized by the server with its configured font.
+  pub const Png: Self = Self(1);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Svg,
+    Self::Png,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Svg => Some("Svg"),
+      Self::Png => Some("Png"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ImageFormat {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ImageFormat {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ImageFormat {
+    type Output = ImageFormat;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for ImageFormat {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ImageFormat {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ImageFormat {}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to draw some files as images, like a screenshot of a code editor.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_THEME: flatbuffers::VOffsetT = 8;
+  pub const VT_FORMAT: flatbuffers::VOffsetT = 10;
+  pub const VT_FONT_SIZE: flatbuffers::VOffsetT = 12;
+  pub const VT_PADDING: flatbuffers::VOffsetT = 14;
+  pub const VT_WINDOW_CHROME: flatbuffers::VOffsetT = 16;
+  pub const VT_LINE_NUMBERS: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    builder.add_font_size(args.font_size);
+    if let Some(x) = args.theme { builder.add_theme(x); }
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_padding(args.padding);
+    builder.add_line_numbers(args.line_numbers);
+    builder.add_window_chrome(args.window_chrome);
+    builder.add_format(args.format);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// The name of the theme used to pick colors. If not provided, the server's default theme is used.
+  /// Passing a theme the server does not know about produces a `400 Bad Request`.
+  #[inline]
+  pub fn theme(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Request::VT_THEME, None)}
+  }
+  #[inline]
+  pub fn format(&self) -> ImageFormat {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ImageFormat>(Request::VT_FORMAT, Some(ImageFormat::Svg)).unwrap()}
+  }
+  /// The font size in pixels. Lines are one and a half times as tall.
+  #[inline]
+  pub fn font_size(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Request::VT_FONT_SIZE, Some(14.0)).unwrap()}
+  }
+  /// The space between the code and the edges of the image, in pixels.
+  #[inline]
+  pub fn padding(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Request::VT_PADDING, Some(32)).unwrap()}
+  }
+  /// If set, the code is drawn in a window with rounded corners and a title bar.
+  #[inline]
+  pub fn window_chrome(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_WINDOW_CHROME, Some(false)).unwrap()}
+  }
+  /// If set, lines are numbered in a gutter to the left of the code.
+  #[inline]
+  pub fn line_numbers(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_LINE_NUMBERS, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("theme", Self::VT_THEME, false)?
+     .visit_field::<ImageFormat>("format", Self::VT_FORMAT, false)?
+     .visit_field::<f32>("font_size", Self::VT_FONT_SIZE, false)?
+     .visit_field::<u16>("padding", Self::VT_PADDING, false)?
+     .visit_field::<bool>("window_chrome", Self::VT_WINDOW_CHROME, false)?
+     .visit_field::<bool>("line_numbers", Self::VT_LINE_NUMBERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub theme: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub format: ImageFormat,
+    pub font_size: f32,
+    pub padding: u16,
+    pub window_chrome: bool,
+    pub line_numbers: bool,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      theme: None,
+      format: ImageFormat::Svg,
+      font_size: 14.0,
+      padding: 32,
+      window_chrome: false,
+      line_numbers: false,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_theme(&mut self, theme: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_THEME, theme);
+  }
+  #[inline]
+  pub fn add_format(&mut self, format: ImageFormat) {
+    self.fbb_.push_slot::<ImageFormat>(Request::VT_FORMAT, format, ImageFormat::Svg);
+  }
+  #[inline]
+  pub fn add_font_size(&mut self, font_size: f32) {
+    self.fbb_.push_slot::<f32>(Request::VT_FONT_SIZE, font_size, 14.0);
+  }
+  #[inline]
+  pub fn add_padding(&mut self, padding: u16) {
+    self.fbb_.push_slot::<u16>(Request::VT_PADDING, padding, 32);
+  }
+  #[inline]
+  pub fn add_window_chrome(&mut self, window_chrome: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_WINDOW_CHROME, window_chrome, false);
+  }
+  #[inline]
+  pub fn add_line_numbers(&mut self, line_numbers: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_LINE_NUMBERS, line_numbers, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("theme", &self.theme());
+      ds.field("format", &self.format());
+      ds.field("font_size", &self.font_size());
+      ds.field("padding", &self.padding());
+      ds.field("window_chrome", &self.window_chrome());
+      ds.field("line_numbers", &self.line_numbers());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing images of documents.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// An image of a highlighted document.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_IMAGE: flatbuffers::VOffsetT = 10;
+  pub const VT_WIDTH: flatbuffers::VOffsetT = 12;
+  pub const VT_HEIGHT: flatbuffers::VOffsetT = 14;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    builder.add_height(args.height);
+    builder.add_width(args.width);
+    if let Some(x) = args.image { builder.add_image(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this image.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The encoded SVG or PNG.
+  #[inline]
+  pub fn image(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Document::VT_IMAGE, None)}
+  }
+  /// The image's size in pixels.
+  #[inline]
+  pub fn width(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Document::VT_WIDTH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn height(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Document::VT_HEIGHT, Some(0)).unwrap()}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("image", Self::VT_IMAGE, false)?
+     .visit_field::<u32>("width", Self::VT_WIDTH, false)?
+     .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub image: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub width: u32,
+    pub height: u32,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      image: None,
+      width: 0,
+      height: 0,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_image(&mut self, image: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_IMAGE, image);
+  }
+  #[inline]
+  pub fn add_width(&mut self, width: u32) {
+    self.fbb_.push_slot::<u32>(Document::VT_WIDTH, width, 0);
+  }
+  #[inline]
+  pub fn add_height(&mut self, height: u32) {
+    self.fbb_.push_slot::<u32>(Document::VT_HEIGHT, height, 0);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("image", &self.image());
+      ds.field("width", &self.width());
+      ds.field("height", &self.height());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod image
+
+#[allow(unused_imports, dead_code)]
+pub mod rtf {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to highlight some files as RTF, for pasting into word processors and slides.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_THEME: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.theme { builder.add_theme(x); }
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// The name of the theme used to pick colors. If not provided, the server's default theme is used.
+  /// Passing a theme the server does not know about produces a `400 Bad Request`.
+  #[inline]
+  pub fn theme(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Request::VT_THEME, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("theme", Self::VT_THEME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub theme: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      theme: None,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_theme(&mut self, theme: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_THEME, theme);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("theme", &self.theme());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing highlighted documents.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A document highlighted as RTF.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_RTF: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.rtf { builder.add_rtf(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
 
 
   /// The ident corresponding to the File that produced this highlighted document.
   #[inline]
-  pub fn ident(&self) -> u16 {
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// A complete RTF document, with a color table drawn from the theme. Only ASCII is used;
+  /// other characters are written as `\u` escapes.
+  #[inline]
+  pub fn rtf(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_RTF, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("rtf", Self::VT_RTF, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub rtf: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      rtf: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_rtf(&mut self, rtf: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_RTF, rtf);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("rtf", &self.rtf());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod rtf
+
+#[allow(unused_imports, dead_code)]
+pub mod markdown {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to render the fenced code blocks in some Markdown files as highlighted HTML.
+/// A block's language comes from the first word of its info string (`rust`, `py`, `tsx`...).
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_THEME: flatbuffers::VOffsetT = 8;
+  pub const VT_INLINE_STYLES: flatbuffers::VOffsetT = 10;
+  pub const VT_RENDER_PROSE: flatbuffers::VOffsetT = 12;
+  pub const VT_CLASS_STYLE: flatbuffers::VOffsetT = 14;
+  pub const VT_CLASS_PREFIX: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.class_prefix { builder.add_class_prefix(x); }
+    if let Some(x) = args.theme { builder.add_theme(x); }
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_class_style(args.class_style);
+    builder.add_render_prose(args.render_prose);
+    builder.add_inline_styles(args.inline_styles);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// The name of the theme used for `inline_styles`. If not provided, the server's default theme is used.
+  /// Passing a theme the server does not know about produces a `400 Bad Request`.
+  #[inline]
+  pub fn theme(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Request::VT_THEME, None)}
+  }
+  /// Style highlights with `style=` attributes instead of `class=` attributes, as `/v1/html` does.
+  #[inline]
+  pub fn inline_styles(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_INLINE_STYLES, Some(false)).unwrap()}
+  }
+  /// If set, the prose is rendered to HTML too. Otherwise it is left as Markdown, and each code
+  /// block is replaced by a single-line HTML block that any Markdown renderer passes through.
+  #[inline]
+  pub fn render_prose(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_RENDER_PROSE, Some(false)).unwrap()}
+  }
+  /// How `class="..."` attributes are named, as in `/v1/html`.
+  #[inline]
+  pub fn class_style(&self) -> super::html::ClassStyle {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::html::ClassStyle>(Request::VT_CLASS_STYLE, Some(super::html::ClassStyle::ServerDefault)).unwrap()}
+  }
+  /// A prefix for every class name. If not provided, the server's default prefix is used.
+  #[inline]
+  pub fn class_prefix(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Request::VT_CLASS_PREFIX, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("theme", Self::VT_THEME, false)?
+     .visit_field::<bool>("inline_styles", Self::VT_INLINE_STYLES, false)?
+     .visit_field::<bool>("render_prose", Self::VT_RENDER_PROSE, false)?
+     .visit_field::<super::html::ClassStyle>("class_style", Self::VT_CLASS_STYLE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("class_prefix", Self::VT_CLASS_PREFIX, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub theme: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub inline_styles: bool,
+    pub render_prose: bool,
+    pub class_style: super::html::ClassStyle,
+    pub class_prefix: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      theme: None,
+      inline_styles: false,
+      render_prose: false,
+      class_style: super::html::ClassStyle::ServerDefault,
+      class_prefix: None,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_theme(&mut self, theme: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_THEME, theme);
+  }
+  #[inline]
+  pub fn add_inline_styles(&mut self, inline_styles: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_INLINE_STYLES, inline_styles, false);
+  }
+  #[inline]
+  pub fn add_render_prose(&mut self, render_prose: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_RENDER_PROSE, render_prose, false);
+  }
+  #[inline]
+  pub fn add_class_style(&mut self, class_style: super::html::ClassStyle) {
+    self.fbb_.push_slot::<super::html::ClassStyle>(Request::VT_CLASS_STYLE, class_style, super::html::ClassStyle::ServerDefault);
+  }
+  #[inline]
+  pub fn add_class_prefix(&mut self, class_prefix: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_CLASS_PREFIX, class_prefix);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("theme", &self.theme());
+      ds.field("inline_styles", &self.inline_styles());
+      ds.field("render_prose", &self.render_prose());
+      ds.field("class_style", &self.class_style());
+      ds.field("class_prefix", &self.class_prefix());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing rendered documents.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A Markdown document with its code blocks highlighted.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_RENDERED: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.rendered { builder.add_rendered(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The document, with each fenced code block replaced by a `<pre class="daylight">`. Blocks
+  /// whose language isn't known are escaped, but not highlighted.
+  #[inline]
+  pub fn rendered(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_RENDERED, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("rendered", Self::VT_RENDERED, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub rendered: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      rendered: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_rendered(&mut self, rendered: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_RENDERED, rendered);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("rendered", &self.rendered());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod markdown
+
+#[allow(unused_imports, dead_code)]
+pub mod diff {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_LAYOUT: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_LAYOUT: i8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LAYOUT: [Layout; 2] = [
+  Layout::Unified,
+  Layout::SideBySide,
+];
+
+/// How the lines of a hunk are laid out.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Layout(pub i8);
+#[allow(non_upper_case_globals)]
+impl Layout {
+  /// One column: context lines once, then removed lines, then the lines that replaced them.
+  pub const Unified: Self = Self(0);
+  /// Two columns, as rows pairing old lines with new ones. Either side of a row may be missing.
+  pub const SideBySide: Self = Self(1);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Unified,
+    Self::SideBySide,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Unified => Some("Unified"),
+      Self::SideBySide => Some("SideBySide"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Layout {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Layout {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Layout {
+    type Output = Layout;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for Layout {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Layout {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Layout {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_MARKUP: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_MARKUP: i8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_MARKUP: [Markup; 2] = [
+  Markup::Html,
+  Markup::Spans,
+];
+
+/// What each line of a diff carries.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Markup(pub i8);
+#[allow(non_upper_case_globals)]
+impl Markup {
+  /// `html`, as `/v1/html` lines, with intra-line changes wrapped in `<del>` or `<ins>`.
+  pub const Html: Self = Self(0);
+  /// `text` and `spans`, as `/v1/spans` would give for the line.
+  pub const Spans: Self = Self(1);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Html,
+    Self::Spans,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Html => Some("Html"),
+      Self::Spans => Some("Spans"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Markup {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Markup {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Markup {
+    type Output = Markup;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for Markup {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Markup {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Markup {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_LINE_KIND: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_LINE_KIND: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LINE_KIND: [LineKind; 3] = [
+  LineKind::Context,
+  LineKind::Added,
+  LineKind::Removed,
+];
+
+/// Which side of the diff a line is on.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct LineKind(pub i8);
+#[allow(non_upper_case_globals)]
+impl LineKind {
+  /// Unchanged, and on both sides.
+  pub const Context: Self = Self(0);
+  /// Only in the new file.
+  pub const Added: Self = Self(1);
+  /// Only in the old file.
+  pub const Removed: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Context,
+    Self::Added,
+    Self::Removed,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Context => Some("Context"),
+      Self::Added => Some("Added"),
+      Self::Removed => Some("Removed"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for LineKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for LineKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for LineKind {
+    type Output = LineKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for LineKind {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for LineKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for LineKind {}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to diff old and new versions of some files, with both sides highlighted.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_OLD_FILES: flatbuffers::VOffsetT = 8;
+  pub const VT_LAYOUT: flatbuffers::VOffsetT = 10;
+  pub const VT_MARKUP: flatbuffers::VOffsetT = 12;
+  pub const VT_WORD_DIFF: flatbuffers::VOffsetT = 14;
+  pub const VT_CONTEXT_LINES: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    builder.add_context_lines(args.context_lines);
+    if let Some(x) = args.old_files { builder.add_old_files(x); }
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_word_diff(args.word_diff);
+    builder.add_markup(args.markup);
+    builder.add_layout(args.layout);
+    builder.finish()
+  }
+
+
+  /// The new version of each file. Its filename and language decide how both sides are highlighted.
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// The old version of each file, paired with `files` by ident. A file without an old version
+  /// was added, so all of its lines are, and a file with empty `contents` was deleted. Only
+  /// `contents` is read.
+  #[inline]
+  pub fn old_files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_OLD_FILES, None)}
+  }
+  #[inline]
+  pub fn layout(&self) -> Layout {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Layout>(Request::VT_LAYOUT, Some(Layout::Unified)).unwrap()}
+  }
+  #[inline]
+  pub fn markup(&self) -> Markup {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Markup>(Request::VT_MARKUP, Some(Markup::Html)).unwrap()}
+  }
+  /// If true, lines that replaced each other also carry the words that changed between them.
+  #[inline]
+  pub fn word_diff(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_WORD_DIFF, Some(false)).unwrap()}
+  }
+  /// How many unchanged lines to keep around each change. Negative keeps every line, in a single hunk.
+  #[inline]
+  pub fn context_lines(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(Request::VT_CONTEXT_LINES, Some(-1)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("old_files", Self::VT_OLD_FILES, false)?
+     .visit_field::<Layout>("layout", Self::VT_LAYOUT, false)?
+     .visit_field::<Markup>("markup", Self::VT_MARKUP, false)?
+     .visit_field::<bool>("word_diff", Self::VT_WORD_DIFF, false)?
+     .visit_field::<i32>("context_lines", Self::VT_CONTEXT_LINES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub old_files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub layout: Layout,
+    pub markup: Markup,
+    pub word_diff: bool,
+    pub context_lines: i32,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      old_files: None,
+      layout: Layout::Unified,
+      markup: Markup::Html,
+      word_diff: false,
+      context_lines: -1,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_old_files(&mut self, old_files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_OLD_FILES, old_files);
+  }
+  #[inline]
+  pub fn add_layout(&mut self, layout: Layout) {
+    self.fbb_.push_slot::<Layout>(Request::VT_LAYOUT, layout, Layout::Unified);
+  }
+  #[inline]
+  pub fn add_markup(&mut self, markup: Markup) {
+    self.fbb_.push_slot::<Markup>(Request::VT_MARKUP, markup, Markup::Html);
+  }
+  #[inline]
+  pub fn add_word_diff(&mut self, word_diff: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_WORD_DIFF, word_diff, false);
+  }
+  #[inline]
+  pub fn add_context_lines(&mut self, context_lines: i32) {
+    self.fbb_.push_slot::<i32>(Request::VT_CONTEXT_LINES, context_lines, -1);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("old_files", &self.old_files());
+      ds.field("layout", &self.layout());
+      ds.field("markup", &self.markup());
+      ds.field("word_diff", &self.word_diff());
+      ds.field("context_lines", &self.context_lines());
+      ds.finish()
+  }
+}
+pub enum ByteRangeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A range of bytes within a line.
+pub struct ByteRange<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ByteRange<'a> {
+  type Inner = ByteRange<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> ByteRange<'a> {
+  pub const VT_START: flatbuffers::VOffsetT = 4;
+  pub const VT_END: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ByteRange { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ByteRangeArgs
+  ) -> flatbuffers::WIPOffset<ByteRange<'bldr>> {
+    let mut builder = ByteRangeBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn start(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ByteRange::VT_START, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn end(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ByteRange::VT_END, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ByteRange<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("start", Self::VT_START, false)?
+     .visit_field::<u32>("end", Self::VT_END, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ByteRangeArgs {
+    pub start: u32,
+    pub end: u32,
+}
+impl<'a> Default for ByteRangeArgs {
+  #[inline]
+  fn default() -> Self {
+    ByteRangeArgs {
+      start: 0,
+      end: 0,
+    }
+  }
+}
+
+pub struct ByteRangeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ByteRangeBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_start(&mut self, start: u32) {
+    self.fbb_.push_slot::<u32>(ByteRange::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u32) {
+    self.fbb_.push_slot::<u32>(ByteRange::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ByteRangeBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ByteRangeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ByteRange<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ByteRange<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ByteRange");
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.finish()
+  }
+}
+pub enum LineOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// One line of a diff, highlighted as part of the file it comes from.
+pub struct Line<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Line<'a> {
+  type Inner = Line<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Line<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_OLD_NUMBER: flatbuffers::VOffsetT = 6;
+  pub const VT_NEW_NUMBER: flatbuffers::VOffsetT = 8;
+  pub const VT_HTML: flatbuffers::VOffsetT = 10;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 12;
+  pub const VT_SPANS: flatbuffers::VOffsetT = 14;
+  pub const VT_CHANGES: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Line { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args LineArgs<'args>
+  ) -> flatbuffers::WIPOffset<Line<'bldr>> {
+    let mut builder = LineBuilder::new(_fbb);
+    if let Some(x) = args.changes { builder.add_changes(x); }
+    if let Some(x) = args.spans { builder.add_spans(x); }
+    if let Some(x) = args.text { builder.add_text(x); }
+    if let Some(x) = args.html { builder.add_html(x); }
+    builder.add_new_number(args.new_number);
+    builder.add_old_number(args.old_number);
+    builder.add_kind(args.kind);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn kind(&self) -> LineKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<LineKind>(Line::VT_KIND, Some(LineKind::Context)).unwrap()}
+  }
+  /// The line's number in the old file, from 1, or 0 if it was added.
+  #[inline]
+  pub fn old_number(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Line::VT_OLD_NUMBER, Some(0)).unwrap()}
+  }
+  /// The line's number in the new file, from 1, or 0 if it was removed.
+  #[inline]
+  pub fn new_number(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Line::VT_NEW_NUMBER, Some(0)).unwrap()}
+  }
+  /// The line as HTML, with `Markup.Html`. It doesn't include the line ending.
+  #[inline]
+  pub fn html(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Line::VT_HTML, None)}
+  }
+  /// The line's text, with `Markup.Spans`. It doesn't include the line ending.
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Line::VT_TEXT, None)}
+  }
+  /// Highlights within `text`, with `Markup.Spans`. Offsets are UTF-8 bytes from the start of the line.
+  #[inline]
+  pub fn spans(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::spans::Span<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::spans::Span>>>>(Line::VT_SPANS, None)}
+  }
+  /// The bytes of the line that changed from the line it replaced, with `word_diff`.
+  #[inline]
+  pub fn changes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ByteRange<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ByteRange>>>>(Line::VT_CHANGES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Line<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<LineKind>("kind", Self::VT_KIND, false)?
+     .visit_field::<u32>("old_number", Self::VT_OLD_NUMBER, false)?
+     .visit_field::<u32>("new_number", Self::VT_NEW_NUMBER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("html", Self::VT_HTML, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::spans::Span>>>>("spans", Self::VT_SPANS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ByteRange>>>>("changes", Self::VT_CHANGES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LineArgs<'a> {
+    pub kind: LineKind,
+    pub old_number: u32,
+    pub new_number: u32,
+    pub html: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub spans: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::spans::Span<'a>>>>>,
+    pub changes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ByteRange<'a>>>>>,
+}
+impl<'a> Default for LineArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LineArgs {
+      kind: LineKind::Context,
+      old_number: 0,
+      new_number: 0,
+      html: None,
+      text: None,
+      spans: None,
+      changes: None,
+    }
+  }
+}
+
+pub struct LineBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LineBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: LineKind) {
+    self.fbb_.push_slot::<LineKind>(Line::VT_KIND, kind, LineKind::Context);
+  }
+  #[inline]
+  pub fn add_old_number(&mut self, old_number: u32) {
+    self.fbb_.push_slot::<u32>(Line::VT_OLD_NUMBER, old_number, 0);
+  }
+  #[inline]
+  pub fn add_new_number(&mut self, new_number: u32) {
+    self.fbb_.push_slot::<u32>(Line::VT_NEW_NUMBER, new_number, 0);
+  }
+  #[inline]
+  pub fn add_html(&mut self, html: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Line::VT_HTML, html);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Line::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn add_spans(&mut self, spans: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::spans::Span<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Line::VT_SPANS, spans);
+  }
+  #[inline]
+  pub fn add_changes(&mut self, changes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ByteRange<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Line::VT_CHANGES, changes);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LineBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LineBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Line<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Line<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Line");
+      ds.field("kind", &self.kind());
+      ds.field("old_number", &self.old_number());
+      ds.field("new_number", &self.new_number());
+      ds.field("html", &self.html());
+      ds.field("text", &self.text());
+      ds.field("spans", &self.spans());
+      ds.field("changes", &self.changes());
+      ds.finish()
+  }
+}
+pub enum RowOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A row of a side-by-side diff.
+pub struct Row<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Row<'a> {
+  type Inner = Row<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Row<'a> {
+  pub const VT_OLD: flatbuffers::VOffsetT = 4;
+  pub const VT_NEW: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Row { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RowArgs<'args>
+  ) -> flatbuffers::WIPOffset<Row<'bldr>> {
+    let mut builder = RowBuilder::new(_fbb);
+    if let Some(x) = args.new { builder.add_new(x); }
+    if let Some(x) = args.old { builder.add_old(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn old(&self) -> Option<Line<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Line>>(Row::VT_OLD, None)}
+  }
+  #[inline]
+  pub fn new(&self) -> Option<Line<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Line>>(Row::VT_NEW, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Row<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Line>>("old", Self::VT_OLD, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Line>>("new", Self::VT_NEW, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RowArgs<'a> {
+    pub old: Option<flatbuffers::WIPOffset<Line<'a>>>,
+    pub new: Option<flatbuffers::WIPOffset<Line<'a>>>,
+}
+impl<'a> Default for RowArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RowArgs {
+      old: None,
+      new: None,
+    }
+  }
+}
+
+pub struct RowBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RowBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_old(&mut self, old: flatbuffers::WIPOffset<Line<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Line>>(Row::VT_OLD, old);
+  }
+  #[inline]
+  pub fn add_new(&mut self, new: flatbuffers::WIPOffset<Line<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Line>>(Row::VT_NEW, new);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RowBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RowBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Row<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Row<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Row");
+      ds.field("old", &self.old());
+      ds.field("new", &self.new());
+      ds.finish()
+  }
+}
+pub enum HunkOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A run of changes and the context around them.
+pub struct Hunk<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hunk<'a> {
+  type Inner = Hunk<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Hunk<'a> {
+  pub const VT_OLD_START: flatbuffers::VOffsetT = 4;
+  pub const VT_OLD_LINES: flatbuffers::VOffsetT = 6;
+  pub const VT_NEW_START: flatbuffers::VOffsetT = 8;
+  pub const VT_NEW_LINES: flatbuffers::VOffsetT = 10;
+  pub const VT_LINES: flatbuffers::VOffsetT = 12;
+  pub const VT_ROWS: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Hunk { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args HunkArgs<'args>
+  ) -> flatbuffers::WIPOffset<Hunk<'bldr>> {
+    let mut builder = HunkBuilder::new(_fbb);
+    if let Some(x) = args.rows { builder.add_rows(x); }
+    if let Some(x) = args.lines { builder.add_lines(x); }
+    builder.add_new_lines(args.new_lines);
+    builder.add_new_start(args.new_start);
+    builder.add_old_lines(args.old_lines);
+    builder.add_old_start(args.old_start);
+    builder.finish()
+  }
+
+
+  /// The first old line in this hunk, from 1, and how many there are.
+  #[inline]
+  pub fn old_start(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Hunk::VT_OLD_START, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn old_lines(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Hunk::VT_OLD_LINES, Some(0)).unwrap()}
+  }
+  /// The first new line in this hunk, from 1, and how many there are.
+  #[inline]
+  pub fn new_start(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Hunk::VT_NEW_START, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn new_lines(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Hunk::VT_NEW_LINES, Some(0)).unwrap()}
+  }
+  /// The hunk's lines, with `Layout.Unified`.
+  #[inline]
+  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Line<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Line>>>>(Hunk::VT_LINES, None)}
+  }
+  /// The hunk's rows, with `Layout.SideBySide`.
+  #[inline]
+  pub fn rows(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Row<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Row>>>>(Hunk::VT_ROWS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Hunk<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("old_start", Self::VT_OLD_START, false)?
+     .visit_field::<u32>("old_lines", Self::VT_OLD_LINES, false)?
+     .visit_field::<u32>("new_start", Self::VT_NEW_START, false)?
+     .visit_field::<u32>("new_lines", Self::VT_NEW_LINES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Line>>>>("lines", Self::VT_LINES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Row>>>>("rows", Self::VT_ROWS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HunkArgs<'a> {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Line<'a>>>>>,
+    pub rows: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Row<'a>>>>>,
+}
+impl<'a> Default for HunkArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    HunkArgs {
+      old_start: 0,
+      old_lines: 0,
+      new_start: 0,
+      new_lines: 0,
+      lines: None,
+      rows: None,
+    }
+  }
+}
+
+pub struct HunkBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HunkBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_old_start(&mut self, old_start: u32) {
+    self.fbb_.push_slot::<u32>(Hunk::VT_OLD_START, old_start, 0);
+  }
+  #[inline]
+  pub fn add_old_lines(&mut self, old_lines: u32) {
+    self.fbb_.push_slot::<u32>(Hunk::VT_OLD_LINES, old_lines, 0);
+  }
+  #[inline]
+  pub fn add_new_start(&mut self, new_start: u32) {
+    self.fbb_.push_slot::<u32>(Hunk::VT_NEW_START, new_start, 0);
+  }
+  #[inline]
+  pub fn add_new_lines(&mut self, new_lines: u32) {
+    self.fbb_.push_slot::<u32>(Hunk::VT_NEW_LINES, new_lines, 0);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Line<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hunk::VT_LINES, lines);
+  }
+  #[inline]
+  pub fn add_rows(&mut self, rows: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Row<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hunk::VT_ROWS, rows);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> HunkBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    HunkBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hunk<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Hunk<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Hunk");
+      ds.field("old_start", &self.old_start());
+      ds.field("old_lines", &self.old_lines());
+      ds.field("new_start", &self.new_start());
+      ds.field("new_lines", &self.new_lines());
+      ds.field("lines", &self.lines());
+      ds.field("rows", &self.rows());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing diffed documents.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The diff of one file.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_HUNKS: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.hunks { builder.add_hunks(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The file's hunks. Identical files have none, unless `context_lines` is negative.
+  #[inline]
+  pub fn hunks(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Hunk<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Hunk>>>>(Document::VT_HUNKS, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Hunk>>>>("hunks", Self::VT_HUNKS, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub hunks: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Hunk<'a>>>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      hunks: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_hunks(&mut self, hunks: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Hunk<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_HUNKS, hunks);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("hunks", &self.hunks());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod diff
+
+#[allow(unused_imports, dead_code)]
+pub mod patch {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to highlight unified diffs, like `git diff` or `git format-patch` output. Each File
+/// is a whole patch, which should be named `.diff` or `.patch` or have the `Diff` language.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.finish()
+  }
+}
+pub enum PatchFileOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A file changed by a patch.
+pub struct PatchFile<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PatchFile<'a> {
+  type Inner = PatchFile<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> PatchFile<'a> {
+  pub const VT_OLD_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_NEW_PATH: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PatchFile { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PatchFileArgs<'args>
+  ) -> flatbuffers::WIPOffset<PatchFile<'bldr>> {
+    let mut builder = PatchFileBuilder::new(_fbb);
+    if let Some(x) = args.new_path { builder.add_new_path(x); }
+    if let Some(x) = args.old_path { builder.add_old_path(x); }
+    builder.add_language(args.language);
+    builder.finish()
+  }
+
+
+  /// The path from the `---` header, without its `a/` prefix. Empty for added files.
+  #[inline]
+  pub fn old_path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PatchFile::VT_OLD_PATH, None)}
+  }
+  /// The path from the `+++` header, without its `b/` prefix. Empty for deleted files.
+  #[inline]
+  pub fn new_path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PatchFile::VT_NEW_PATH, None)}
+  }
+  /// The language the file's hunks were highlighted with, from its path. Hunks of files in
+  /// languages the server doesn't know are escaped, but not highlighted.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(PatchFile::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PatchFile<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("old_path", Self::VT_OLD_PATH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("new_path", Self::VT_NEW_PATH, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PatchFileArgs<'a> {
+    pub old_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub new_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+}
+impl<'a> Default for PatchFileArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PatchFileArgs {
+      old_path: None,
+      new_path: None,
+      language: super::common::Language::Unspecified,
+    }
+  }
+}
+
+pub struct PatchFileBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PatchFileBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_old_path(&mut self, old_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PatchFile::VT_OLD_PATH, old_path);
+  }
+  #[inline]
+  pub fn add_new_path(&mut self, new_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PatchFile::VT_NEW_PATH, new_path);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(PatchFile::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PatchFileBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PatchFileBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PatchFile<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PatchFile<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PatchFile");
+      ds.field("old_path", &self.old_path());
+      ds.field("new_path", &self.new_path());
+      ds.field("language", &self.language());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing highlighted patches.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A highlighted patch.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_LINES: flatbuffers::VOffsetT = 10;
+  pub const VT_FILES: flatbuffers::VOffsetT = 12;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.files { builder.add_files(x); }
+    if let Some(x) = args.lines { builder.add_lines(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language the patch was given or inferred as, usually `Diff`.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// A line of HTML for each line of the patch. Lines outside hunks are wrapped in
+  /// `<span class="diff-header">`, hunk headers in `<span class="diff-hunk">`, and
+  /// `\ No newline at end of file` in `<span class="diff-note">`. Each line of a hunk is wrapped
+  /// in `diff-context`, `diff-added` or `diff-removed`, and starts with its prefix in a
+  /// `<span class="diff-marker">`, followed by its code, highlighted as in `/v1/html`.
+  #[inline]
+  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Document::VT_LINES, None)}
+  }
+  /// The files the patch changes, in order.
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PatchFile<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PatchFile>>>>(Document::VT_FILES, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("lines", Self::VT_LINES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PatchFile>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PatchFile<'a>>>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      lines: None,
+      files: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_LINES, lines);
+  }
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PatchFile<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("lines", &self.lines());
+      ds.field("files", &self.files());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod patch
+
+#[allow(unused_imports, dead_code)]
+pub mod tags {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request for the symbols each file defines and refers to, as found by its language's
+/// tree-sitter tags query. Languages without a tags query have no tags.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.finish()
+  }
+}
+pub enum TagOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A symbol defined or referred to in a file.
+pub struct Tag<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Tag<'a> {
+  type Inner = Tag<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Tag<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_IS_DEFINITION: flatbuffers::VOffsetT = 8;
+  pub const VT_START: flatbuffers::VOffsetT = 10;
+  pub const VT_END: flatbuffers::VOffsetT = 12;
+  pub const VT_NAME_START: flatbuffers::VOffsetT = 14;
+  pub const VT_NAME_END: flatbuffers::VOffsetT = 16;
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 18;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 20;
+  pub const VT_DOCS: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Tag { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TagArgs<'args>
+  ) -> flatbuffers::WIPOffset<Tag<'bldr>> {
+    let mut builder = TagBuilder::new(_fbb);
+    builder.add_name_end(args.name_end);
+    builder.add_name_start(args.name_start);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    if let Some(x) = args.docs { builder.add_docs(x); }
+    builder.add_end_line(args.end_line);
+    builder.add_start_line(args.start_line);
+    if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    builder.add_is_definition(args.is_definition);
+    builder.finish()
+  }
+
+
+  /// The kind of symbol, as named by the tags query: `function`, `method`, `class`,
+  /// `interface`, `module`, `macro`, `constant`, `call`, `type` and so on.
+  #[inline]
+  pub fn kind(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Tag::VT_KIND, None)}
+  }
+  /// The symbol's name, as written in the file.
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Tag::VT_NAME, None)}
+  }
+  /// True for a definition, false for a reference.
+  #[inline]
+  pub fn is_definition(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Tag::VT_IS_DEFINITION, Some(false)).unwrap()}
+  }
+  /// The byte offset of the start of the whole definition or reference.
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Tag::VT_START, Some(0)).unwrap()}
+  }
+  /// The byte offset of the end of the whole definition or reference.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Tag::VT_END, Some(0)).unwrap()}
+  }
+  /// The byte offset of the start of the name.
+  #[inline]
+  pub fn name_start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Tag::VT_NAME_START, Some(0)).unwrap()}
+  }
+  /// The byte offset of the end of the name.
+  #[inline]
+  pub fn name_end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Tag::VT_NAME_END, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `start`.
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Tag::VT_START_LINE, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `end`.
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Tag::VT_END_LINE, Some(0)).unwrap()}
+  }
+  /// The comments or docstring documenting a definition, with comment markers stripped and
+  /// lines joined by newlines. Not set if the symbol has none, or its language's query
+  /// doesn't look for them.
+  #[inline]
+  pub fn docs(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Tag::VT_DOCS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Tag<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<bool>("is_definition", Self::VT_IS_DEFINITION, false)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u64>("name_start", Self::VT_NAME_START, false)?
+     .visit_field::<u64>("name_end", Self::VT_NAME_END, false)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("docs", Self::VT_DOCS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TagArgs<'a> {
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub is_definition: bool,
+    pub start: u64,
+    pub end: u64,
+    pub name_start: u64,
+    pub name_end: u64,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub docs: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for TagArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TagArgs {
+      kind: None,
+      name: None,
+      is_definition: false,
+      start: 0,
+      end: 0,
+      name_start: 0,
+      name_end: 0,
+      start_line: 0,
+      end_line: 0,
+      docs: None,
+    }
+  }
+}
+
+pub struct TagBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TagBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Tag::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Tag::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_is_definition(&mut self, is_definition: bool) {
+    self.fbb_.push_slot::<bool>(Tag::VT_IS_DEFINITION, is_definition, false);
+  }
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Tag::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u64) {
+    self.fbb_.push_slot::<u64>(Tag::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn add_name_start(&mut self, name_start: u64) {
+    self.fbb_.push_slot::<u64>(Tag::VT_NAME_START, name_start, 0);
+  }
+  #[inline]
+  pub fn add_name_end(&mut self, name_end: u64) {
+    self.fbb_.push_slot::<u64>(Tag::VT_NAME_END, name_end, 0);
+  }
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(Tag::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(Tag::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
+  pub fn add_docs(&mut self, docs: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Tag::VT_DOCS, docs);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TagBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TagBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Tag<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Tag<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Tag");
+      ds.field("kind", &self.kind());
+      ds.field("name", &self.name());
+      ds.field("is_definition", &self.is_definition());
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.field("name_start", &self.name_start());
+      ds.field("name_end", &self.name_end());
+      ds.field("start_line", &self.start_line());
+      ds.field("end_line", &self.end_line());
+      ds.field("docs", &self.docs());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing the tags of each file.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The tags of a file.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_TAGS: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.tags { builder.add_tags(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language used to parse this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The file's tags, in the order their names appear.
+  #[inline]
+  pub fn tags(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Tag<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Tag>>>>(Document::VT_TAGS, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Tag>>>>("tags", Self::VT_TAGS, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub tags: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Tag<'a>>>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      tags: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_tags(&mut self, tags: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Tag<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_TAGS, tags);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("tags", &self.tags());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod tags
+
+#[allow(unused_imports, dead_code)]
+pub mod folds {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_FOLD_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_FOLD_KIND: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_FOLD_KIND: [FoldKind; 3] = [
+  FoldKind::Region,
+  FoldKind::Comment,
+  FoldKind::Imports,
+];
+
+/// What a folding range holds, matching the kinds of folding ranges in the Language Server
+/// Protocol.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct FoldKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl FoldKind {
+  /// A block, definition, literal or other syntactic region.
+  pub const Region: Self = Self(0);
+  /// A comment, or a run of comments on consecutive lines.
+  pub const Comment: Self = Self(1);
+  /// An import, or a run of imports on consecutive lines.
+  pub const Imports: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Region,
+    Self::Comment,
+    Self::Imports,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Region => Some("Region"),
+      Self::Comment => Some("Comment"),
+      Self::Imports => Some("Imports"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for FoldKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for FoldKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for FoldKind {
+    type Output = FoldKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for FoldKind {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for FoldKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for FoldKind {}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request for the regions of each file that an editor could fold away.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.finish()
+  }
+}
+pub enum FoldOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A region that can be folded away.
+pub struct Fold<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Fold<'a> {
+  type Inner = Fold<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Fold<'a> {
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 4;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 6;
+  pub const VT_KIND: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Fold { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args FoldArgs
+  ) -> flatbuffers::WIPOffset<Fold<'bldr>> {
+    let mut builder = FoldBuilder::new(_fbb);
+    builder.add_end_line(args.end_line);
+    builder.add_start_line(args.start_line);
+    builder.add_kind(args.kind);
+    builder.finish()
+  }
+
+
+  /// The zero-based line the region starts on, which stays visible when it's folded.
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Fold::VT_START_LINE, Some(0)).unwrap()}
+  }
+  /// The zero-based line the region ends on, including any closing bracket. Editors that keep
+  /// the closing bracket visible should fold up to the line before it.
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Fold::VT_END_LINE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> FoldKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<FoldKind>(Fold::VT_KIND, Some(FoldKind::Region)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Fold<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
+     .visit_field::<FoldKind>("kind", Self::VT_KIND, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FoldArgs {
+    pub start_line: u32,
+    pub end_line: u32,
+    pub kind: FoldKind,
+}
+impl<'a> Default for FoldArgs {
+  #[inline]
+  fn default() -> Self {
+    FoldArgs {
+      start_line: 0,
+      end_line: 0,
+      kind: FoldKind::Region,
+    }
+  }
+}
+
+pub struct FoldBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FoldBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(Fold::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(Fold::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: FoldKind) {
+    self.fbb_.push_slot::<FoldKind>(Fold::VT_KIND, kind, FoldKind::Region);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FoldBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    FoldBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Fold<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Fold<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Fold");
+      ds.field("start_line", &self.start_line());
+      ds.field("end_line", &self.end_line());
+      ds.field("kind", &self.kind());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing the folding ranges of each file.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The folding ranges of a file.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_FOLDS: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.folds { builder.add_folds(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language used to parse this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The file's folding ranges, ordered by start line. Each covers at least two lines, and no
+  /// two start on the same line.
+  #[inline]
+  pub fn folds(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Fold<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Fold>>>>(Document::VT_FOLDS, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Fold>>>>("folds", Self::VT_FOLDS, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub folds: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Fold<'a>>>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      folds: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_folds(&mut self, folds: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Fold<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FOLDS, folds);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("folds", &self.folds());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod folds
+
+#[allow(unused_imports, dead_code)]
+pub mod diagnostics {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_DIAGNOSTIC_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_DIAGNOSTIC_KIND: u8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_DIAGNOSTIC_KIND: [DiagnosticKind; 2] = [
+  DiagnosticKind::Error,
+  DiagnosticKind::Missing,
+];
+
+/// How a file failed to parse.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct DiagnosticKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl DiagnosticKind {
+  /// Text the grammar couldn't make sense of, which tree-sitter skipped over.
+  pub const Error: Self = Self(0);
+  /// A token the grammar needed but didn't find, which tree-sitter pretended was there.
+  pub const Missing: Self = Self(1);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Error,
+    Self::Missing,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Error => Some("Error"),
+      Self::Missing => Some("Missing"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for DiagnosticKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for DiagnosticKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for DiagnosticKind {
+    type Output = DiagnosticKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for DiagnosticKind {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for DiagnosticKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for DiagnosticKind {}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request for the places where each file doesn't parse, to flag broken input or a wrongly
+/// detected language before trusting its highlighting.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.finish()
+  }
+}
+pub enum DiagnosticOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A place where a file doesn't parse.
+pub struct Diagnostic<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Diagnostic<'a> {
+  type Inner = Diagnostic<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Diagnostic<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_START: flatbuffers::VOffsetT = 6;
+  pub const VT_END: flatbuffers::VOffsetT = 8;
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 10;
+  pub const VT_START_COLUMN: flatbuffers::VOffsetT = 12;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 14;
+  pub const VT_END_COLUMN: flatbuffers::VOffsetT = 16;
+  pub const VT_EXPECTED: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Diagnostic { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DiagnosticArgs<'args>
+  ) -> flatbuffers::WIPOffset<Diagnostic<'bldr>> {
+    let mut builder = DiagnosticBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    if let Some(x) = args.expected { builder.add_expected(x); }
+    builder.add_end_column(args.end_column);
+    builder.add_end_line(args.end_line);
+    builder.add_start_column(args.start_column);
+    builder.add_start_line(args.start_line);
+    builder.add_kind(args.kind);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn kind(&self) -> DiagnosticKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DiagnosticKind>(Diagnostic::VT_KIND, Some(DiagnosticKind::Error)).unwrap()}
+  }
+  /// The byte offset of the start of the skipped text. For `Missing`, the start and end are the
+  /// same: the place the token should have been.
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Diagnostic::VT_START, Some(0)).unwrap()}
+  }
+  /// The byte offset of the end of the skipped text.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Diagnostic::VT_END, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `start`.
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Diagnostic::VT_START_LINE, Some(0)).unwrap()}
+  }
+  /// The byte column of `start` within its line.
+  #[inline]
+  pub fn start_column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Diagnostic::VT_START_COLUMN, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `end`.
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Diagnostic::VT_END_LINE, Some(0)).unwrap()}
+  }
+  /// The byte column of `end` within its line.
+  #[inline]
+  pub fn end_column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Diagnostic::VT_END_COLUMN, Some(0)).unwrap()}
+  }
+  /// For `Missing`, the kind of the missing node. For `Error`, the tokens the grammar would
+  /// have accepted where the skipped text starts, like `;` or `identifier`. Grammars accept a
+  /// lot in some places, so this can be a long list.
+  #[inline]
+  pub fn expected(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Diagnostic::VT_EXPECTED, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Diagnostic<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<DiagnosticKind>("kind", Self::VT_KIND, false)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("start_column", Self::VT_START_COLUMN, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
+     .visit_field::<u32>("end_column", Self::VT_END_COLUMN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("expected", Self::VT_EXPECTED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DiagnosticArgs<'a> {
+    pub kind: DiagnosticKind,
+    pub start: u64,
+    pub end: u64,
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    pub expected: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for DiagnosticArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DiagnosticArgs {
+      kind: DiagnosticKind::Error,
+      start: 0,
+      end: 0,
+      start_line: 0,
+      start_column: 0,
+      end_line: 0,
+      end_column: 0,
+      expected: None,
+    }
+  }
+}
+
+pub struct DiagnosticBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DiagnosticBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: DiagnosticKind) {
+    self.fbb_.push_slot::<DiagnosticKind>(Diagnostic::VT_KIND, kind, DiagnosticKind::Error);
+  }
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Diagnostic::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u64) {
+    self.fbb_.push_slot::<u64>(Diagnostic::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(Diagnostic::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_start_column(&mut self, start_column: u32) {
+    self.fbb_.push_slot::<u32>(Diagnostic::VT_START_COLUMN, start_column, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(Diagnostic::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
+  pub fn add_end_column(&mut self, end_column: u32) {
+    self.fbb_.push_slot::<u32>(Diagnostic::VT_END_COLUMN, end_column, 0);
+  }
+  #[inline]
+  pub fn add_expected(&mut self, expected: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Diagnostic::VT_EXPECTED, expected);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DiagnosticBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DiagnosticBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Diagnostic<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Diagnostic<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Diagnostic");
+      ds.field("kind", &self.kind());
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.field("start_line", &self.start_line());
+      ds.field("start_column", &self.start_column());
+      ds.field("end_line", &self.end_line());
+      ds.field("end_column", &self.end_column());
+      ds.field("expected", &self.expected());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing the diagnostics of each file.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The diagnostics of a file.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_DIAGNOSTICS: flatbuffers::VOffsetT = 10;
+  pub const VT_PARSE_QUALITY: flatbuffers::VOffsetT = 12;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    builder.add_parse_quality(args.parse_quality);
+    if let Some(x) = args.diagnostics { builder.add_diagnostics(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language used to parse this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// Where the file doesn't parse, in order. Errors nested inside other errors aren't reported.
+  #[inline]
+  pub fn diagnostics(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic>>>>(Document::VT_DIAGNOSTICS, None)}
+  }
+  /// The fraction of the file's bytes outside any `Error`, from 0 to 1. A file that parses
+  /// cleanly scores 1, and text in the wrong language usually scores well below it.
+  #[inline]
+  pub fn parse_quality(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Document::VT_PARSE_QUALITY, Some(0.0)).unwrap()}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Diagnostic>>>>("diagnostics", Self::VT_DIAGNOSTICS, false)?
+     .visit_field::<f32>("parse_quality", Self::VT_PARSE_QUALITY, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub diagnostics: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Diagnostic<'a>>>>>,
+    pub parse_quality: f32,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      diagnostics: None,
+      parse_quality: 0.0,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_diagnostics(&mut self, diagnostics: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Diagnostic<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_DIAGNOSTICS, diagnostics);
+  }
+  #[inline]
+  pub fn add_parse_quality(&mut self, parse_quality: f32) {
+    self.fbb_.push_slot::<f32>(Document::VT_PARSE_QUALITY, parse_quality, 0.0);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("diagnostics", &self.diagnostics());
+      ds.field("parse_quality", &self.parse_quality());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod diagnostics
+
+#[allow(unused_imports, dead_code)]
+pub mod references {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request for the scope graph of each file: where its local variables are defined, where they
+/// are used, and which definition each use refers to, from its language's locals query. Languages
+/// without a locals query have empty graphs.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.finish()
+  }
+}
+pub enum ScopeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A region of a file that definitions are local to, like a function or a block.
+pub struct Scope<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Scope<'a> {
+  type Inner = Scope<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Scope<'a> {
+  pub const VT_START: flatbuffers::VOffsetT = 4;
+  pub const VT_END: flatbuffers::VOffsetT = 6;
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 8;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 10;
+  pub const VT_PARENT: flatbuffers::VOffsetT = 12;
+  pub const VT_ISOLATED: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Scope { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ScopeArgs
+  ) -> flatbuffers::WIPOffset<Scope<'bldr>> {
+    let mut builder = ScopeBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    builder.add_parent(args.parent);
+    builder.add_end_line(args.end_line);
+    builder.add_start_line(args.start_line);
+    builder.add_isolated(args.isolated);
+    builder.finish()
+  }
+
+
+  /// The byte offset of the start of the scope.
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Scope::VT_START, Some(0)).unwrap()}
+  }
+  /// The byte offset of the end of the scope.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Scope::VT_END, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `start`.
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Scope::VT_START_LINE, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `end`.
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Scope::VT_END_LINE, Some(0)).unwrap()}
+  }
+  /// The index of the enclosing scope, or -1 for the first scope, which is the whole file.
+  #[inline]
+  pub fn parent(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(Scope::VT_PARENT, Some(-1)).unwrap()}
+  }
+  /// If true, references in this scope can't see the definitions of enclosing scopes, as in a
+  /// Ruby method.
+  #[inline]
+  pub fn isolated(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Scope::VT_ISOLATED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Scope<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
+     .visit_field::<i32>("parent", Self::VT_PARENT, false)?
+     .visit_field::<bool>("isolated", Self::VT_ISOLATED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScopeArgs {
+    pub start: u64,
+    pub end: u64,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub parent: i32,
+    pub isolated: bool,
+}
+impl<'a> Default for ScopeArgs {
+  #[inline]
+  fn default() -> Self {
+    ScopeArgs {
+      start: 0,
+      end: 0,
+      start_line: 0,
+      end_line: 0,
+      parent: -1,
+      isolated: false,
+    }
+  }
+}
+
+pub struct ScopeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScopeBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Scope::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u64) {
+    self.fbb_.push_slot::<u64>(Scope::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(Scope::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(Scope::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
+  pub fn add_parent(&mut self, parent: i32) {
+    self.fbb_.push_slot::<i32>(Scope::VT_PARENT, parent, -1);
+  }
+  #[inline]
+  pub fn add_isolated(&mut self, isolated: bool) {
+    self.fbb_.push_slot::<bool>(Scope::VT_ISOLATED, isolated, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScopeBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ScopeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Scope<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Scope<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Scope");
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.field("start_line", &self.start_line());
+      ds.field("end_line", &self.end_line());
+      ds.field("parent", &self.parent());
+      ds.field("isolated", &self.isolated());
+      ds.finish()
+  }
+}
+pub enum DefinitionOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Where a local variable is defined.
+pub struct Definition<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Definition<'a> {
+  type Inner = Definition<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Definition<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_START: flatbuffers::VOffsetT = 8;
+  pub const VT_END: flatbuffers::VOffsetT = 10;
+  pub const VT_LINE: flatbuffers::VOffsetT = 12;
+  pub const VT_COLUMN: flatbuffers::VOffsetT = 14;
+  pub const VT_SCOPE: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Definition { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DefinitionArgs<'args>
+  ) -> flatbuffers::WIPOffset<Definition<'bldr>> {
+    let mut builder = DefinitionBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    builder.add_scope(args.scope);
+    builder.add_column(args.column);
+    builder.add_line(args.line);
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  /// The variable's name.
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Definition::VT_NAME, None)}
+  }
+  /// What kind of definition it is, from a `@local.definition.<kind>` capture, if the locals
+  /// query says.
+  #[inline]
+  pub fn kind(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Definition::VT_KIND, None)}
+  }
+  /// The byte offset of the start of the name.
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Definition::VT_START, Some(0)).unwrap()}
+  }
+  /// The byte offset of the end of the name.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Definition::VT_END, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `start`.
+  #[inline]
+  pub fn line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Definition::VT_LINE, Some(0)).unwrap()}
+  }
+  /// The byte column of `start` within its line.
+  #[inline]
+  pub fn column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Definition::VT_COLUMN, Some(0)).unwrap()}
+  }
+  /// The index of the scope the definition belongs to.
+  #[inline]
+  pub fn scope(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Definition::VT_SCOPE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Definition<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, false)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u32>("line", Self::VT_LINE, false)?
+     .visit_field::<u32>("column", Self::VT_COLUMN, false)?
+     .visit_field::<u32>("scope", Self::VT_SCOPE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DefinitionArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub start: u64,
+    pub end: u64,
+    pub line: u32,
+    pub column: u32,
+    pub scope: u32,
+}
+impl<'a> Default for DefinitionArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DefinitionArgs {
+      name: None,
+      kind: None,
+      start: 0,
+      end: 0,
+      line: 0,
+      column: 0,
+      scope: 0,
+    }
+  }
+}
+
+pub struct DefinitionBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DefinitionBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Definition::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Definition::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Definition::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u64) {
+    self.fbb_.push_slot::<u64>(Definition::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn add_line(&mut self, line: u32) {
+    self.fbb_.push_slot::<u32>(Definition::VT_LINE, line, 0);
+  }
+  #[inline]
+  pub fn add_column(&mut self, column: u32) {
+    self.fbb_.push_slot::<u32>(Definition::VT_COLUMN, column, 0);
+  }
+  #[inline]
+  pub fn add_scope(&mut self, scope: u32) {
+    self.fbb_.push_slot::<u32>(Definition::VT_SCOPE, scope, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DefinitionBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DefinitionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Definition<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Definition<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Definition");
+      ds.field("name", &self.name());
+      ds.field("kind", &self.kind());
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.field("line", &self.line());
+      ds.field("column", &self.column());
+      ds.field("scope", &self.scope());
+      ds.finish()
+  }
+}
+pub enum ReferenceOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A use of a variable.
+pub struct Reference<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Reference<'a> {
+  type Inner = Reference<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Reference<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_START: flatbuffers::VOffsetT = 6;
+  pub const VT_END: flatbuffers::VOffsetT = 8;
+  pub const VT_LINE: flatbuffers::VOffsetT = 10;
+  pub const VT_COLUMN: flatbuffers::VOffsetT = 12;
+  pub const VT_DEFINITION: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Reference { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ReferenceArgs<'args>
+  ) -> flatbuffers::WIPOffset<Reference<'bldr>> {
+    let mut builder = ReferenceBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    builder.add_definition(args.definition);
+    builder.add_column(args.column);
+    builder.add_line(args.line);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  /// The variable's name.
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Reference::VT_NAME, None)}
+  }
+  /// The byte offset of the start of the name.
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Reference::VT_START, Some(0)).unwrap()}
+  }
+  /// The byte offset of the end of the name.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Reference::VT_END, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `start`.
+  #[inline]
+  pub fn line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Reference::VT_LINE, Some(0)).unwrap()}
+  }
+  /// The byte column of `start` within its line.
+  #[inline]
+  pub fn column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Reference::VT_COLUMN, Some(0)).unwrap()}
+  }
+  /// The index of the definition the reference resolves to: the latest one before it with the
+  /// same name, in the innermost scope that has one. -1 if there isn't one, as for globals.
+  #[inline]
+  pub fn definition(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(Reference::VT_DEFINITION, Some(-1)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Reference<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u32>("line", Self::VT_LINE, false)?
+     .visit_field::<u32>("column", Self::VT_COLUMN, false)?
+     .visit_field::<i32>("definition", Self::VT_DEFINITION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ReferenceArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub start: u64,
+    pub end: u64,
+    pub line: u32,
+    pub column: u32,
+    pub definition: i32,
+}
+impl<'a> Default for ReferenceArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ReferenceArgs {
+      name: None,
+      start: 0,
+      end: 0,
+      line: 0,
+      column: 0,
+      definition: -1,
+    }
+  }
+}
+
+pub struct ReferenceBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReferenceBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Reference::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Reference::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u64) {
+    self.fbb_.push_slot::<u64>(Reference::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn add_line(&mut self, line: u32) {
+    self.fbb_.push_slot::<u32>(Reference::VT_LINE, line, 0);
+  }
+  #[inline]
+  pub fn add_column(&mut self, column: u32) {
+    self.fbb_.push_slot::<u32>(Reference::VT_COLUMN, column, 0);
+  }
+  #[inline]
+  pub fn add_definition(&mut self, definition: i32) {
+    self.fbb_.push_slot::<i32>(Reference::VT_DEFINITION, definition, -1);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ReferenceBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ReferenceBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Reference<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Reference<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Reference");
+      ds.field("name", &self.name());
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.field("line", &self.line());
+      ds.field("column", &self.column());
+      ds.field("definition", &self.definition());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing the scope graph of each file.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The scope graph of a file.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_SCOPES: flatbuffers::VOffsetT = 10;
+  pub const VT_DEFINITIONS: flatbuffers::VOffsetT = 12;
+  pub const VT_REFERENCES: flatbuffers::VOffsetT = 14;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.references { builder.add_references(x); }
+    if let Some(x) = args.definitions { builder.add_definitions(x); }
+    if let Some(x) = args.scopes { builder.add_scopes(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language used to parse this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The file's scopes, in order of their starts.
+  #[inline]
+  pub fn scopes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Scope<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Scope>>>>(Document::VT_SCOPES, None)}
+  }
+  /// The file's definitions, in order.
+  #[inline]
+  pub fn definitions(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Definition<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Definition>>>>(Document::VT_DEFINITIONS, None)}
+  }
+  /// The file's references, in order.
+  #[inline]
+  pub fn references(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Reference<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Reference>>>>(Document::VT_REFERENCES, None)}
+  }
+  /// If no error occurred, the code will be NoError.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Scope>>>>("scopes", Self::VT_SCOPES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Definition>>>>("definitions", Self::VT_DEFINITIONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Reference>>>>("references", Self::VT_REFERENCES, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub scopes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Scope<'a>>>>>,
+    pub definitions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Definition<'a>>>>>,
+    pub references: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Reference<'a>>>>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      scopes: None,
+      definitions: None,
+      references: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_scopes(&mut self, scopes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Scope<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_SCOPES, scopes);
+  }
+  #[inline]
+  pub fn add_definitions(&mut self, definitions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Definition<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_DEFINITIONS, definitions);
+  }
+  #[inline]
+  pub fn add_references(&mut self, references: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Reference<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_REFERENCES, references);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("scopes", &self.scopes());
+      ds.field("definitions", &self.definitions());
+      ds.field("references", &self.references());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod references
+
+#[allow(unused_imports, dead_code)]
+pub mod tree {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TREE_FORMAT: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TREE_FORMAT: i8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TREE_FORMAT: [TreeFormat; 2] = [
+  TreeFormat::Nodes,
+  TreeFormat::SExpression,
+];
+
+/// How a syntax tree is returned.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TreeFormat(pub i8);
+#[allow(non_upper_case_globals)]
+impl TreeFormat {
+  /// `nodes`, a flat list of every node in the tree, named or not.
+  pub const Nodes: Self = Self(0);
+  /// `sexp`, an S-expression of the named nodes, like tree-sitter's CLI prints.
+  pub const SExpression: Self = Self(1);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Nodes,
+    Self::SExpression,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Nodes => Some("Nodes"),
+      Self::SExpression => Some("SExpression"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for TreeFormat {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for TreeFormat {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for TreeFormat {
+    type Output = TreeFormat;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for TreeFormat {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for TreeFormat {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for TreeFormat {}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request for the syntax trees of some files.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_FORMAT: flatbuffers::VOffsetT = 8;
+  pub const VT_MAX_DEPTH: flatbuffers::VOffsetT = 10;
+  pub const VT_BYTE_START: flatbuffers::VOffsetT = 12;
+  pub const VT_BYTE_END: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_byte_end(args.byte_end);
+    builder.add_byte_start(args.byte_start);
+    builder.add_timeout_ms(args.timeout_ms);
+    builder.add_max_depth(args.max_depth);
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.add_format(args.format);
+    builder.finish()
+  }
+
+
+  /// The files to parse.
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn format(&self) -> TreeFormat {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TreeFormat>(Request::VT_FORMAT, Some(TreeFormat::Nodes)).unwrap()}
+  }
+  /// How deep below the root to go. The root is at depth 0. If zero, the whole tree is returned.
+  #[inline]
+  pub fn max_depth(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Request::VT_MAX_DEPTH, Some(0)).unwrap()}
+  }
+  /// Only nodes that overlap the bytes from `byte_start` to `byte_end` are returned, with the
+  /// nodes that enclose them. If `byte_end` is zero, the range lasts until the end of the file.
+  #[inline]
+  pub fn byte_start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_BYTE_START, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn byte_end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_BYTE_END, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<TreeFormat>("format", Self::VT_FORMAT, false)?
+     .visit_field::<u32>("max_depth", Self::VT_MAX_DEPTH, false)?
+     .visit_field::<u64>("byte_start", Self::VT_BYTE_START, false)?
+     .visit_field::<u64>("byte_end", Self::VT_BYTE_END, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub format: TreeFormat,
+    pub max_depth: u32,
+    pub byte_start: u64,
+    pub byte_end: u64,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      format: TreeFormat::Nodes,
+      max_depth: 0,
+      byte_start: 0,
+      byte_end: 0,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_format(&mut self, format: TreeFormat) {
+    self.fbb_.push_slot::<TreeFormat>(Request::VT_FORMAT, format, TreeFormat::Nodes);
+  }
+  #[inline]
+  pub fn add_max_depth(&mut self, max_depth: u32) {
+    self.fbb_.push_slot::<u32>(Request::VT_MAX_DEPTH, max_depth, 0);
+  }
+  #[inline]
+  pub fn add_byte_start(&mut self, byte_start: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_BYTE_START, byte_start, 0);
+  }
+  #[inline]
+  pub fn add_byte_end(&mut self, byte_end: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_BYTE_END, byte_end, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("format", &self.format());
+      ds.field("max_depth", &self.max_depth());
+      ds.field("byte_start", &self.byte_start());
+      ds.field("byte_end", &self.byte_end());
+      ds.finish()
+  }
+}
+pub enum NodeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A node of a syntax tree.
+pub struct Node<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Node<'a> {
+  type Inner = Node<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Node<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_FIELD_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_IS_NAMED: flatbuffers::VOffsetT = 8;
+  pub const VT_START: flatbuffers::VOffsetT = 10;
+  pub const VT_END: flatbuffers::VOffsetT = 12;
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 14;
+  pub const VT_START_COLUMN: flatbuffers::VOffsetT = 16;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 18;
+  pub const VT_END_COLUMN: flatbuffers::VOffsetT = 20;
+  pub const VT_PARENT: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Node { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args NodeArgs<'args>
+  ) -> flatbuffers::WIPOffset<Node<'bldr>> {
+    let mut builder = NodeBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    builder.add_parent(args.parent);
+    builder.add_end_column(args.end_column);
+    builder.add_end_line(args.end_line);
+    builder.add_start_column(args.start_column);
+    builder.add_start_line(args.start_line);
+    if let Some(x) = args.field_name { builder.add_field_name(x); }
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    builder.add_is_named(args.is_named);
+    builder.finish()
+  }
+
+
+  /// The node's kind, like `function_item` or `(`.
+  #[inline]
+  pub fn kind(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Node::VT_KIND, None)}
+  }
+  /// The name of the field the node is in its parent, if it's in one.
+  #[inline]
+  pub fn field_name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Node::VT_FIELD_NAME, None)}
+  }
+  /// Whether the node is named in the grammar, rather than an anonymous token like `(`.
+  #[inline]
+  pub fn is_named(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Node::VT_IS_NAMED, Some(false)).unwrap()}
+  }
+  /// The byte offset of the start of the node.
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Node::VT_START, Some(0)).unwrap()}
+  }
+  /// The byte offset of the end of the node.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Node::VT_END, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `start`.
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Node::VT_START_LINE, Some(0)).unwrap()}
+  }
+  /// The byte column of `start` within its line.
+  #[inline]
+  pub fn start_column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Node::VT_START_COLUMN, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `end`.
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Node::VT_END_LINE, Some(0)).unwrap()}
+  }
+  /// The byte column of `end` within its line.
+  #[inline]
+  pub fn end_column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Node::VT_END_COLUMN, Some(0)).unwrap()}
+  }
+  /// The index of the node's parent, or -1 for the root.
+  #[inline]
+  pub fn parent(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(Node::VT_PARENT, Some(-1)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Node<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("field_name", Self::VT_FIELD_NAME, false)?
+     .visit_field::<bool>("is_named", Self::VT_IS_NAMED, false)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("start_column", Self::VT_START_COLUMN, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
+     .visit_field::<u32>("end_column", Self::VT_END_COLUMN, false)?
+     .visit_field::<i32>("parent", Self::VT_PARENT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NodeArgs<'a> {
+    pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub field_name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub is_named: bool,
+    pub start: u64,
+    pub end: u64,
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    pub parent: i32,
+}
+impl<'a> Default for NodeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    NodeArgs {
+      kind: None,
+      field_name: None,
+      is_named: false,
+      start: 0,
+      end: 0,
+      start_line: 0,
+      start_column: 0,
+      end_line: 0,
+      end_column: 0,
+      parent: -1,
+    }
+  }
+}
+
+pub struct NodeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NodeBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_field_name(&mut self, field_name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_FIELD_NAME, field_name);
+  }
+  #[inline]
+  pub fn add_is_named(&mut self, is_named: bool) {
+    self.fbb_.push_slot::<bool>(Node::VT_IS_NAMED, is_named, false);
+  }
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Node::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u64) {
+    self.fbb_.push_slot::<u64>(Node::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(Node::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_start_column(&mut self, start_column: u32) {
+    self.fbb_.push_slot::<u32>(Node::VT_START_COLUMN, start_column, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(Node::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
+  pub fn add_end_column(&mut self, end_column: u32) {
+    self.fbb_.push_slot::<u32>(Node::VT_END_COLUMN, end_column, 0);
+  }
+  #[inline]
+  pub fn add_parent(&mut self, parent: i32) {
+    self.fbb_.push_slot::<i32>(Node::VT_PARENT, parent, -1);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> NodeBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    NodeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Node<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Node<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Node");
+      ds.field("kind", &self.kind());
+      ds.field("field_name", &self.field_name());
+      ds.field("is_named", &self.is_named());
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.field("start_line", &self.start_line());
+      ds.field("start_column", &self.start_column());
+      ds.field("end_line", &self.end_line());
+      ds.field("end_column", &self.end_column());
+      ds.field("parent", &self.parent());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing the syntax tree of each file.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The syntax tree of a file.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_NODES: flatbuffers::VOffsetT = 10;
+  pub const VT_SEXP: flatbuffers::VOffsetT = 12;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.sexp { builder.add_sexp(x); }
+    if let Some(x) = args.nodes { builder.add_nodes(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language used to parse this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The tree's nodes in preorder, with `TreeFormat.Nodes`. A node's parent comes before it.
+  #[inline]
+  pub fn nodes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node>>>>(Document::VT_NODES, None)}
+  }
+  /// The tree as an S-expression, with `TreeFormat.SExpression`.
+  #[inline]
+  pub fn sexp(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_SEXP, None)}
+  }
+  /// If non-zero, an error occurred while processing this file.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Node>>>>("nodes", Self::VT_NODES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sexp", Self::VT_SEXP, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub nodes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>>>,
+    pub sexp: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub error_code: super::common::ErrorCode,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      nodes: None,
+      sexp: None,
+      error_code: super::common::ErrorCode::NoError,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_nodes(&mut self, nodes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Node<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_NODES, nodes);
+  }
+  #[inline]
+  pub fn add_sexp(&mut self, sexp: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_SEXP, sexp);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("nodes", &self.nodes());
+      ds.field("sexp", &self.sexp());
+      ds.field("error_code", &self.error_code());
+      ds.finish()
+  }
+}
+}  // pub mod tree
+
+#[allow(unused_imports, dead_code)]
+pub mod query {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_QUERY_ERROR_KIND: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_QUERY_ERROR_KIND: i8 = 6;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_QUERY_ERROR_KIND: [QueryErrorKind; 7] = [
+  QueryErrorKind::Syntax,
+  QueryErrorKind::NodeType,
+  QueryErrorKind::Field,
+  QueryErrorKind::Capture,
+  QueryErrorKind::Predicate,
+  QueryErrorKind::Structure,
+  QueryErrorKind::Language,
+];
+
+/// Why a query didn't compile, as tree-sitter reports it.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct QueryErrorKind(pub i8);
+#[allow(non_upper_case_globals)]
+impl QueryErrorKind {
+  /// The query isn't a well-formed S-expression.
+  pub const Syntax: Self = Self(0);
+  /// A node kind the language doesn't have.
+  pub const NodeType: Self = Self(1);
+  /// A field the language doesn't have.
+  pub const Field: Self = Self(2);
+  /// A predicate names a capture the pattern doesn't have.
+  pub const Capture: Self = Self(3);
+  /// A predicate with the wrong arguments.
+  pub const Predicate: Self = Self(4);
+  /// A pattern no tree in the language could match.
+  pub const Structure: Self = Self(5);
+  /// The language is incompatible with this version of tree-sitter.
+  pub const Language: Self = Self(6);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 6;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Syntax,
+    Self::NodeType,
+    Self::Field,
+    Self::Capture,
+    Self::Predicate,
+    Self::Structure,
+    Self::Language,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Syntax => Some("Syntax"),
+      Self::NodeType => Some("NodeType"),
+      Self::Field => Some("Field"),
+      Self::Capture => Some("Capture"),
+      Self::Predicate => Some("Predicate"),
+      Self::Structure => Some("Structure"),
+      Self::Language => Some("Language"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for QueryErrorKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for QueryErrorKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for QueryErrorKind {
+    type Output = QueryErrorKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for QueryErrorKind {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for QueryErrorKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for QueryErrorKind {}
+pub enum LanguageQueryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A query to run over the files in one language.
+pub struct LanguageQuery<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LanguageQuery<'a> {
+  type Inner = LanguageQuery<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> LanguageQuery<'a> {
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 4;
+  pub const VT_QUERY: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LanguageQuery { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args LanguageQueryArgs<'args>
+  ) -> flatbuffers::WIPOffset<LanguageQuery<'bldr>> {
+    let mut builder = LanguageQueryBuilder::new(_fbb);
+    if let Some(x) = args.query { builder.add_query(x); }
+    builder.add_language(args.language);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(LanguageQuery::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// A tree-sitter query, in the same syntax as `highlights.scm`.
+  #[inline]
+  pub fn query(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LanguageQuery::VT_QUERY, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for LanguageQuery<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LanguageQueryArgs<'a> {
+    pub language: super::common::Language,
+    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for LanguageQueryArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LanguageQueryArgs {
+      language: super::common::Language::Unspecified,
+      query: None,
+    }
+  }
+}
+
+pub struct LanguageQueryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LanguageQueryBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(LanguageQuery::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LanguageQuery::VT_QUERY, query);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LanguageQueryBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LanguageQueryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LanguageQuery<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LanguageQuery<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LanguageQuery");
+      ds.field("language", &self.language());
+      ds.field("query", &self.query());
+      ds.finish()
+  }
+}
+pub enum RequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to run a tree-sitter query over some files.
+pub struct Request<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Request<'a> {
+  type Inner = Request<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Request<'a> {
+  pub const VT_FILES: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+  pub const VT_QUERY: flatbuffers::VOffsetT = 8;
+  pub const VT_LANGUAGE_QUERIES: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Request { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
+    let mut builder = RequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.language_queries { builder.add_language_queries(x); }
+    if let Some(x) = args.query { builder.add_query(x); }
+    if let Some(x) = args.files { builder.add_files(x); }
+    builder.finish()
+  }
+
+
+  /// The files to search.
+  #[inline]
+  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+  }
+  /// The maximum time a file is allowed to take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+  /// A tree-sitter query to run over files in any language without one in `language_queries`.
+  #[inline]
+  pub fn query(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Request::VT_QUERY, None)}
+  }
+  /// The queries for particular languages. Files in languages with neither these nor `query`
+  /// have no matches.
+  #[inline]
+  pub fn language_queries(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LanguageQuery<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LanguageQuery>>>>(Request::VT_LANGUAGE_QUERIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Request<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LanguageQuery>>>>("language_queries", Self::VT_LANGUAGE_QUERIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RequestArgs<'a> {
+    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
+    pub timeout_ms: u64,
+    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language_queries: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LanguageQuery<'a>>>>>,
+}
+impl<'a> Default for RequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RequestArgs {
+      files: None,
+      timeout_ms: 0,
+      query: None,
+      language_queries: None,
+    }
+  }
+}
+
+pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_QUERY, query);
+  }
+  #[inline]
+  pub fn add_language_queries(&mut self, language_queries: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LanguageQuery<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_LANGUAGE_QUERIES, language_queries);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Request<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Request");
+      ds.field("files", &self.files());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.field("query", &self.query());
+      ds.field("language_queries", &self.language_queries());
+      ds.finish()
+  }
+}
+pub enum CaptureOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A node a pattern captured.
+pub struct Capture<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Capture<'a> {
+  type Inner = Capture<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Capture<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_START: flatbuffers::VOffsetT = 6;
+  pub const VT_END: flatbuffers::VOffsetT = 8;
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 10;
+  pub const VT_START_COLUMN: flatbuffers::VOffsetT = 12;
+  pub const VT_END_LINE: flatbuffers::VOffsetT = 14;
+  pub const VT_END_COLUMN: flatbuffers::VOffsetT = 16;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Capture { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args CaptureArgs<'args>
+  ) -> flatbuffers::WIPOffset<Capture<'bldr>> {
+    let mut builder = CaptureBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.add_end_column(args.end_column);
+    builder.add_end_line(args.end_line);
+    builder.add_start_column(args.start_column);
+    builder.add_start_line(args.start_line);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  /// The capture's name, without the `@`.
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Capture::VT_NAME, None)}
+  }
+  /// The byte offset of the start of the node.
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Capture::VT_START, Some(0)).unwrap()}
+  }
+  /// The byte offset of the end of the node.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Capture::VT_END, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `start`.
+  #[inline]
+  pub fn start_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Capture::VT_START_LINE, Some(0)).unwrap()}
+  }
+  /// The byte column of `start` within its line.
+  #[inline]
+  pub fn start_column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Capture::VT_START_COLUMN, Some(0)).unwrap()}
+  }
+  /// The zero-based line of `end`.
+  #[inline]
+  pub fn end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Capture::VT_END_LINE, Some(0)).unwrap()}
+  }
+  /// The byte column of `end` within its line.
+  #[inline]
+  pub fn end_column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Capture::VT_END_COLUMN, Some(0)).unwrap()}
+  }
+  /// The node's text.
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Capture::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Capture<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("start_column", Self::VT_START_COLUMN, false)?
+     .visit_field::<u32>("end_line", Self::VT_END_LINE, false)?
+     .visit_field::<u32>("end_column", Self::VT_END_COLUMN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CaptureArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub start: u64,
+    pub end: u64,
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CaptureArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CaptureArgs {
+      name: None,
+      start: 0,
+      end: 0,
+      start_line: 0,
+      start_column: 0,
+      end_line: 0,
+      end_column: 0,
+      text: None,
+    }
+  }
+}
+
+pub struct CaptureBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CaptureBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Capture::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Capture::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u64) {
+    self.fbb_.push_slot::<u64>(Capture::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(Capture::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_start_column(&mut self, start_column: u32) {
+    self.fbb_.push_slot::<u32>(Capture::VT_START_COLUMN, start_column, 0);
+  }
+  #[inline]
+  pub fn add_end_line(&mut self, end_line: u32) {
+    self.fbb_.push_slot::<u32>(Capture::VT_END_LINE, end_line, 0);
+  }
+  #[inline]
+  pub fn add_end_column(&mut self, end_column: u32) {
+    self.fbb_.push_slot::<u32>(Capture::VT_END_COLUMN, end_column, 0);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Capture::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CaptureBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    CaptureBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Capture<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Capture<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Capture");
+      ds.field("name", &self.name());
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.field("start_line", &self.start_line());
+      ds.field("start_column", &self.start_column());
+      ds.field("end_line", &self.end_line());
+      ds.field("end_column", &self.end_column());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum MatchOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A match of one of the query's patterns.
+pub struct Match<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Match<'a> {
+  type Inner = Match<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Match<'a> {
+  pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
+  pub const VT_CAPTURES: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Match { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MatchArgs<'args>
+  ) -> flatbuffers::WIPOffset<Match<'bldr>> {
+    let mut builder = MatchBuilder::new(_fbb);
+    if let Some(x) = args.captures { builder.add_captures(x); }
+    builder.add_pattern(args.pattern);
+    builder.finish()
+  }
+
+
+  /// The index of the pattern within the query, from 0.
+  #[inline]
+  pub fn pattern(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Match::VT_PATTERN, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn captures(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Capture<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Capture>>>>(Match::VT_CAPTURES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Match<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("pattern", Self::VT_PATTERN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Capture>>>>("captures", Self::VT_CAPTURES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MatchArgs<'a> {
+    pub pattern: u32,
+    pub captures: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Capture<'a>>>>>,
+}
+impl<'a> Default for MatchArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MatchArgs {
+      pattern: 0,
+      captures: None,
+    }
+  }
+}
+
+pub struct MatchBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MatchBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_pattern(&mut self, pattern: u32) {
+    self.fbb_.push_slot::<u32>(Match::VT_PATTERN, pattern, 0);
+  }
+  #[inline]
+  pub fn add_captures(&mut self, captures: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Capture<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Match::VT_CAPTURES, captures);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MatchBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MatchBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Match<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Match<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Match");
+      ds.field("pattern", &self.pattern());
+      ds.field("captures", &self.captures());
+      ds.finish()
+  }
+}
+pub enum QueryErrorOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Why a language's query didn't compile.
+pub struct QueryError<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryError<'a> {
+  type Inner = QueryError<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> QueryError<'a> {
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_ROW: flatbuffers::VOffsetT = 10;
+  pub const VT_COLUMN: flatbuffers::VOffsetT = 12;
+  pub const VT_OFFSET: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryError { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args QueryErrorArgs<'args>
+  ) -> flatbuffers::WIPOffset<QueryError<'bldr>> {
+    let mut builder = QueryErrorBuilder::new(_fbb);
+    builder.add_offset(args.offset);
+    builder.add_column(args.column);
+    builder.add_row(args.row);
+    if let Some(x) = args.message { builder.add_message(x); }
+    builder.add_language(args.language);
+    builder.add_kind(args.kind);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(QueryError::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> QueryErrorKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<QueryErrorKind>(QueryError::VT_KIND, Some(QueryErrorKind::Syntax)).unwrap()}
+  }
+  #[inline]
+  pub fn message(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(QueryError::VT_MESSAGE, None)}
+  }
+  /// The zero-based line and column within the query where the problem is.
+  #[inline]
+  pub fn row(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(QueryError::VT_ROW, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn column(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(QueryError::VT_COLUMN, Some(0)).unwrap()}
+  }
+  /// The byte offset within the query where the problem is.
+  #[inline]
+  pub fn offset(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(QueryError::VT_OFFSET, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for QueryError<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<QueryErrorKind>("kind", Self::VT_KIND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .visit_field::<u32>("row", Self::VT_ROW, false)?
+     .visit_field::<u32>("column", Self::VT_COLUMN, false)?
+     .visit_field::<u64>("offset", Self::VT_OFFSET, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryErrorArgs<'a> {
+    pub language: super::common::Language,
+    pub kind: QueryErrorKind,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub row: u32,
+    pub column: u32,
+    pub offset: u64,
+}
+impl<'a> Default for QueryErrorArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    QueryErrorArgs {
+      language: super::common::Language::Unspecified,
+      kind: QueryErrorKind::Syntax,
+      message: None,
+      row: 0,
+      column: 0,
+      offset: 0,
+    }
+  }
+}
+
+pub struct QueryErrorBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> QueryErrorBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(QueryError::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: QueryErrorKind) {
+    self.fbb_.push_slot::<QueryErrorKind>(QueryError::VT_KIND, kind, QueryErrorKind::Syntax);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(QueryError::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn add_row(&mut self, row: u32) {
+    self.fbb_.push_slot::<u32>(QueryError::VT_ROW, row, 0);
+  }
+  #[inline]
+  pub fn add_column(&mut self, column: u32) {
+    self.fbb_.push_slot::<u32>(QueryError::VT_COLUMN, column, 0);
+  }
+  #[inline]
+  pub fn add_offset(&mut self, offset: u64) {
+    self.fbb_.push_slot::<u64>(QueryError::VT_OFFSET, offset, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> QueryErrorBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    QueryErrorBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryError<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryError<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryError");
+      ds.field("language", &self.language());
+      ds.field("kind", &self.kind());
+      ds.field("message", &self.message());
+      ds.field("row", &self.row());
+      ds.field("column", &self.column());
+      ds.field("offset", &self.offset());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response containing each file's matches.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+  pub const VT_QUERY_ERRORS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    if let Some(x) = args.query_errors { builder.add_query_errors(x); }
+    if let Some(x) = args.documents { builder.add_documents(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+  }
+  /// One error for each language whose query didn't compile. Its files have the
+  /// `InvalidQuery` error code.
+  #[inline]
+  pub fn query_errors(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryError<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryError>>>>(Response::VT_QUERY_ERRORS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Response<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<QueryError>>>>("query_errors", Self::VT_QUERY_ERRORS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ResponseArgs<'a> {
+    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+    pub query_errors: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<QueryError<'a>>>>>,
+}
+impl<'a> Default for ResponseArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ResponseArgs {
+      documents: None,
+      query_errors: None,
+    }
+  }
+}
+
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  }
+  #[inline]
+  pub fn add_query_errors(&mut self, query_errors: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<QueryError<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_QUERY_ERRORS, query_errors);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Response<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Response");
+      ds.field("documents", &self.documents());
+      ds.field("query_errors", &self.query_errors());
+      ds.finish()
+  }
+}
+pub enum DocumentOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The matches in a file, in order of their starts.
+pub struct Document<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Document<'a> {
+  type Inner = Document<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Document<'a> {
+  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
+  pub const VT_MATCHES: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+  pub const VT_TRUNCATED: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Document { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DocumentArgs<'args>
+  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
+    let mut builder = DocumentBuilder::new(_fbb);
+    if let Some(x) = args.matches { builder.add_matches(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    builder.add_language(args.language);
+    builder.add_ident(args.ident);
+    builder.add_truncated(args.truncated);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The ident corresponding to the File that produced this document.
+  #[inline]
+  pub fn ident(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+  }
+  /// The file name, provided as a convenience.
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+  }
+  /// The language used to parse this file.
+  #[inline]
+  pub fn language(&self) -> super::common::Language {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+  }
+  /// The file's matches. Only the first 10,000 are returned.
+  #[inline]
+  pub fn matches(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Match<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Match>>>>(Document::VT_MATCHES, None)}
+  }
+  /// If non-zero, an error occurred while processing this file.
+  #[inline]
+  pub fn error_code(&self) -> super::common::ErrorCode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+  }
+  /// True if the file had more matches than were returned.
+  #[inline]
+  pub fn truncated(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Document::VT_TRUNCATED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Document<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Match>>>>("matches", Self::VT_MATCHES, false)?
+     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .visit_field::<bool>("truncated", Self::VT_TRUNCATED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DocumentArgs<'a> {
+    pub ident: u16,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub language: super::common::Language,
+    pub matches: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Match<'a>>>>>,
+    pub error_code: super::common::ErrorCode,
+    pub truncated: bool,
+}
+impl<'a> Default for DocumentArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DocumentArgs {
+      ident: 0,
+      filename: None,
+      language: super::common::Language::Unspecified,
+      matches: None,
+      error_code: super::common::ErrorCode::NoError,
+      truncated: false,
+    }
+  }
+}
+
+pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_ident(&mut self, ident: u16) {
+    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  }
+  #[inline]
+  pub fn add_matches(&mut self, matches: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Match<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_MATCHES, matches);
+  }
+  #[inline]
+  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
+    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  }
+  #[inline]
+  pub fn add_truncated(&mut self, truncated: bool) {
+    self.fbb_.push_slot::<bool>(Document::VT_TRUNCATED, truncated, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DocumentBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Document<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Document");
+      ds.field("ident", &self.ident());
+      ds.field("filename", &self.filename());
+      ds.field("language", &self.language());
+      ds.field("matches", &self.matches());
+      ds.field("error_code", &self.error_code());
+      ds.field("truncated", &self.truncated());
+      ds.finish()
+  }
+}
+}  // pub mod query
+
+#[allow(unused_imports, dead_code)]
+pub mod session {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum OpenRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to open an editing session on a document (`POST /v1/sessions`). The server keeps
+/// the document and its syntax tree, so that edits only re-highlight what they affect.
+pub struct OpenRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OpenRequest<'a> {
+  type Inner = OpenRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> OpenRequest<'a> {
+  pub const VT_FILE: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    OpenRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args OpenRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<OpenRequest<'bldr>> {
+    let mut builder = OpenRequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.file { builder.add_file(x); }
+    builder.finish()
+  }
+
+
+  /// The document. Its `ident` is ignored, and `start_line` and `end_line` don't apply.
+  #[inline]
+  pub fn file(&self) -> Option<super::common::File<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<super::common::File>>(OpenRequest::VT_FILE, None)}
+  }
+  /// The maximum time the first highlighting may take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(OpenRequest::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for OpenRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<super::common::File>>("file", Self::VT_FILE, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OpenRequestArgs<'a> {
+    pub file: Option<flatbuffers::WIPOffset<super::common::File<'a>>>,
+    pub timeout_ms: u64,
+}
+impl<'a> Default for OpenRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    OpenRequestArgs {
+      file: None,
+      timeout_ms: 0,
+    }
+  }
+}
+
+pub struct OpenRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OpenRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_file(&mut self, file: flatbuffers::WIPOffset<super::common::File<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<super::common::File>>(OpenRequest::VT_FILE, file);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(OpenRequest::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> OpenRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OpenRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<OpenRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for OpenRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("OpenRequest");
+      ds.field("file", &self.file());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.finish()
+  }
+}
+pub enum EditOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// An edit, like tree-sitter's `InputEdit`: the bytes from `start` to `old_end` are replaced by
+/// `text`. Offsets are bytes in the document as it is before the edit.
+pub struct Edit<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Edit<'a> {
+  type Inner = Edit<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Edit<'a> {
+  pub const VT_START: flatbuffers::VOffsetT = 4;
+  pub const VT_OLD_END: flatbuffers::VOffsetT = 6;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Edit { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EditArgs<'args>
+  ) -> flatbuffers::WIPOffset<Edit<'bldr>> {
+    let mut builder = EditBuilder::new(_fbb);
+    builder.add_old_end(args.old_end);
+    builder.add_start(args.start);
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn start(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Edit::VT_START, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn old_end(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Edit::VT_OLD_END, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Edit::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Edit<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("start", Self::VT_START, false)?
+     .visit_field::<u64>("old_end", Self::VT_OLD_END, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EditArgs<'a> {
+    pub start: u64,
+    pub old_end: u64,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for EditArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EditArgs {
+      start: 0,
+      old_end: 0,
+      text: None,
+    }
+  }
+}
+
+pub struct EditBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EditBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_start(&mut self, start: u64) {
+    self.fbb_.push_slot::<u64>(Edit::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_old_end(&mut self, old_end: u64) {
+    self.fbb_.push_slot::<u64>(Edit::VT_OLD_END, old_end, 0);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Edit::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EditBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EditBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Edit<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Edit<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Edit");
+      ds.field("start", &self.start());
+      ds.field("old_end", &self.old_end());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum EditRequestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A request to edit a session's document (`POST /v1/sessions/{id}/edits`).
+pub struct EditRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EditRequest<'a> {
+  type Inner = EditRequest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> EditRequest<'a> {
+  pub const VT_EDITS: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EditRequest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EditRequestArgs<'args>
+  ) -> flatbuffers::WIPOffset<EditRequest<'bldr>> {
+    let mut builder = EditRequestBuilder::new(_fbb);
+    builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.edits { builder.add_edits(x); }
+    builder.finish()
+  }
+
+
+  /// The edits, applied in order, each to the document as the ones before it left it.
+  #[inline]
+  pub fn edits(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Edit<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Edit>>>>(EditRequest::VT_EDITS, None)}
+  }
+  /// The maximum time re-highlighting may take. If zero or not provided, the server may choose a timeout.
+  #[inline]
+  pub fn timeout_ms(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(EditRequest::VT_TIMEOUT_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for EditRequest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Edit>>>>("edits", Self::VT_EDITS, false)?
+     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EditRequestArgs<'a> {
+    pub edits: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Edit<'a>>>>>,
+    pub timeout_ms: u64,
+}
+impl<'a> Default for EditRequestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EditRequestArgs {
+      edits: None,
+      timeout_ms: 0,
+    }
+  }
+}
+
+pub struct EditRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EditRequestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_edits(&mut self, edits: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Edit<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EditRequest::VT_EDITS, edits);
+  }
+  #[inline]
+  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
+    self.fbb_.push_slot::<u64>(EditRequest::VT_TIMEOUT_MS, timeout_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EditRequestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EditRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EditRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EditRequest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EditRequest");
+      ds.field("edits", &self.edits());
+      ds.field("timeout_ms", &self.timeout_ms());
+      ds.finish()
+  }
+}
+pub enum SpanOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A highlighted range within a line.
+pub struct Span<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Span<'a> {
+  type Inner = Span<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Span<'a> {
+  pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+  pub const VT_START: flatbuffers::VOffsetT = 6;
+  pub const VT_END: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Span { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SpanArgs
+  ) -> flatbuffers::WIPOffset<Span<'bldr>> {
+    let mut builder = SpanBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    builder.add_index(args.index);
+    builder.finish()
+  }
+
+
+  /// The index of the highlight in `ALL_HIGHLIGHT_NAMES`, as in `/v1/spans`.
+  #[inline]
+  pub fn index(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Span::VT_INDEX, Some(0)).unwrap()}
+  }
+  /// Byte columns within the line.
+  #[inline]
+  pub fn start(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Span::VT_START, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn end(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Span::VT_END, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Span<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("index", Self::VT_INDEX, false)?
+     .visit_field::<u32>("start", Self::VT_START, false)?
+     .visit_field::<u32>("end", Self::VT_END, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpanArgs {
+    pub index: u16,
+    pub start: u32,
+    pub end: u32,
+}
+impl<'a> Default for SpanArgs {
+  #[inline]
+  fn default() -> Self {
+    SpanArgs {
+      index: 0,
+      start: 0,
+      end: 0,
+    }
+  }
+}
+
+pub struct SpanBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpanBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_index(&mut self, index: u16) {
+    self.fbb_.push_slot::<u16>(Span::VT_INDEX, index, 0);
+  }
+  #[inline]
+  pub fn add_start(&mut self, start: u32) {
+    self.fbb_.push_slot::<u32>(Span::VT_START, start, 0);
+  }
+  #[inline]
+  pub fn add_end(&mut self, end: u32) {
+    self.fbb_.push_slot::<u32>(Span::VT_END, end, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SpanBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SpanBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Span<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Span<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Span");
+      ds.field("index", &self.index());
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
+      ds.finish()
+  }
+}
+pub enum LineOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The highlights of a line, in order. Unhighlighted text has no spans.
+pub struct Line<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Line<'a> {
+  type Inner = Line<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Line<'a> {
+  pub const VT_SPANS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Line { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args LineArgs<'args>
+  ) -> flatbuffers::WIPOffset<Line<'bldr>> {
+    let mut builder = LineBuilder::new(_fbb);
+    if let Some(x) = args.spans { builder.add_spans(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn spans(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span>>>>(Line::VT_SPANS, None)}
   }
-  /// The file name, provided as a convenience.
+}
+
+impl flatbuffers::Verifiable for Line<'_> {
   #[inline]
-  pub fn filename(&self) -> Option<&'a str> {
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Span>>>>("spans", Self::VT_SPANS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LineArgs<'a> {
+    pub spans: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span<'a>>>>>,
+}
+impl<'a> Default for LineArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LineArgs {
+      spans: None,
+    }
+  }
+}
+
+pub struct LineBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LineBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_spans(&mut self, spans: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Span<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Line::VT_SPANS, spans);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LineBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LineBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Line<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Line<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Line");
+      ds.field("spans", &self.spans());
+      ds.finish()
+  }
+}
+pub enum ChangeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The lines from `start_line` up to (but not including) `old_end_line` of the previous version
+/// of the document are replaced by `lines`. Lines are numbered from 0.
+pub struct Change<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Change<'a> {
+  type Inner = Change<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Change<'a> {
+  pub const VT_START_LINE: flatbuffers::VOffsetT = 4;
+  pub const VT_OLD_END_LINE: flatbuffers::VOffsetT = 6;
+  pub const VT_LINES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Change { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ChangeArgs<'args>
+  ) -> flatbuffers::WIPOffset<Change<'bldr>> {
+    let mut builder = ChangeBuilder::new(_fbb);
+    if let Some(x) = args.lines { builder.add_lines(x); }
+    builder.add_old_end_line(args.old_end_line);
+    builder.add_start_line(args.start_line);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn start_line(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
+    unsafe { self._tab.get::<u32>(Change::VT_START_LINE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn old_end_line(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Change::VT_OLD_END_LINE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Line<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Line>>>>(Change::VT_LINES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Change<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("start_line", Self::VT_START_LINE, false)?
+     .visit_field::<u32>("old_end_line", Self::VT_OLD_END_LINE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Line>>>>("lines", Self::VT_LINES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ChangeArgs<'a> {
+    pub start_line: u32,
+    pub old_end_line: u32,
+    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Line<'a>>>>>,
+}
+impl<'a> Default for ChangeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ChangeArgs {
+      start_line: 0,
+      old_end_line: 0,
+      lines: None,
+    }
+  }
+}
+
+pub struct ChangeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ChangeBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_start_line(&mut self, start_line: u32) {
+    self.fbb_.push_slot::<u32>(Change::VT_START_LINE, start_line, 0);
+  }
+  #[inline]
+  pub fn add_old_end_line(&mut self, old_end_line: u32) {
+    self.fbb_.push_slot::<u32>(Change::VT_OLD_END_LINE, old_end_line, 0);
   }
-  /// The language that was used or inferred for this file.
+  #[inline]
+  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Line<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Change::VT_LINES, lines);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ChangeBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ChangeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Change<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Change<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Change");
+      ds.field("start_line", &self.start_line());
+      ds.field("old_end_line", &self.old_end_line());
+      ds.field("lines", &self.lines());
+      ds.finish()
+  }
+}
+pub enum ResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A response to opening or editing a session.
+pub struct Response<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Response<'a> {
+  type Inner = Response<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Response<'a> {
+  pub const VT_SESSION_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 6;
+  pub const VT_CHANGES: flatbuffers::VOffsetT = 8;
+  pub const VT_LINE_COUNT: flatbuffers::VOffsetT = 10;
+  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Response { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
+    let mut builder = ResponseBuilder::new(_fbb);
+    builder.add_line_count(args.line_count);
+    if let Some(x) = args.changes { builder.add_changes(x); }
+    if let Some(x) = args.session_id { builder.add_session_id(x); }
+    builder.add_language(args.language);
+    builder.add_error_code(args.error_code);
+    builder.finish()
+  }
+
+
+  /// The session's ID, for its URLs.
+  #[inline]
+  pub fn session_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Response::VT_SESSION_ID, None)}
+  }
+  /// The language the document is highlighted as.
   #[inline]
   pub fn language(&self) -> super::common::Language {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
+    unsafe { self._tab.get::<super::common::Language>(Response::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
   }
-  /// Lines of HTML. If `error_code` is not NoError, this will be empty.
+  /// The highlights that changed. Opening a session gives one change containing every line.
   #[inline]
-  pub fn lines(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn changes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Change<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Document::VT_LINES, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Change>>>>(Response::VT_CHANGES, None)}
   }
-  /// If no error occurred, the code will be NoError. Natch.
+  /// How many lines the document has now. Zero if there was an error.
+  #[inline]
+  pub fn line_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Response::VT_LINE_COUNT, Some(0)).unwrap()}
+  }
+  /// If non-zero, highlighting failed, and the session's document wasn't changed. An edit that
+  /// would make the document or the session too large to keep fails with `FileTooLarge`.
   #[inline]
   pub fn error_code(&self) -> super::common::ErrorCode {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+    unsafe { self._tab.get::<super::common::ErrorCode>(Response::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Document<'_> {
+impl flatbuffers::Verifiable for Response<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("session_id", Self::VT_SESSION_ID, false)?
      .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("lines", Self::VT_LINES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Change>>>>("changes", Self::VT_CHANGES, false)?
+     .visit_field::<u32>("line_count", Self::VT_LINE_COUNT, false)?
      .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct DocumentArgs<'a> {
-    pub ident: u16,
-    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct ResponseArgs<'a> {
+    pub session_id: Option<flatbuffers::WIPOffset<&'a str>>,
     pub language: super::common::Language,
-    pub lines: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub changes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Change<'a>>>>>,
+    pub line_count: u32,
     pub error_code: super::common::ErrorCode,
 }
-impl<'a> Default for DocumentArgs<'a> {
+impl<'a> Default for ResponseArgs<'a> {
   #[inline]
   fn default() -> Self {
-    DocumentArgs {
-      ident: 0,
-      filename: None,
+    ResponseArgs {
+      session_id: None,
       language: super::common::Language::Unspecified,
-      lines: None,
+      changes: None,
+      line_count: 0,
       error_code: super::common::ErrorCode::NoError,
     }
   }
 }
 
-pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_ident(&mut self, ident: u16) {
-    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
+  pub fn add_session_id(&mut self, session_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_SESSION_ID, session_id);
   }
   #[inline]
-  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
+  pub fn add_language(&mut self, language: super::common::Language) {
+    self.fbb_.push_slot::<super::common::Language>(Response::VT_LANGUAGE, language, super::common::Language::Unspecified);
   }
   #[inline]
-  pub fn add_language(&mut self, language: super::common::Language) {
-    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
+  pub fn add_changes(&mut self, changes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Change<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_CHANGES, changes);
   }
   #[inline]
-  pub fn add_lines(&mut self, lines: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_LINES, lines);
+  pub fn add_line_count(&mut self, line_count: u32) {
+    self.fbb_.push_slot::<u32>(Response::VT_LINE_COUNT, line_count, 0);
   }
   #[inline]
   pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
-    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+    self.fbb_.push_slot::<super::common::ErrorCode>(Response::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    DocumentBuilder {
+    ResponseBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Document<'_> {
+impl core::fmt::Debug for Response<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Document");
-      ds.field("ident", &self.ident());
-      ds.field("filename", &self.filename());
+    let mut ds = f.debug_struct("Response");
+      ds.field("session_id", &self.session_id());
       ds.field("language", &self.language());
-      ds.field("lines", &self.lines());
+      ds.field("changes", &self.changes());
+      ds.field("line_count", &self.line_count());
       ds.field("error_code", &self.error_code());
       ds.finish()
   }
 }
-}  // pub mod html
+}  // pub mod session
 
 #[allow(unused_imports, dead_code)]
-pub mod spans {
+pub mod ws {
 
   use core::mem;
   use core::cmp::Ordering;
@@ -872,530 +13702,600 @@ pub mod spans {
   extern crate flatbuffers;
   use self::flatbuffers::{EndianScalar, Follow};
 
-pub enum RequestOffset {}
+pub enum ClientMessageOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Request<'a> {
+/// A message from a client on the `/v1/ws` WebSocket. Binary messages are Flatbuffers
+/// `ClientMessage`s, and text messages are the same in JSON, where `request` is an object.
+pub struct ClientMessage<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Request<'a> {
-  type Inner = Request<'a>;
+impl<'a> flatbuffers::Follow<'a> for ClientMessage<'a> {
+  type Inner = ClientMessage<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Request<'a> {
-  pub const VT_FILES: flatbuffers::VOffsetT = 4;
-  pub const VT_TIMEOUT_MS: flatbuffers::VOffsetT = 6;
+impl<'a> ClientMessage<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_ENDPOINT: flatbuffers::VOffsetT = 6;
+  pub const VT_REQUEST: flatbuffers::VOffsetT = 8;
+  pub const VT_CANCEL: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Request { _tab: table }
+    ClientMessage { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args RequestArgs<'args>
-  ) -> flatbuffers::WIPOffset<Request<'bldr>> {
-    let mut builder = RequestBuilder::new(_fbb);
-    builder.add_timeout_ms(args.timeout_ms);
-    if let Some(x) = args.files { builder.add_files(x); }
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ClientMessageArgs<'args>
+  ) -> flatbuffers::WIPOffset<ClientMessage<'bldr>> {
+    let mut builder = ClientMessageBuilder::new(_fbb);
+    builder.add_id(args.id);
+    if let Some(x) = args.request { builder.add_request(x); }
+    if let Some(x) = args.endpoint { builder.add_endpoint(x); }
+    builder.add_cancel(args.cancel);
     builder.finish()
   }
 
 
+  /// Chosen by the client, and repeated on every message about this request. It must not be
+  /// the ID of a request that hasn't finished yet.
   #[inline]
-  pub fn files(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>> {
+  pub fn id(&self) -> u64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File>>>>(Request::VT_FILES, None)}
+    unsafe { self._tab.get::<u64>(ClientMessage::VT_ID, Some(0)).unwrap()}
   }
+  /// The batch endpoint to run `request` on, like `html` for `/v1/html`.
   #[inline]
-  pub fn timeout_ms(&self) -> u64 {
+  pub fn endpoint(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(Request::VT_TIMEOUT_MS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ClientMessage::VT_ENDPOINT, None)}
+  }
+  /// A request for `endpoint`, exactly as it would be POSTed.
+  #[inline]
+  pub fn request(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ClientMessage::VT_REQUEST, None)}
+  }
+  /// If true, cancel the unfinished request with this `id` instead.
+  #[inline]
+  pub fn cancel(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ClientMessage::VT_CANCEL, Some(false)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Request<'_> {
+impl flatbuffers::Verifiable for ClientMessage<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<super::common::File>>>>("files", Self::VT_FILES, false)?
-     .visit_field::<u64>("timeout_ms", Self::VT_TIMEOUT_MS, false)?
+     .visit_field::<u64>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("endpoint", Self::VT_ENDPOINT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("request", Self::VT_REQUEST, false)?
+     .visit_field::<bool>("cancel", Self::VT_CANCEL, false)?
      .finish();
     Ok(())
   }
 }
-pub struct RequestArgs<'a> {
-    pub files: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<super::common::File<'a>>>>>,
-    pub timeout_ms: u64,
+pub struct ClientMessageArgs<'a> {
+    pub id: u64,
+    pub endpoint: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub request: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub cancel: bool,
 }
-impl<'a> Default for RequestArgs<'a> {
+impl<'a> Default for ClientMessageArgs<'a> {
   #[inline]
   fn default() -> Self {
-    RequestArgs {
-      files: None,
-      timeout_ms: 0,
+    ClientMessageArgs {
+      id: 0,
+      endpoint: None,
+      request: None,
+      cancel: false,
     }
   }
 }
 
-pub struct RequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ClientMessageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ClientMessageBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_files(&mut self, files: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<super::common::File<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Request::VT_FILES, files);
+  pub fn add_id(&mut self, id: u64) {
+    self.fbb_.push_slot::<u64>(ClientMessage::VT_ID, id, 0);
   }
   #[inline]
-  pub fn add_timeout_ms(&mut self, timeout_ms: u64) {
-    self.fbb_.push_slot::<u64>(Request::VT_TIMEOUT_MS, timeout_ms, 0);
+  pub fn add_endpoint(&mut self, endpoint: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ClientMessage::VT_ENDPOINT, endpoint);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
+  pub fn add_request(&mut self, request: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ClientMessage::VT_REQUEST, request);
+  }
+  #[inline]
+  pub fn add_cancel(&mut self, cancel: bool) {
+    self.fbb_.push_slot::<bool>(ClientMessage::VT_CANCEL, cancel, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ClientMessageBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    RequestBuilder {
+    ClientMessageBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Request<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ClientMessage<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Request<'_> {
+impl core::fmt::Debug for ClientMessage<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Request");
-      ds.field("files", &self.files());
-      ds.field("timeout_ms", &self.timeout_ms());
+    let mut ds = f.debug_struct("ClientMessage");
+      ds.field("id", &self.id());
+      ds.field("endpoint", &self.endpoint());
+      ds.field("request", &self.request());
+      ds.field("cancel", &self.cancel());
       ds.finish()
   }
 }
-pub enum ResponseOffset {}
+pub enum ServerMessageOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Response<'a> {
+/// A message from the server about a request. Each document is sent as soon as it's done,
+/// followed by a last message with `done` set.
+pub struct ServerMessage<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Response<'a> {
-  type Inner = Response<'a>;
+impl<'a> flatbuffers::Follow<'a> for ServerMessage<'a> {
+  type Inner = ServerMessage<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Response<'a> {
-  pub const VT_HIGHLIGHT_NAMES: flatbuffers::VOffsetT = 4;
-  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 6;
+impl<'a> ServerMessage<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_RESPONSE: flatbuffers::VOffsetT = 6;
+  pub const VT_DONE: flatbuffers::VOffsetT = 8;
+  pub const VT_ERROR: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Response { _tab: table }
+    ServerMessage { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<Response<'bldr>> {
-    let mut builder = ResponseBuilder::new(_fbb);
-    if let Some(x) = args.documents { builder.add_documents(x); }
-    if let Some(x) = args.highlight_names { builder.add_highlight_names(x); }
+    args: &'args ServerMessageArgs<'args>
+  ) -> flatbuffers::WIPOffset<ServerMessage<'bldr>> {
+    let mut builder = ServerMessageBuilder::new(_fbb);
+    builder.add_id(args.id);
+    if let Some(x) = args.error { builder.add_error(x); }
+    if let Some(x) = args.response { builder.add_response(x); }
+    builder.add_done(args.done);
     builder.finish()
   }
 
 
+  /// The `id` of the request.
   #[inline]
-  pub fn highlight_names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn id(&self) -> u64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Response::VT_HIGHLIGHT_NAMES, None)}
+    unsafe { self._tab.get::<u64>(ServerMessage::VT_ID, Some(0)).unwrap()}
   }
+  /// A response from the request's endpoint with a single document. Empty in the last message.
   #[inline]
-  pub fn documents(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>> {
+  pub fn response(&self) -> Option<flatbuffers::Vector<'a, u8>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document>>>>(Response::VT_DOCUMENTS, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ServerMessage::VT_RESPONSE, None)}
+  }
+  /// True on the last message about a request.
+  #[inline]
+  pub fn done(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ServerMessage::VT_DONE, Some(false)).unwrap()}
+  }
+  /// On the last message, why the request failed as a whole, if it did: a message a batch
+  /// endpoint would have returned with an error status, or `Cancelled`. An `error` without
+  /// `done` means a message was rejected because its `id` was already in use; the request that
+  /// has that `id` carries on.
+  #[inline]
+  pub fn error(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ServerMessage::VT_ERROR, None)}
   }
 }
 
-impl flatbuffers::Verifiable for Response<'_> {
+impl flatbuffers::Verifiable for ServerMessage<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("highlight_names", Self::VT_HIGHLIGHT_NAMES, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Document>>>>("documents", Self::VT_DOCUMENTS, false)?
+     .visit_field::<u64>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("response", Self::VT_RESPONSE, false)?
+     .visit_field::<bool>("done", Self::VT_DONE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ResponseArgs<'a> {
-    pub highlight_names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub documents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Document<'a>>>>>,
+pub struct ServerMessageArgs<'a> {
+    pub id: u64,
+    pub response: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub done: bool,
+    pub error: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for ResponseArgs<'a> {
+impl<'a> Default for ServerMessageArgs<'a> {
   #[inline]
   fn default() -> Self {
-    ResponseArgs {
-      highlight_names: None,
-      documents: None,
+    ServerMessageArgs {
+      id: 0,
+      response: None,
+      done: false,
+      error: None,
     }
   }
 }
 
-pub struct ResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ServerMessageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResponseBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ServerMessageBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_highlight_names(&mut self, highlight_names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_HIGHLIGHT_NAMES, highlight_names);
+  pub fn add_id(&mut self, id: u64) {
+    self.fbb_.push_slot::<u64>(ServerMessage::VT_ID, id, 0);
   }
   #[inline]
-  pub fn add_documents(&mut self, documents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Document<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Response::VT_DOCUMENTS, documents);
+  pub fn add_response(&mut self, response: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ServerMessage::VT_RESPONSE, response);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ResponseBuilder<'a, 'b, A> {
+  pub fn add_done(&mut self, done: bool) {
+    self.fbb_.push_slot::<bool>(ServerMessage::VT_DONE, done, false);
+  }
+  #[inline]
+  pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ServerMessage::VT_ERROR, error);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ServerMessageBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ResponseBuilder {
+    ServerMessageBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Response<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ServerMessage<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Response<'_> {
+impl core::fmt::Debug for ServerMessage<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Response");
-      ds.field("highlight_names", &self.highlight_names());
-      ds.field("documents", &self.documents());
+    let mut ds = f.debug_struct("ServerMessage");
+      ds.field("id", &self.id());
+      ds.field("response", &self.response());
+      ds.field("done", &self.done());
+      ds.field("error", &self.error());
       ds.finish()
   }
 }
-pub enum SpanOffset {}
+}  // pub mod ws
+
+#[allow(unused_imports, dead_code)]
+pub mod stream {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum TrailerOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Span<'a> {
+/// How a streamed batch went, sent once every document has been.
+pub struct Trailer<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Span<'a> {
-  type Inner = Span<'a>;
+impl<'a> flatbuffers::Follow<'a> for Trailer<'a> {
+  type Inner = Trailer<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Span<'a> {
-  pub const VT_INDEX: flatbuffers::VOffsetT = 4;
-  pub const VT_START: flatbuffers::VOffsetT = 6;
-  pub const VT_END: flatbuffers::VOffsetT = 8;
+impl<'a> Trailer<'a> {
+  pub const VT_DOCUMENTS: flatbuffers::VOffsetT = 4;
+  pub const VT_SUCCEEDED: flatbuffers::VOffsetT = 6;
+  pub const VT_FAILED: flatbuffers::VOffsetT = 8;
+  pub const VT_TIMED_OUT: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Span { _tab: table }
+    Trailer { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SpanArgs
-  ) -> flatbuffers::WIPOffset<Span<'bldr>> {
-    let mut builder = SpanBuilder::new(_fbb);
-    builder.add_end(args.end);
-    builder.add_start(args.start);
-    builder.add_index(args.index);
+    args: &'args TrailerArgs
+  ) -> flatbuffers::WIPOffset<Trailer<'bldr>> {
+    let mut builder = TrailerBuilder::new(_fbb);
+    builder.add_timed_out(args.timed_out);
+    builder.add_failed(args.failed);
+    builder.add_succeeded(args.succeeded);
+    builder.add_documents(args.documents);
     builder.finish()
   }
 
 
+  /// How many documents were sent.
   #[inline]
-  pub fn index(&self) -> u16 {
+  pub fn documents(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(Span::VT_INDEX, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u32>(Trailer::VT_DOCUMENTS, Some(0)).unwrap()}
   }
+  /// How many of them have no error code.
   #[inline]
-  pub fn start(&self) -> u64 {
+  pub fn succeeded(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(Span::VT_START, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u32>(Trailer::VT_SUCCEEDED, Some(0)).unwrap()}
   }
+  /// How many of them have an error code, including those that timed out.
   #[inline]
-  pub fn end(&self) -> u64 {
+  pub fn failed(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(Span::VT_END, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u32>(Trailer::VT_FAILED, Some(0)).unwrap()}
+  }
+  /// How many of them timed out.
+  #[inline]
+  pub fn timed_out(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Trailer::VT_TIMED_OUT, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Span<'_> {
+impl flatbuffers::Verifiable for Trailer<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u16>("index", Self::VT_INDEX, false)?
-     .visit_field::<u64>("start", Self::VT_START, false)?
-     .visit_field::<u64>("end", Self::VT_END, false)?
+     .visit_field::<u32>("documents", Self::VT_DOCUMENTS, false)?
+     .visit_field::<u32>("succeeded", Self::VT_SUCCEEDED, false)?
+     .visit_field::<u32>("failed", Self::VT_FAILED, false)?
+     .visit_field::<u32>("timed_out", Self::VT_TIMED_OUT, false)?
      .finish();
     Ok(())
   }
 }
-pub struct SpanArgs {
-    pub index: u16,
-    pub start: u64,
-    pub end: u64,
+pub struct TrailerArgs {
+    pub documents: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    pub timed_out: u32,
 }
-impl<'a> Default for SpanArgs {
+impl<'a> Default for TrailerArgs {
   #[inline]
   fn default() -> Self {
-    SpanArgs {
-      index: 0,
-      start: 0,
-      end: 0,
+    TrailerArgs {
+      documents: 0,
+      succeeded: 0,
+      failed: 0,
+      timed_out: 0,
     }
   }
 }
 
-pub struct SpanBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct TrailerBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpanBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TrailerBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_index(&mut self, index: u16) {
-    self.fbb_.push_slot::<u16>(Span::VT_INDEX, index, 0);
+  pub fn add_documents(&mut self, documents: u32) {
+    self.fbb_.push_slot::<u32>(Trailer::VT_DOCUMENTS, documents, 0);
   }
   #[inline]
-  pub fn add_start(&mut self, start: u64) {
-    self.fbb_.push_slot::<u64>(Span::VT_START, start, 0);
+  pub fn add_succeeded(&mut self, succeeded: u32) {
+    self.fbb_.push_slot::<u32>(Trailer::VT_SUCCEEDED, succeeded, 0);
   }
   #[inline]
-  pub fn add_end(&mut self, end: u64) {
-    self.fbb_.push_slot::<u64>(Span::VT_END, end, 0);
+  pub fn add_failed(&mut self, failed: u32) {
+    self.fbb_.push_slot::<u32>(Trailer::VT_FAILED, failed, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SpanBuilder<'a, 'b, A> {
+  pub fn add_timed_out(&mut self, timed_out: u32) {
+    self.fbb_.push_slot::<u32>(Trailer::VT_TIMED_OUT, timed_out, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TrailerBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SpanBuilder {
+    TrailerBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Span<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Trailer<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Span<'_> {
+impl core::fmt::Debug for Trailer<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Span");
-      ds.field("index", &self.index());
-      ds.field("start", &self.start());
-      ds.field("end", &self.end());
+    let mut ds = f.debug_struct("Trailer");
+      ds.field("documents", &self.documents());
+      ds.field("succeeded", &self.succeeded());
+      ds.field("failed", &self.failed());
+      ds.field("timed_out", &self.timed_out());
       ds.finish()
   }
 }
-pub enum DocumentOffset {}
+pub enum FrameOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Document<'a> {
+/// A streamed batch response (`Accept: application/x-flatbuffers-stream`) is a sequence of
+/// size-prefixed `Frame`s: one for each document as soon as it's done, in no particular order,
+/// and then one with the trailer.
+pub struct Frame<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Document<'a> {
-  type Inner = Document<'a>;
+impl<'a> flatbuffers::Follow<'a> for Frame<'a> {
+  type Inner = Frame<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
   }
 }
 
-impl<'a> Document<'a> {
-  pub const VT_IDENT: flatbuffers::VOffsetT = 4;
-  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
-  pub const VT_LANGUAGE: flatbuffers::VOffsetT = 8;
-  pub const VT_SPANS: flatbuffers::VOffsetT = 10;
-  pub const VT_ERROR_CODE: flatbuffers::VOffsetT = 12;
+impl<'a> Frame<'a> {
+  pub const VT_RESPONSE: flatbuffers::VOffsetT = 4;
+  pub const VT_TRAILER: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Document { _tab: table }
+    Frame { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args DocumentArgs<'args>
-  ) -> flatbuffers::WIPOffset<Document<'bldr>> {
-    let mut builder = DocumentBuilder::new(_fbb);
-    if let Some(x) = args.spans { builder.add_spans(x); }
-    if let Some(x) = args.filename { builder.add_filename(x); }
-    builder.add_language(args.language);
-    builder.add_ident(args.ident);
-    builder.add_error_code(args.error_code);
+    args: &'args FrameArgs<'args>
+  ) -> flatbuffers::WIPOffset<Frame<'bldr>> {
+    let mut builder = FrameBuilder::new(_fbb);
+    if let Some(x) = args.trailer { builder.add_trailer(x); }
+    if let Some(x) = args.response { builder.add_response(x); }
     builder.finish()
   }
 
 
+  /// The endpoint's response, with a single document. Empty in the trailer's frame.
   #[inline]
-  pub fn ident(&self) -> u16 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(Document::VT_IDENT, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn filename(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Document::VT_FILENAME, None)}
-  }
-  #[inline]
-  pub fn language(&self) -> super::common::Language {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<super::common::Language>(Document::VT_LANGUAGE, Some(super::common::Language::Unspecified)).unwrap()}
-  }
-  #[inline]
-  pub fn spans(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span<'a>>>> {
+  pub fn response(&self) -> Option<flatbuffers::Vector<'a, u8>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span>>>>(Document::VT_SPANS, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Frame::VT_RESPONSE, None)}
   }
   #[inline]
-  pub fn error_code(&self) -> super::common::ErrorCode {
+  pub fn trailer(&self) -> Option<Trailer<'a>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<super::common::ErrorCode>(Document::VT_ERROR_CODE, Some(super::common::ErrorCode::NoError)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Trailer>>(Frame::VT_TRAILER, None)}
   }
 }
 
-impl flatbuffers::Verifiable for Document<'_> {
+impl flatbuffers::Verifiable for Frame<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u16>("ident", Self::VT_IDENT, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
-     .visit_field::<super::common::Language>("language", Self::VT_LANGUAGE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Span>>>>("spans", Self::VT_SPANS, false)?
-     .visit_field::<super::common::ErrorCode>("error_code", Self::VT_ERROR_CODE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("response", Self::VT_RESPONSE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Trailer>>("trailer", Self::VT_TRAILER, false)?
      .finish();
     Ok(())
   }
 }
-pub struct DocumentArgs<'a> {
-    pub ident: u16,
-    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub language: super::common::Language,
-    pub spans: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Span<'a>>>>>,
-    pub error_code: super::common::ErrorCode,
+pub struct FrameArgs<'a> {
+    pub response: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub trailer: Option<flatbuffers::WIPOffset<Trailer<'a>>>,
 }
-impl<'a> Default for DocumentArgs<'a> {
+impl<'a> Default for FrameArgs<'a> {
   #[inline]
   fn default() -> Self {
-    DocumentArgs {
-      ident: 0,
-      filename: None,
-      language: super::common::Language::Unspecified,
-      spans: None,
-      error_code: super::common::ErrorCode::NoError,
+    FrameArgs {
+      response: None,
+      trailer: None,
     }
   }
 }
 
-pub struct DocumentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct FrameBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DocumentBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_ident(&mut self, ident: u16) {
-    self.fbb_.push_slot::<u16>(Document::VT_IDENT, ident, 0);
-  }
-  #[inline]
-  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_FILENAME, filename);
-  }
-  #[inline]
-  pub fn add_language(&mut self, language: super::common::Language) {
-    self.fbb_.push_slot::<super::common::Language>(Document::VT_LANGUAGE, language, super::common::Language::Unspecified);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FrameBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_spans(&mut self, spans: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Span<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Document::VT_SPANS, spans);
+  pub fn add_response(&mut self, response: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Frame::VT_RESPONSE, response);
   }
   #[inline]
-  pub fn add_error_code(&mut self, error_code: super::common::ErrorCode) {
-    self.fbb_.push_slot::<super::common::ErrorCode>(Document::VT_ERROR_CODE, error_code, super::common::ErrorCode::NoError);
+  pub fn add_trailer(&mut self, trailer: flatbuffers::WIPOffset<Trailer<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Trailer>>(Frame::VT_TRAILER, trailer);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DocumentBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FrameBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    DocumentBuilder {
+    FrameBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Document<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Frame<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Document<'_> {
+impl core::fmt::Debug for Frame<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Document");
-      ds.field("ident", &self.ident());
-      ds.field("filename", &self.filename());
-      ds.field("language", &self.language());
-      ds.field("spans", &self.spans());
-      ds.field("error_code", &self.error_code());
+    let mut ds = f.debug_struct("Frame");
+      ds.field("response", &self.response());
+      ds.field("trailer", &self.trailer());
       ds.finish()
   }
 }
-}  // pub mod spans
+}  // pub mod stream
 }  // pub mod daylight
 
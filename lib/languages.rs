@@ -1,15 +1,17 @@
 use std::collections::BTreeMap;
 use std::path::Path;
 use std::str::FromStr;
-use std::sync::LazyLock;
+use std::sync::{LazyLock, OnceLock};
 
 use crate::daylight_generated::daylight::common::Language as FbLanguage;
+use regex::Regex;
+use tree_sitter::{Query, QueryPredicateArg};
 use tree_sitter_highlight::HighlightConfiguration;
 
 macro_rules! language {
     ($name:ident, $fb_lang:expr, $ts_lang:expr, $lang_name:literal, $query:expr, $exts:expr) => {
         static $name: LazyLock<Config> =
-            LazyLock::new(|| Config::new($fb_lang, $ts_lang.into(), $lang_name, $query, "", "", $exts));
+            LazyLock::new(|| Config::new($fb_lang, $ts_lang.into(), $lang_name, $query, "", &[], $exts));
     };
     ($name:ident, $fb_lang:expr, $ts_lang:expr, $lang_name:literal, $query:expr, $injection:expr, $locals:expr, $exts:expr) => {
         static $name: LazyLock<Config> =
@@ -66,8 +68,15 @@ pub type SharedConfig = &'static Config;
 pub struct Config {
     pub fb_language: FbLanguage,
     pub ts_config: tree_sitter_highlight::HighlightConfiguration,
+    /// Captures the regions that can be folded away, as `@fold`, `@fold.comment` or
+    /// `@fold.imports`.
+    pub folds_query: Query,
     pub name: &'static str,
     pub extensions: &'static [&'static str],
+    tags: OnceLock<Option<TagsConfiguration>>,
+    /// The locals query given to `ts_config`, kept so that it can be compiled on its own.
+    locals_query: String,
+    locals: OnceLock<Option<Query>>,
 }
 
 impl Config {
@@ -77,20 +86,96 @@ impl Config {
         name: &'static str,
         highlights_query: &str,
         injection_query: &str,
-        locals_query: &str,
+        locals_queries: &[&str],
         extensions: &'static [&'static str],
     ) -> Self {
+        let locals_query = locals_queries.join("\n");
         let mut ts_config =
-            HighlightConfiguration::new(ts_language, name, highlights_query, injection_query, locals_query)
+            HighlightConfiguration::new(ts_language, name, highlights_query, injection_query, &locals_query)
                 .expect("Tree-sitter bindings are broken");
         ts_config.configure(&ALL_HIGHLIGHT_NAMES);
+        let (_, folds) = FOLDS_QUERIES
+            .iter()
+            .find(|(language, _)| *language == fb_language)
+            .expect("Every language has a folds query");
+        let folds_query = Query::new(&ts_config.language, &folds.join("\n")).expect("Tree-sitter bindings are broken");
         Config {
             fb_language,
             ts_config,
+            folds_query,
             name,
             extensions,
+            tags: OnceLock::new(),
+            locals_query,
+            locals: OnceLock::new(),
         }
     }
+
+    /// The language's locals query, which captures `@local.scope`, `@local.definition` and
+    /// `@local.reference`, compiled the first time it's asked for. This is the same query that
+    /// highlighting uses. `None` if the language doesn't have one.
+    pub fn locals(&self) -> Option<&Query> {
+        self.locals
+            .get_or_init(|| {
+                (!self.locals_query.is_empty()).then(|| {
+                    Query::new(&self.ts_config.language, &self.locals_query).expect("Tree-sitter bindings are broken")
+                })
+            })
+            .as_ref()
+    }
+
+    /// The language's tags query, compiled the first time it's asked for. `None` if the
+    /// language doesn't have one.
+    pub fn tags(&self) -> Option<&TagsConfiguration> {
+        self.tags
+            .get_or_init(|| {
+                let (_, sources) = TAGS_QUERIES.iter().find(|(language, _)| *language == self.fb_language)?;
+                Some(TagsConfiguration::new(&self.ts_config.language, &sources.join("\n")))
+            })
+            .as_ref()
+    }
+}
+
+/// A compiled tags query, following the conventions of `tree-sitter tags`: each pattern
+/// captures a `@name` and a `@definition.<kind>` or `@reference.<kind>`, and may capture
+/// `@doc` comments, cleaned up by `#strip!` and limited to those directly above the definition
+/// by `#select-adjacent!`.
+pub struct TagsConfiguration {
+    pub query: Query,
+    pub patterns: Vec<TagsPattern>,
+}
+
+/// The directives of one pattern in a tags query.
+#[derive(Default)]
+pub struct TagsPattern {
+    /// What `#strip!` removes from each `@doc` capture.
+    pub doc_strip: Option<Regex>,
+    /// The capture that `@doc` comments must lead up to, line by line, to count.
+    pub doc_adjacent_to: Option<u32>,
+}
+
+impl TagsConfiguration {
+    fn new(language: &tree_sitter::Language, source: &str) -> Self {
+        let query = Query::new(language, source).expect("Tree-sitter bindings are broken");
+        let patterns = (0..query.pattern_count())
+            .map(|i| {
+                let mut pattern = TagsPattern::default();
+                for predicate in query.general_predicates(i) {
+                    match (&*predicate.operator, &*predicate.args) {
+                        ("strip!", [_, QueryPredicateArg::String(regex)]) => {
+                            pattern.doc_strip = Some(Regex::new(&format!("(?m){regex}")).expect("Invalid tags query"));
+                        }
+                        ("select-adjacent!" | "set-adjacent!", [_, QueryPredicateArg::Capture(capture)]) => {
+                            pattern.doc_adjacent_to = Some(*capture);
+                        }
+                        _ => {}
+                    }
+                }
+                pattern
+            })
+            .collect();
+        TagsConfiguration { query, patterns }
+    }
 }
 
 language!(
@@ -133,6 +218,14 @@ language!(
     tree_sitter_css::HIGHLIGHTS_QUERY,
     &["css"]
 );
+language!(
+    DIFF,
+    FbLanguage::Diff,
+    tree_sitter_diff::LANGUAGE,
+    "diff",
+    tree_sitter_diff::HIGHLIGHTS_QUERY,
+    &["diff", "patch"]
+);
 language!(
     GO,
     FbLanguage::Go,
@@ -148,7 +241,7 @@ language!(
     "html",
     tree_sitter_html::HIGHLIGHTS_QUERY,
     tree_sitter_html::INJECTIONS_QUERY,
-    "",
+    &[],
     &["html", "htm"]
 );
 language!(
@@ -166,7 +259,7 @@ language!(
     "javascript",
     tree_sitter_javascript::HIGHLIGHT_QUERY,
     tree_sitter_javascript::INJECTIONS_QUERY,
-    tree_sitter_javascript::LOCALS_QUERY,
+    &[tree_sitter_javascript::LOCALS_QUERY],
     &["js", "mjs", "cjs"]
 );
 language!(
@@ -184,9 +277,19 @@ language!(
     "jsx",
     tree_sitter_javascript::JSX_HIGHLIGHT_QUERY,
     tree_sitter_javascript::INJECTIONS_QUERY,
-    tree_sitter_javascript::LOCALS_QUERY,
+    &[tree_sitter_javascript::LOCALS_QUERY],
     &["jsx"]
 );
+language!(
+    MARKDOWN,
+    FbLanguage::Markdown,
+    tree_sitter_md::LANGUAGE,
+    "markdown",
+    tree_sitter_md::HIGHLIGHT_QUERY_BLOCK,
+    tree_sitter_md::INJECTION_QUERY_BLOCK,
+    &[],
+    &["md", "markdown"]
+);
 language!(
     PYTHON,
     FbLanguage::Python,
@@ -202,7 +305,7 @@ language!(
     "ruby",
     tree_sitter_ruby::HIGHLIGHTS_QUERY,
     "",
-    tree_sitter_ruby::LOCALS_QUERY,
+    &[tree_sitter_ruby::LOCALS_QUERY],
     &["rb"]
 );
 language!(
@@ -212,7 +315,7 @@ language!(
     "rust",
     tree_sitter_rust::HIGHLIGHTS_QUERY,
     tree_sitter_rust::INJECTIONS_QUERY,
-    "",
+    &[],
     &["rs"]
 );
 language!(
@@ -222,7 +325,8 @@ language!(
     "typescript",
     tree_sitter_typescript::HIGHLIGHTS_QUERY,
     "",
-    tree_sitter_typescript::LOCALS_QUERY,
+    // TypeScript's locals query only adds to JavaScript's.
+    &[tree_sitter_javascript::LOCALS_QUERY, tree_sitter_typescript::LOCALS_QUERY],
     &["ts"]
 );
 language!(
@@ -232,10 +336,63 @@ language!(
     "tsx",
     tree_sitter_typescript::HIGHLIGHTS_QUERY,
     "",
-    tree_sitter_typescript::LOCALS_QUERY,
+    &[tree_sitter_javascript::LOCALS_QUERY, tree_sitter_typescript::LOCALS_QUERY],
     &["tsx"]
 );
 
+/// The folds queries of each language, which are our own, since grammars don't ship them.
+static FOLDS_QUERIES: &[(FbLanguage, &[&str])] = &[
+    (FbLanguage::Agda, &[include_str!("../queries/agda/folds.scm")]),
+    (FbLanguage::Bash, &[include_str!("../queries/bash/folds.scm")]),
+    (FbLanguage::C, &[include_str!("../queries/c/folds.scm")]),
+    (FbLanguage::Cpp, &[include_str!("../queries/cpp/folds.scm")]),
+    (FbLanguage::Css, &[include_str!("../queries/css/folds.scm")]),
+    (FbLanguage::Diff, &[include_str!("../queries/diff/folds.scm")]),
+    (FbLanguage::Go, &[include_str!("../queries/go/folds.scm")]),
+    (FbLanguage::Html, &[include_str!("../queries/html/folds.scm")]),
+    (FbLanguage::Java, &[include_str!("../queries/java/folds.scm")]),
+    (FbLanguage::JavaScript, &[include_str!("../queries/javascript/folds.scm")]),
+    (FbLanguage::Json, &[include_str!("../queries/json/folds.scm")]),
+    (
+        FbLanguage::Jsx,
+        &[include_str!("../queries/javascript/folds.scm"), include_str!("../queries/jsx/folds.scm")],
+    ),
+    (FbLanguage::Markdown, &[include_str!("../queries/markdown/folds.scm")]),
+    (FbLanguage::Python, &[include_str!("../queries/python/folds.scm")]),
+    (FbLanguage::Ruby, &[include_str!("../queries/ruby/folds.scm")]),
+    (FbLanguage::Rust, &[include_str!("../queries/rust/folds.scm")]),
+    (
+        FbLanguage::TypeScript,
+        &[include_str!("../queries/javascript/folds.scm"), include_str!("../queries/typescript/folds.scm")],
+    ),
+    (
+        FbLanguage::Tsx,
+        &[
+            include_str!("../queries/javascript/folds.scm"),
+            include_str!("../queries/jsx/folds.scm"),
+            include_str!("../queries/typescript/folds.scm"),
+        ],
+    ),
+];
+
+/// The tags queries of the languages that have them. TypeScript's only adds to JavaScript's.
+static TAGS_QUERIES: &[(FbLanguage, &[&str])] = &[
+    (FbLanguage::C, &[tree_sitter_c::TAGS_QUERY]),
+    (FbLanguage::Cpp, &[tree_sitter_cpp::TAGS_QUERY]),
+    (FbLanguage::Go, &[tree_sitter_go::TAGS_QUERY]),
+    (FbLanguage::Java, &[tree_sitter_java::TAGS_QUERY]),
+    (FbLanguage::JavaScript, &[tree_sitter_javascript::TAGS_QUERY]),
+    (FbLanguage::Jsx, &[tree_sitter_javascript::TAGS_QUERY]),
+    (FbLanguage::Python, &[tree_sitter_python::TAGS_QUERY]),
+    (FbLanguage::Ruby, &[tree_sitter_ruby::TAGS_QUERY]),
+    (FbLanguage::Rust, &[tree_sitter_rust::TAGS_QUERY]),
+    (
+        FbLanguage::TypeScript,
+        &[tree_sitter_javascript::TAGS_QUERY, tree_sitter_typescript::TAGS_QUERY],
+    ),
+    (FbLanguage::Tsx, &[tree_sitter_javascript::TAGS_QUERY, tree_sitter_typescript::TAGS_QUERY]),
+];
+
 static EXTENSION_MAP: LazyLock<BTreeMap<&'static str, &'static Config>> = LazyLock::new(|| {
     let mut map = BTreeMap::new();
     for lang in all_languages() {
@@ -254,6 +411,19 @@ static NAME_MAP: LazyLock<BTreeMap<&'static str, &'static Config>> = LazyLock::n
     map
 });
 
+/// Other names for languages that are neither their own name nor one of their extensions.
+const ALIASES: &[(&str, &str)] = &[
+    ("c++", "cpp"),
+    ("golang", "go"),
+    ("javascriptreact", "jsx"),
+    ("node", "javascript"),
+    ("python3", "python"),
+    ("shell", "bash"),
+    ("shellscript", "bash"),
+    ("typescriptreact", "tsx"),
+    ("zsh", "bash"),
+];
+
 fn all_languages() -> impl Iterator<Item = &'static Config> {
     [
         &*AGDA,
@@ -261,12 +431,14 @@ fn all_languages() -> impl Iterator<Item = &'static Config> {
         &*C,
         &*CPP,
         &*CSS,
+        &*DIFF,
         &*GO,
         &*HTML,
         &*JAVA,
         &*JAVASCRIPT,
         &*JSON,
         &*JSX,
+        &*MARKDOWN,
         &*PYTHON,
         &*RUBY,
         &*RUST,
@@ -284,6 +456,17 @@ pub fn from_name(name: &str) -> Option<&'static Config> {
     NAME_MAP.get(name).copied()
 }
 
+/// Look a language up by the first word of a Markdown fence's info string. Besides names,
+/// this accepts aliases and extensions (`py`, `c++`, `shell`), since that's what people write.
+pub fn from_info_string(name: &str) -> Option<&'static Config> {
+    from_name(name)
+        .or_else(|| {
+            let (_, canonical) = ALIASES.iter().find(|(alias, _)| *alias == name)?;
+            from_name(canonical)
+        })
+        .or_else(|| from_extension(name))
+}
+
 pub fn from_path(path: &Path) -> Option<&'static Config> {
     path.extension()
         .and_then(|ext| ext.to_str())
@@ -300,12 +483,14 @@ impl TryFrom<FbLanguage> for &'static Config {
             FbLanguage::C => Ok(&*C),
             FbLanguage::Cpp => Ok(&*CPP),
             FbLanguage::Css => Ok(&*CSS),
+            FbLanguage::Diff => Ok(&*DIFF),
             FbLanguage::Go => Ok(&*GO),
             FbLanguage::Html => Ok(&*HTML),
             FbLanguage::Java => Ok(&*JAVA),
             FbLanguage::JavaScript => Ok(&*JAVASCRIPT),
             FbLanguage::Json => Ok(&*JSON),
             FbLanguage::Jsx => Ok(&*JSX),
+            FbLanguage::Markdown => Ok(&*MARKDOWN),
             FbLanguage::Python => Ok(&*PYTHON),
             FbLanguage::Ruby => Ok(&*RUBY),
             FbLanguage::Rust => Ok(&*RUST),
@@ -1,9 +1,18 @@
+pub mod classes;
 pub mod client;
+pub mod encoding;
 pub mod errors;
+pub mod fonts;
 pub mod languages;
+pub mod offsets;
 pub mod processors;
 pub mod server;
+pub mod sessions;
+pub mod streaming;
+pub mod themes;
 pub mod thread_locals;
+pub mod websocket;
+pub mod wire;
 
 #[path = "generated/daylight_generated.rs"]
 #[allow(warnings)]
@@ -0,0 +1,100 @@
+use std::cell::Cell;
+use std::ops::Range;
+
+use crate::daylight_generated::daylight::common::OffsetEncoding;
+
+/// Where each line of a document starts, for converting byte offsets into offsets and
+/// `(line, column)` positions in another encoding. Lines end at `\n`; a preceding `\r`
+/// belongs to its line.
+pub struct LineIndex<'a> {
+    contents: &'a [u8],
+    encoding: OffsetEncoding,
+    /// The byte offset of the start of each line.
+    byte_starts: Vec<usize>,
+    /// The start of each line, counted in `encoding`.
+    unit_starts: Vec<usize>,
+    /// The last `(line, byte offset, column)` looked up. Offsets are usually converted in
+    /// ascending order, so scanning on from here keeps long lines from becoming quadratic.
+    last: Cell<(usize, usize, usize)>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(contents: &'a [u8], encoding: OffsetEncoding) -> Self {
+        let byte_starts: Vec<usize> = std::iter::once(0)
+            .chain(contents.iter().enumerate().filter(|(_, b)| **b == b'\n').map(|(i, _)| i + 1))
+            .collect();
+        let unit_starts = match encoding {
+            OffsetEncoding::Utf8 => byte_starts.clone(),
+            _ => std::iter::once(0)
+                .chain(byte_starts.windows(2).scan(0, |total, line| {
+                    *total += units(&contents[line[0]..line[1]], encoding);
+                    Some(*total)
+                }))
+                .collect(),
+        };
+        Self {
+            contents,
+            encoding,
+            byte_starts,
+            unit_starts,
+            last: Cell::new((0, 0, 0)),
+        }
+    }
+
+    /// The start of each line, counted in this index's encoding. There is always at least one line.
+    pub fn line_starts(&self) -> &[usize] {
+        &self.unit_starts
+    }
+
+    /// The bytes spanned by some zero-based lines, including their line endings.
+    pub fn byte_range(&self, lines: Range<usize>) -> Range<usize> {
+        let start = |line: usize| self.byte_starts.get(line).copied().unwrap_or(self.contents.len());
+        start(lines.start)..start(lines.end)
+    }
+
+    /// The zero-based line containing a byte offset.
+    pub fn line(&self, offset: usize) -> usize {
+        self.byte_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    /// The zero-based line and column of a byte offset, with the column counted in this index's encoding.
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        let line = self.line(offset);
+        let line_start = self.byte_starts[line];
+        if self.encoding == OffsetEncoding::Utf8 {
+            return (line, offset - line_start);
+        }
+        let (last_line, last_offset, last_column) = self.last.get();
+        let (from, column) = if last_line == line && last_offset <= offset {
+            (last_offset, last_column)
+        } else {
+            (line_start, 0)
+        };
+        let column = column + units(&self.contents[from..offset], self.encoding);
+        self.last.set((line, offset, column));
+        (line, column)
+    }
+
+    /// A byte offset, converted to an offset in this index's encoding.
+    pub fn offset(&self, offset: usize) -> usize {
+        let (line, column) = self.position(offset);
+        self.unit_starts[line] + column
+    }
+}
+
+/// The length of some UTF-8 text in `encoding`. Invalid UTF-8 is counted a byte at a time,
+/// the way a lossy decoder would replace it.
+pub fn units(text: &[u8], encoding: OffsetEncoding) -> usize {
+    match encoding {
+        OffsetEncoding::Utf16 => text
+            .iter()
+            .map(|&b| match b {
+                0x80..0xc0 => 0, // continuation byte
+                0xf0.. => 2,     // the start of a surrogate pair
+                _ => 1,
+            })
+            .sum(),
+        OffsetEncoding::Utf32 => text.iter().filter(|&&b| !(0x80..0xc0).contains(&b)).count(),
+        _ => text.len(),
+    }
+}
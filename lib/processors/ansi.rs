@@ -0,0 +1,262 @@
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::ansi::{self, ColorMode};
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages;
+use crate::themes::{self, Color, SharedTheme, Style};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, highlight};
+
+/// ANSI processor that returns lines of text containing terminal escape sequences.
+pub struct AnsiProcessor;
+
+pub struct AnsiOptions {
+    theme: SharedTheme,
+    color_mode: ColorMode,
+}
+
+impl Processor for AnsiProcessor {
+    type Output = Vec<String>;
+    type Options = AnsiOptions;
+    type WireRequest = wire::ansi::Request;
+    type WireResponse = wire::ansi::Response;
+
+    fn options(request: &Bytes) -> Result<AnsiOptions, FatalError> {
+        let request = flatbuffers::root::<ansi::Request>(request)?;
+        let name = request.theme().unwrap_or(themes::DEFAULT_THEME);
+        let theme = themes::from_name(name).ok_or_else(|| FatalError::UnknownTheme(name.to_string()))?;
+        Ok(AnsiOptions {
+            theme,
+            color_mode: request.color_mode(),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &AnsiOptions,
+    ) -> Outcome<Vec<String>> {
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let iter = highlight(highlighter, language, &contents, file.include_injections, &cancellation_flag)?;
+            let mut lines = vec![];
+            let mut line = String::new();
+            let mut stack: Vec<Style> = vec![];
+            for event in iter {
+                match event? {
+                    ts::HighlightEvent::HighlightStart(h) => {
+                        // Unstyled captures inherit whatever their enclosing capture looks like.
+                        let style = options.theme.style(h.0);
+                        let inherited = stack.last().copied().unwrap_or_default();
+                        stack.push(if style.is_empty() { inherited } else { style });
+                    }
+                    ts::HighlightEvent::HighlightEnd => {
+                        stack.pop();
+                    }
+                    ts::HighlightEvent::Source { start, end } => {
+                        let style = stack.last().copied().unwrap_or_default();
+                        let text = String::from_utf8_lossy(&contents[start..end]);
+                        for (i, segment) in text.split('\n').enumerate() {
+                            if i > 0 {
+                                line.push('\n');
+                                lines.push(std::mem::take(&mut line));
+                            }
+                            let segment = segment.trim_end_matches('\r');
+                            if !segment.is_empty() {
+                                write_styled(&mut line, segment, style, options.color_mode);
+                            }
+                        }
+                    }
+                }
+            }
+            if !line.is_empty() {
+                line.push('\n');
+                lines.push(line);
+            }
+            Ok(lines)
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(lines) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: lines,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<Vec<String>>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let lines = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let line_offsets: Vec<_> = contents
+                                .iter()
+                                .map(|line| builder.create_string(line))
+                                .collect();
+                            Some(builder.create_vector(&line_offsets))
+                        }
+                        _ => None,
+                    };
+                    ansi::Document::create(
+                        builder,
+                        &ansi::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            lines,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = ansi::Response::create(builder, &ansi::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Append `text` to `line`, wrapped in the escapes for `style` and a trailing reset.
+fn write_styled(line: &mut String, text: &str, style: Style, mode: ColorMode) {
+    if style.is_empty() {
+        line.push_str(text);
+        return;
+    }
+    let mut params: Vec<String> = vec![];
+    if style.bold {
+        params.push("1".into());
+    }
+    if style.italic {
+        params.push("3".into());
+    }
+    if style.underline {
+        params.push("4".into());
+    }
+    if let Some(fg) = style.foreground {
+        params.push(color_params(fg, mode, false));
+    }
+    if let Some(bg) = style.background {
+        params.push(color_params(bg, mode, true));
+    }
+    let _ = write!(line, "\x1b[{}m{}\x1b[0m", params.join(";"), text);
+}
+
+fn color_params(color: Color, mode: ColorMode, background: bool) -> String {
+    match mode {
+        ColorMode::Ansi256 => {
+            format!("{};5;{}", if background { 48 } else { 38 }, to_ansi256(color))
+        }
+        ColorMode::Ansi16 => {
+            let index = to_ansi16(color);
+            let base = match (background, index < 8) {
+                (false, true) => 30,
+                (false, false) => 90 - 8,
+                (true, true) => 40,
+                (true, false) => 100 - 8,
+            };
+            (base + index).to_string()
+        }
+        // Unknown modes are treated like truecolor, which most modern terminals support.
+        _ => format!(
+            "{};2;{};{};{}",
+            if background { 48 } else { 38 },
+            color.r,
+            color.g,
+            color.b
+        ),
+    }
+}
+
+fn distance(a: Color, b: Color) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).unsigned_abs().pow(2);
+    d(a.r, b.r) + d(a.g, b.g) + d(a.b, b.b)
+}
+
+/// The channel intensities of the xterm 6x6x6 color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Map a color onto the xterm 256-color palette, choosing between the nearest color-cube
+/// entry and the nearest step of the grayscale ramp.
+fn to_ansi256(color: Color) -> u8 {
+    let nearest_level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - c as i32).abs())
+            .unwrap_or(0)
+    };
+    let (r, g, b) = (nearest_level(color.r), nearest_level(color.g), nearest_level(color.b));
+    let cube = Color {
+        r: CUBE_LEVELS[r],
+        g: CUBE_LEVELS[g],
+        b: CUBE_LEVELS[b],
+    };
+    let cube_index = 16 + 36 * r + 6 * g + b;
+
+    let average = (color.r as u32 + color.g as u32 + color.b as u32) / 3;
+    let gray_step = (average.saturating_sub(8) / 10).min(23) as u8;
+    let gray_level = 8 + 10 * gray_step;
+    let gray = Color {
+        r: gray_level,
+        g: gray_level,
+        b: gray_level,
+    };
+
+    if distance(color, gray) < distance(color, cube) {
+        232 + gray_step
+    } else {
+        cube_index as u8
+    }
+}
+
+/// The xterm defaults for the 16 basic colors. Terminals are free to remap these,
+/// so this is only ever an approximation.
+const ANSI16_PALETTE: [Color; 16] = [
+    Color::hex(0x000000),
+    Color::hex(0xcd0000),
+    Color::hex(0x00cd00),
+    Color::hex(0xcdcd00),
+    Color::hex(0x0000ee),
+    Color::hex(0xcd00cd),
+    Color::hex(0x00cdcd),
+    Color::hex(0xe5e5e5),
+    Color::hex(0x7f7f7f),
+    Color::hex(0xff0000),
+    Color::hex(0x00ff00),
+    Color::hex(0xffff00),
+    Color::hex(0x5c5cff),
+    Color::hex(0xff00ff),
+    Color::hex(0x00ffff),
+    Color::hex(0xffffff),
+];
+
+fn to_ansi16(color: Color) -> u8 {
+    (0..ANSI16_PALETTE.len())
+        .min_by_key(|&i| distance(color, ANSI16_PALETTE[i]))
+        .unwrap_or(0) as u8
+}
@@ -0,0 +1,263 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter::{Language, Node};
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::diagnostics;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages;
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, parse};
+
+pub use crate::daylight_generated::daylight::diagnostics::DiagnosticKind;
+
+/// Diagnostics processor that reports where files fail to parse.
+pub struct DiagnosticsProcessor;
+
+/// A place where a file doesn't parse.
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// Byte offsets of the skipped text, or of where a missing node should have been.
+    pub start: usize,
+    pub end: usize,
+    /// Zero-based lines and byte columns of `start` and `end`.
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    /// The kinds of node the grammar expected.
+    pub expected: Vec<&'static str>,
+}
+
+/// The diagnostics of a file, and the fraction of it that parsed.
+#[derive(Default)]
+pub struct DiagnosticsDocument {
+    pub diagnostics: Vec<Diagnostic>,
+    pub parse_quality: f32,
+}
+
+impl Processor for DiagnosticsProcessor {
+    type Output = DiagnosticsDocument;
+    type Options = ();
+    type WireRequest = wire::diagnostics::Request;
+    type WireResponse = wire::diagnostics::Response;
+    // An empty file parses cleanly.
+    const ALLOWS_EMPTY_FILES: bool = true;
+
+    fn options(request: &Bytes) -> Result<(), FatalError> {
+        flatbuffers::root::<diagnostics::Request>(request)?;
+        Ok(())
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, _options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        _file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        _options: &(),
+    ) -> Outcome<DiagnosticsDocument> {
+        let result = ThreadState::parse_with_tree_sitter(|parser| {
+            let tree = parse(parser, language, &contents, &cancellation_flag)?;
+            // Tree-sitter numbers terminals before non-terminals, the first of which is the
+            // start rule, so the root's symbol is the number of terminals. When the whole file
+            // is an error, an empty file's root is used instead.
+            let root = match tree.root_node() {
+                root if root.is_error() => parse(parser, language, b"", &cancellation_flag)?.root_node().grammar_id(),
+                root => root.grammar_id(),
+            };
+            let mut diagnostics = vec![];
+            let mut finder = DiagnosticsFinder {
+                language: &language.ts_config.language,
+                terminals: root,
+                diagnostics: &mut diagnostics,
+                cancellation_flag: &cancellation_flag,
+            };
+            finder.find(tree.root_node())?;
+            let error_bytes: usize = diagnostics.iter().map(|d| d.end - d.start).sum();
+            let parse_quality = match contents.len() {
+                0 => 1.0,
+                len => 1.0 - error_bytes as f32 / len as f32,
+            };
+            Ok(DiagnosticsDocument {
+                diagnostics,
+                parse_quality,
+            })
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(document) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: document,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<DiagnosticsDocument>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let (diagnostics, parse_quality) = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let diagnostics: Vec<_> = contents
+                                .diagnostics
+                                .iter()
+                                .map(|diagnostic| {
+                                    let expected: Vec<_> =
+                                        diagnostic.expected.iter().map(|kind| builder.create_string(kind)).collect();
+                                    let expected = Some(builder.create_vector(&expected));
+                                    diagnostics::Diagnostic::create(
+                                        builder,
+                                        &diagnostics::DiagnosticArgs {
+                                            kind: diagnostic.kind,
+                                            start: diagnostic.start as u64,
+                                            end: diagnostic.end as u64,
+                                            start_line: diagnostic.start_line as u32,
+                                            start_column: diagnostic.start_column as u32,
+                                            end_line: diagnostic.end_line as u32,
+                                            end_column: diagnostic.end_column as u32,
+                                            expected,
+                                        },
+                                    )
+                                })
+                                .collect();
+                            (Some(builder.create_vector(&diagnostics)), contents.parse_quality)
+                        }
+                        _ => (None, 0.0),
+                    };
+                    diagnostics::Document::create(
+                        builder,
+                        &diagnostics::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            diagnostics,
+                            parse_quality,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = diagnostics::Response::create(builder, &diagnostics::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Collects the `ERROR` and `MISSING` nodes of a tree.
+struct DiagnosticsFinder<'a> {
+    language: &'a Language,
+    /// Symbols below this are terminals.
+    terminals: u16,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    cancellation_flag: &'a AtomicUsize,
+}
+
+impl DiagnosticsFinder<'_> {
+    /// Collect the diagnostics under a node, without looking inside errors or subtrees that
+    /// parsed cleanly. This walks with a cursor rather than by recursion, which deeply nested
+    /// files would overflow.
+    fn find(&mut self, node: Node) -> Result<(), ts::Error> {
+        let mut cursor = node.walk();
+        loop {
+            if self.cancellation_flag.load(Ordering::SeqCst) != 0 {
+                return Err(ts::Error::Cancelled);
+            }
+            let node = cursor.node();
+            if node.is_error() || node.is_missing() {
+                self.report(node);
+            } else if node.has_error() && cursor.goto_first_child() {
+                continue;
+            }
+            // Move on to the next sibling, leaving the nodes that have none.
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Record an `ERROR` or `MISSING` node.
+    fn report(&mut self, node: Node) {
+        let (kind, expected) = if node.is_error() {
+            (DiagnosticKind::Error, self.expected_before(node))
+        } else {
+            // A missing node has the kind it would have had, like `;`.
+            (DiagnosticKind::Missing, vec![node.kind()])
+        };
+        let (start, end) = (node.start_position(), node.end_position());
+        self.diagnostics.push(Diagnostic {
+            kind,
+            start: node.start_byte(),
+            end: node.end_byte(),
+            start_line: start.row,
+            start_column: start.column,
+            end_line: end.row,
+            end_column: end.column,
+            expected,
+        });
+    }
+
+    /// The visible tokens the grammar would have accepted where an error starts, from the
+    /// parse state after the token before it.
+    fn expected_before(&self, node: Node) -> Vec<&'static str> {
+        // Only tokens have dependable parse states, and comments can go anywhere.
+        let mut previous = previous_leaf(node);
+        while let Some(leaf) = previous
+            && leaf.is_extra()
+        {
+            previous = previous_leaf(leaf);
+        }
+        // With nothing before the error, the parser was in its initial state.
+        let state = previous.map_or(1, |leaf| leaf.next_parse_state());
+        let Some(lookahead) = self.language.lookahead_iterator(state) else {
+            return vec![];
+        };
+        let mut expected: Vec<&'static str> = lookahead
+            .filter(|symbol| *symbol != 0 && *symbol < self.terminals && self.language.node_kind_is_visible(*symbol))
+            .filter_map(|symbol| self.language.node_kind_for_id(symbol))
+            .collect();
+        expected.sort_unstable();
+        expected.dedup();
+        expected
+    }
+}
+
+/// The last token before a node.
+fn previous_leaf(node: Node) -> Option<Node> {
+    let mut current = node;
+    let mut previous = loop {
+        if let Some(sibling) = current.prev_sibling() {
+            break sibling;
+        }
+        current = current.parent()?;
+    };
+    while let Some(last) = previous.child(previous.child_count().wrapping_sub(1)) {
+        previous = last;
+    }
+    Some(previous)
+}
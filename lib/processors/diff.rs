@@ -0,0 +1,529 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use http::StatusCode;
+use similar::{Algorithm, DiffTag, capture_diff_slices, group_diff_ops};
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::classes;
+use crate::daylight_generated::daylight::diff::{self, Layout, LineKind, Markup};
+use crate::daylight_generated::daylight::spans;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages;
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::html::escape_html;
+use super::spans::Span;
+use super::{FileOptions, Outcome, Processor, highlight};
+
+/// Diff processor that returns the changes between two versions of each file, with both
+/// versions highlighted.
+pub struct DiffProcessor;
+
+pub struct DiffOptions {
+    /// The old version of each file, by ident.
+    old_files: HashMap<u16, Bytes>,
+    layout: Layout,
+    markup: Markup,
+    word_diff: bool,
+    /// `None` keeps every line.
+    context_lines: Option<usize>,
+    /// The `class` attribute value for each index into `ALL_HIGHLIGHT_NAMES`.
+    classes: Vec<String>,
+}
+
+/// One line of a diff. A line carries either `html` or `text` and `spans`, depending on the
+/// request's markup.
+#[derive(Default)]
+pub struct DiffLine {
+    pub kind: LineKind,
+    /// Numbered from 1, or 0 for a file the line isn't in.
+    pub old_number: usize,
+    pub new_number: usize,
+    pub html: Option<String>,
+    pub text: Option<String>,
+    /// Offsets are bytes from the start of the line, and `positions` are never set.
+    pub spans: Vec<Span>,
+    /// Bytes from the start of the line that changed from the line it replaced.
+    pub changes: Vec<Range<usize>>,
+}
+
+/// A run of changes and the context around them, laid out as `lines` or `rows`.
+#[derive(Default)]
+pub struct Hunk {
+    pub old_start: usize,
+    pub old_lines: usize,
+    pub new_start: usize,
+    pub new_lines: usize,
+    pub lines: Vec<DiffLine>,
+    pub rows: Vec<(Option<DiffLine>, Option<DiffLine>)>,
+}
+
+/// The hunks of a diffed document.
+#[derive(Default)]
+pub struct DiffDocument {
+    pub hunks: Vec<Hunk>,
+}
+
+impl Processor for DiffProcessor {
+    type Output = DiffDocument;
+    type Options = DiffOptions;
+    type WireRequest = wire::diff::Request;
+    type WireResponse = wire::diff::Response;
+    // A file that was deleted is empty on the new side.
+    const ALLOWS_EMPTY_FILES: bool = true;
+
+    fn options(body: &Bytes) -> Result<DiffOptions, FatalError> {
+        let request = flatbuffers::root::<diff::Request>(body)?;
+        let old_files = request
+            .old_files()
+            .unwrap_or_default()
+            .iter()
+            .map(|file| {
+                let contents = file.contents().map(|c| body.slice_ref(c.bytes())).unwrap_or_default();
+                (file.ident(), contents)
+            })
+            .collect();
+        Ok(DiffOptions {
+            old_files,
+            layout: request.layout(),
+            markup: request.markup(),
+            word_diff: request.word_diff(),
+            context_lines: usize::try_from(request.context_lines()).ok(),
+            classes: classes::server_default().attributes(),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &DiffOptions,
+    ) -> Outcome<DiffDocument> {
+        let old = options.old_files.get(&ident).cloned().unwrap_or_default();
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let mut highlighted = |contents| {
+                Highlighted::new(
+                    highlighter,
+                    language,
+                    contents,
+                    file.include_injections,
+                    &cancellation_flag,
+                )
+            };
+            let old = highlighted(&old)?;
+            let new = highlighted(&contents)?;
+            Ok(diff(&old, &new, options))
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(document) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: document,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<DiffDocument>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let hunks = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let hunks: Vec<_> = contents.hunks.iter().map(|hunk| create_hunk(builder, hunk)).collect();
+                            Some(builder.create_vector(&hunks))
+                        }
+                        _ => None,
+                    };
+                    diff::Document::create(
+                        builder,
+                        &diff::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            hunks,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = diff::Response::create(builder, &diff::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+fn create_hunk<'a>(builder: &mut FlatBufferBuilder<'a>, hunk: &Hunk) -> WIPOffset<diff::Hunk<'a>> {
+    let lines: Vec<_> = hunk.lines.iter().map(|line| create_line(builder, line)).collect();
+    let rows: Vec<_> = hunk
+        .rows
+        .iter()
+        .map(|(old, new)| {
+            let old = old.as_ref().map(|line| create_line(builder, line));
+            let new = new.as_ref().map(|line| create_line(builder, line));
+            diff::Row::create(builder, &diff::RowArgs { old, new })
+        })
+        .collect();
+    let lines = Some(builder.create_vector(&lines));
+    let rows = Some(builder.create_vector(&rows));
+    diff::Hunk::create(
+        builder,
+        &diff::HunkArgs {
+            old_start: hunk.old_start as u32,
+            old_lines: hunk.old_lines as u32,
+            new_start: hunk.new_start as u32,
+            new_lines: hunk.new_lines as u32,
+            lines,
+            rows,
+        },
+    )
+}
+
+fn create_line<'a>(builder: &mut FlatBufferBuilder<'a>, line: &DiffLine) -> WIPOffset<diff::Line<'a>> {
+    let html = line.html.as_deref().map(|html| builder.create_string(html));
+    let text = line.text.as_deref().map(|text| builder.create_string(text));
+    let spans: Vec<_> = line
+        .spans
+        .iter()
+        .map(|span| {
+            let scopes = Some(builder.create_vector(&span.scopes));
+            spans::Span::create(
+                builder,
+                &spans::SpanArgs {
+                    index: span.index as u16,
+                    start: span.start as u64,
+                    end: span.end as u64,
+                    scopes,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+    let spans = Some(builder.create_vector(&spans));
+    let changes: Vec<_> = line
+        .changes
+        .iter()
+        .map(|range| {
+            diff::ByteRange::create(
+                builder,
+                &diff::ByteRangeArgs {
+                    start: range.start as u32,
+                    end: range.end as u32,
+                },
+            )
+        })
+        .collect();
+    let changes = Some(builder.create_vector(&changes));
+    diff::Line::create(
+        builder,
+        &diff::LineArgs {
+            kind: line.kind,
+            old_number: line.old_number as u32,
+            new_number: line.new_number as u32,
+            html,
+            text,
+            spans,
+            changes,
+        },
+    )
+}
+
+/// A highlighted file, split into lines.
+pub(crate) struct Highlighted<'a> {
+    contents: &'a [u8],
+    /// Every piece of the file, with the captures enclosing it, outermost first.
+    pieces: Vec<(Range<usize>, Vec<u16>)>,
+    /// Each line, without its line ending.
+    lines: Vec<Range<usize>>,
+}
+
+impl<'a> Highlighted<'a> {
+    pub(crate) fn new(
+        highlighter: &mut ts::Highlighter,
+        language: languages::SharedConfig,
+        contents: &'a [u8],
+        include_injections: bool,
+        cancellation_flag: &AtomicUsize,
+    ) -> Result<Self, ts::Error> {
+        let mut pieces = vec![];
+        let mut stack: Vec<u16> = vec![];
+        for event in highlight(highlighter, language, contents, include_injections, cancellation_flag)? {
+            match event? {
+                ts::HighlightEvent::HighlightStart(highlight) => stack.push(highlight.0 as u16),
+                ts::HighlightEvent::HighlightEnd => {
+                    stack.pop();
+                }
+                ts::HighlightEvent::Source { start, end } => pieces.push((start..end, stack.clone())),
+            }
+        }
+        let mut lines = vec![];
+        let mut start = 0;
+        for line in contents.split_inclusive(|b| *b == b'\n') {
+            let end = start + line.len();
+            let text_end = end - line.iter().rev().take_while(|b| matches!(b, b'\n' | b'\r')).count();
+            lines.push(start..text_end);
+            start = end;
+        }
+        Ok(Self {
+            contents,
+            pieces,
+            lines,
+        })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub(crate) fn line(&self, line: usize) -> &'a [u8] {
+        &self.contents[self.lines[line].clone()]
+    }
+
+    /// The pieces of a line, with offsets from the start of the line.
+    fn pieces(&self, line: usize) -> impl Iterator<Item = (Range<usize>, &[u16])> {
+        let line = self.lines[line].clone();
+        let first = self.pieces.partition_point(|(range, _)| range.end <= line.start);
+        self.pieces[first..]
+            .iter()
+            .take_while(move |(range, _)| range.start < line.end)
+            .map(move |(range, scopes)| {
+                (
+                    range.start.max(line.start) - line.start..range.end.min(line.end) - line.start,
+                    scopes.as_slice(),
+                )
+            })
+            .filter(|(range, _)| !range.is_empty())
+    }
+
+    /// A line as HTML, with `changes` wrapped in `tag`. `classes` are the attribute values for
+    /// each capture.
+    pub(crate) fn html(&self, line: usize, changes: &[Range<usize>], tag: &str, classes: &[String]) -> String {
+        let text = self.line(line);
+        let mut out = String::new();
+        for (range, scopes) in self.pieces(line) {
+            // Pieces are split wherever a change starts or ends, so that tags nest.
+            let mut start = range.start;
+            while start < range.end {
+                let changed = changes.iter().find(|c| c.contains(&start));
+                let end = match changed {
+                    Some(change) => change.end,
+                    None => changes
+                        .iter()
+                        .map(|c| c.start)
+                        .filter(|&s| s > start)
+                        .min()
+                        .unwrap_or(range.end),
+                }
+                .min(range.end);
+                if changed.is_some() {
+                    let _ = write!(out, "<{tag}>");
+                }
+                for scope in scopes.iter().map(|&scope| &classes[scope as usize]) {
+                    match scope.is_empty() {
+                        true => out.push_str("<span>"),
+                        false => _ = write!(out, "<span class=\"{scope}\">"),
+                    }
+                }
+                escape_html(&mut out, &String::from_utf8_lossy(&text[start..end]));
+                scopes.iter().for_each(|_| out.push_str("</span>"));
+                if changed.is_some() {
+                    let _ = write!(out, "</{tag}>");
+                }
+                start = end;
+            }
+        }
+        out
+    }
+
+    /// The highlighted spans of a line, with offsets from the start of the line.
+    pub(crate) fn spans(&self, line: usize) -> Vec<Span> {
+        self.pieces(line)
+            .filter_map(|(range, scopes)| {
+                Some(Span {
+                    index: *scopes.last()? as usize,
+                    start: range.start,
+                    end: range.end,
+                    positions: None,
+                    scopes: scopes.to_vec(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Split a line into words, runs of whitespace and single symbols, for an intra-line diff.
+/// Bytes outside ASCII count as word characters, so tokens never split a character.
+fn tokens(line: &[u8]) -> Vec<&[u8]> {
+    let class = |b: u8| match b {
+        b if b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80 => 0,
+        b if b.is_ascii_whitespace() => 1,
+        _ => 2,
+    };
+    let mut tokens = vec![];
+    let mut start = 0;
+    for i in 1..=line.len() {
+        if i == line.len() || class(line[i]) != class(line[start]) || class(line[start]) == 2 {
+            tokens.push(&line[start..i]);
+            start = i;
+        }
+    }
+    tokens
+}
+
+/// The bytes of two lines that differ from each other, as ranges in the old line and the new.
+fn word_changes(old: &[u8], new: &[u8]) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let (old_tokens, new_tokens) = (tokens(old), tokens(new));
+    let offsets = |tokens: &[&[u8]]| {
+        std::iter::once(0)
+            .chain(tokens.iter().scan(0, |total, token| {
+                *total += token.len();
+                Some(*total)
+            }))
+            .collect::<Vec<_>>()
+    };
+    let (old_offsets, new_offsets) = (offsets(&old_tokens), offsets(&new_tokens));
+    let mut changes = (vec![], vec![]);
+    for op in capture_diff_slices(Algorithm::Myers, &old_tokens, &new_tokens) {
+        let (tag, old_range, new_range) = op.as_tag_tuple();
+        if tag != DiffTag::Equal {
+            push_change(&mut changes.0, old_offsets[old_range.start]..old_offsets[old_range.end]);
+            push_change(&mut changes.1, new_offsets[new_range.start]..new_offsets[new_range.end]);
+        }
+    }
+    changes
+}
+
+/// Add a change, merging it into the previous one if they touch.
+fn push_change(changes: &mut Vec<Range<usize>>, change: Range<usize>) {
+    if change.is_empty() {
+        return;
+    }
+    match changes.last_mut() {
+        Some(last) if last.end == change.start => last.end = change.end,
+        _ => changes.push(change),
+    }
+}
+
+/// Render one line from one side of the diff.
+fn render_line(
+    side: &Highlighted,
+    index: usize,
+    kind: LineKind,
+    numbers: (usize, usize),
+    changes: Vec<Range<usize>>,
+    options: &DiffOptions,
+) -> DiffLine {
+    let (html, text, spans) = match options.markup {
+        Markup::Spans => (
+            None,
+            Some(String::from_utf8_lossy(side.line(index)).into_owned()),
+            side.spans(index),
+        ),
+        _ => {
+            let tag = if kind == LineKind::Removed { "del" } else { "ins" };
+            (Some(side.html(index, &changes, tag, &options.classes)), None, vec![])
+        }
+    };
+    DiffLine {
+        kind,
+        old_number: numbers.0,
+        new_number: numbers.1,
+        html,
+        text,
+        spans,
+        changes,
+    }
+}
+
+/// Diff two highlighted versions of a file by line, and lay the result out as hunks.
+fn diff(old: &Highlighted, new: &Highlighted, options: &DiffOptions) -> DiffDocument {
+    let old_lines: Vec<&[u8]> = (0..old.len()).map(|i| old.line(i)).collect();
+    let new_lines: Vec<&[u8]> = (0..new.len()).map(|i| new.line(i)).collect();
+    let ops = capture_diff_slices(Algorithm::Myers, &old_lines, &new_lines);
+    let groups = match options.context_lines {
+        Some(n) => group_diff_ops(ops, n)
+            .into_iter()
+            .filter(|group| group.iter().any(|op| op.tag() != DiffTag::Equal))
+            .collect(),
+        None if ops.is_empty() => vec![],
+        None => vec![ops],
+    };
+
+    let mut hunks = vec![];
+    for group in groups {
+        let (_, first_old, first_new) = group[0].as_tag_tuple();
+        let (_, last_old, last_new) = group[group.len() - 1].as_tag_tuple();
+        let (old_lines, new_lines) = (last_old.end - first_old.start, last_new.end - first_new.start);
+        // As in unified diffs, a side without lines starts at the line before the hunk.
+        let start = |first: usize, count: usize| if count == 0 { first } else { first + 1 };
+        let mut hunk = Hunk {
+            old_start: start(first_old.start, old_lines),
+            old_lines,
+            new_start: start(first_new.start, new_lines),
+            new_lines,
+            ..Default::default()
+        };
+        for op in group {
+            let (tag, old_range, new_range) = op.as_tag_tuple();
+            if tag == DiffTag::Equal {
+                for (o, n) in old_range.zip(new_range) {
+                    let numbers = (o + 1, n + 1);
+                    match options.layout {
+                        Layout::SideBySide => hunk.rows.push((
+                            Some(render_line(old, o, LineKind::Context, numbers, vec![], options)),
+                            Some(render_line(new, n, LineKind::Context, numbers, vec![], options)),
+                        )),
+                        _ => hunk
+                            .lines
+                            .push(render_line(new, n, LineKind::Context, numbers, vec![], options)),
+                    }
+                }
+                continue;
+            }
+            // Lines that replaced each other are paired up in order, for the word diff and for rows.
+            let mut removed = vec![];
+            let mut added = vec![];
+            for k in 0..old_range.len().max(new_range.len()) {
+                let (o, n) = (old_range.start + k, new_range.start + k);
+                let (o, n) = (old_range.contains(&o).then_some(o), new_range.contains(&n).then_some(n));
+                let (old_changes, new_changes) = match (o, n) {
+                    (Some(o), Some(n)) if options.word_diff => word_changes(old.line(o), new.line(n)),
+                    _ => Default::default(),
+                };
+                removed.push(o.map(|o| render_line(old, o, LineKind::Removed, (o + 1, 0), old_changes, options)));
+                added.push(n.map(|n| render_line(new, n, LineKind::Added, (0, n + 1), new_changes, options)));
+            }
+            match options.layout {
+                Layout::SideBySide => hunk.rows.extend(removed.into_iter().zip(added)),
+                _ => hunk.lines.extend(removed.into_iter().chain(added).flatten()),
+            }
+        }
+        hunks.push(hunk);
+    }
+    DiffDocument { hunks }
+}
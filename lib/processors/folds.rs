@@ -0,0 +1,183 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter::{Node, QueryCursor, StreamingIterator};
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::folds;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages;
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, parse};
+
+pub use crate::daylight_generated::daylight::folds::FoldKind;
+
+/// Folds processor that returns the regions of each file that can be folded away.
+pub struct FoldsProcessor;
+
+/// A region that can be folded away, between zero-based lines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fold {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub kind: FoldKind,
+}
+
+impl Processor for FoldsProcessor {
+    type Output = Vec<Fold>;
+    type Options = ();
+    type WireRequest = wire::folds::Request;
+    type WireResponse = wire::folds::Response;
+
+    fn options(request: &Bytes) -> Result<(), FatalError> {
+        flatbuffers::root::<folds::Request>(request)?;
+        Ok(())
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, _options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        _file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        _options: &(),
+    ) -> Outcome<Vec<Fold>> {
+        let result = ThreadState::parse_with_tree_sitter(|parser| {
+            let tree = parse(parser, language, &contents, &cancellation_flag)?;
+            find_folds(language, tree.root_node(), &contents, &cancellation_flag)
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(folds) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: folds,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<Vec<Fold>>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let folds = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let folds: Vec<_> = contents
+                                .iter()
+                                .map(|fold| {
+                                    folds::Fold::create(
+                                        builder,
+                                        &folds::FoldArgs {
+                                            start_line: fold.start_line as u32,
+                                            end_line: fold.end_line as u32,
+                                            kind: fold.kind,
+                                        },
+                                    )
+                                })
+                                .collect();
+                            Some(builder.create_vector(&folds))
+                        }
+                        _ => None,
+                    };
+                    folds::Document::create(
+                        builder,
+                        &folds::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            folds,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = folds::Response::create(builder, &folds::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Run a language's folds query over a syntax tree. Comments and imports on consecutive lines
+/// fold together, and when several regions start on the same line, only the largest is kept.
+fn find_folds(
+    language: languages::SharedConfig,
+    root: Node,
+    contents: &[u8],
+    cancellation_flag: &AtomicUsize,
+) -> Result<Vec<Fold>, ts::Error> {
+    let query = &language.folds_query;
+    let names = query.capture_names();
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(query, root, contents);
+    let mut found = vec![];
+    while let Some(m) = matches.next() {
+        if cancellation_flag.load(Ordering::SeqCst) != 0 {
+            return Err(ts::Error::Cancelled);
+        }
+        for capture in m.captures {
+            let kind = match names[capture.index as usize] {
+                "fold" => FoldKind::Region,
+                "fold.comment" => FoldKind::Comment,
+                "fold.imports" => FoldKind::Imports,
+                _ => continue,
+            };
+            // A comment after code on the same line can't be folded without hiding the code.
+            if kind == FoldKind::Comment && !starts_line(capture.node, contents) {
+                continue;
+            }
+            let (start, end) = (capture.node.start_position(), capture.node.end_position());
+            // Nodes that take in their trailing newline end at the start of the next line.
+            let end_line = if end.column == 0 && end.row > start.row { end.row - 1 } else { end.row };
+            found.push(Fold {
+                start_line: start.row,
+                end_line,
+                kind,
+            });
+        }
+    }
+    found.sort_by_key(|fold| (fold.start_line, std::cmp::Reverse(fold.end_line)));
+
+    let mut folds: Vec<Fold> = vec![];
+    for fold in found {
+        if fold.kind != FoldKind::Region
+            && let Some(run) = folds.iter_mut().rev().find(|run| run.kind == fold.kind)
+            && fold.start_line <= run.end_line + 1
+        {
+            run.end_line = run.end_line.max(fold.end_line);
+            continue;
+        }
+        folds.push(fold);
+    }
+    folds.retain(|fold| fold.end_line > fold.start_line);
+    // Sorted by start line, with the largest first, so the first of each line is kept.
+    folds.sort_by_key(|fold| (fold.start_line, std::cmp::Reverse(fold.end_line)));
+    folds.dedup_by_key(|fold| fold.start_line);
+    Ok(folds)
+}
+
+/// Whether only whitespace comes before a node on its first line.
+fn starts_line(node: Node, contents: &[u8]) -> bool {
+    let before = &contents[..node.start_byte()];
+    let line_start = before.iter().rposition(|b| *b == b'\n').map_or(0, |i| i + 1);
+    before[line_start..].iter().all(u8::is_ascii_whitespace)
+}
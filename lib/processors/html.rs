@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+use std::fmt::Write;
 use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 
@@ -7,107 +9,307 @@ use http::StatusCode;
 use tracing::instrument;
 use tree_sitter_highlight as ts;
 
+use crate::classes::ClassNames;
 use crate::daylight_generated::daylight::html;
+use crate::encoding::Encoding;
 use crate::errors::{FatalError, NonFatalError};
 use crate::languages;
+use crate::themes::{self, CODE_CSS, EMPHASIZED_CSS, LINE_CSS, LINE_NUMBER_CSS, SharedTheme};
 use crate::thread_locals::ThreadState;
+use crate::wire;
 
-use super::{Outcome, Processor};
+use super::references::{LinkedEvents, link_index, links, open_link, scope_graph};
+use super::{FileOptions, Outcome, Processor, highlight};
 
 /// HTML processor that returns formatted HTML strings.
 pub struct HtmlProcessor;
 
+pub struct HtmlOptions {
+    theme: SharedTheme,
+    inline_styles: bool,
+    attributes: Attributes,
+    wrap_in_pre: bool,
+    full_document: bool,
+    /// 1-based, inclusive line ranges.
+    emphasized_lines: Vec<(usize, usize)>,
+    link_references: bool,
+}
+
+/// The lines of a highlighted document, and the complete document if it was asked for.
+#[derive(Default)]
+pub struct HtmlDocument {
+    pub lines: Vec<String>,
+    pub rendered: Option<String>,
+}
+
 impl Processor for HtmlProcessor {
-    type Output = String;
+    type Output = HtmlDocument;
+    type Options = HtmlOptions;
+    type WireRequest = wire::html::Request;
+    type WireResponse = wire::html::Response;
 
-    #[instrument(skip(language, contents, cancellation_flag))]
+    fn options(request: &Bytes) -> Result<HtmlOptions, FatalError> {
+        let request = flatbuffers::root::<html::Request>(request)?;
+        let name = request.theme().unwrap_or(themes::DEFAULT_THEME);
+        let theme = themes::from_name(name).ok_or_else(|| FatalError::UnknownTheme(name.to_string()))?;
+        let classes = ClassNames::for_request(request.class_style(), request.class_prefix())
+            .map_err(|reason| FatalError::InvalidRequest(Encoding::Flatbuffers, reason))?;
+        Ok(HtmlOptions {
+            theme,
+            inline_styles: request.inline_styles(),
+            attributes: Attributes::new(theme, request.inline_styles(), &classes),
+            wrap_in_pre: request.wrap_in_pre(),
+            full_document: request.full_document(),
+            emphasized_lines: request
+                .emphasized_lines()
+                .unwrap_or_default()
+                .iter()
+                .map(|range| (range.start() as usize, range.end() as usize))
+                .collect(),
+            link_references: request.link_references(),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
     fn process(
         ident: u16,
         filename: Arc<str>,
         language: languages::SharedConfig,
         contents: Bytes,
-        include_injections: bool,
+        file: &FileOptions,
         cancellation_flag: Arc<AtomicUsize>,
-    ) -> Outcome<String> {
+        options: &HtmlOptions,
+    ) -> Outcome<HtmlDocument> {
         let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
-            let iter = {
-                highlighter.highlight(
-                    &language.ts_config,
-                    &contents,
-                    Some(&cancellation_flag),
-                    |s| {
-                        if include_injections {
-                            languages::from_name(s).map(|l| &l.ts_config)
-                        } else {
-                            None
-                        }
-                    },
-                )
-            }?;
-
-            ThreadState::render_with_tree_sitter(|renderer| {
-                renderer.reset();
-                renderer.render(iter, &contents, &|highlight, output| {
-                    let kind = languages::ALL_HIGHLIGHT_NAMES[highlight.0];
-                    output.extend_from_slice(b"class=\"");
-                    output.extend_from_slice(kind.as_bytes());
-                    output.extend_from_slice(b"\"");
-                })?;
-                Ok(renderer.lines().map(String::from).collect())
-            })
+            let links = match options.link_references {
+                true => links(&scope_graph(language, &contents, &cancellation_flag)?),
+                false => vec![],
+            };
+            let iter = highlight(highlighter, language, &contents, file.include_injections, &cancellation_flag)?;
+
+            let mut defined = vec![false; links.len()];
+            let lines = render_lines(LinkedEvents::new(iter, &links), &contents, |highlight, output| {
+                match link_index(highlight) {
+                    Some(i) => open_link(output, &links[i].1, &mut defined),
+                    None => {
+                        options.attributes.write(output, highlight);
+                        "</span>"
+                    }
+                }
+            })?;
+            let window = file.window(lines.len());
+            let mut lines: Vec<String> = lines.into_iter().skip(window.start).take(window.len()).collect();
+            let rendered = options
+                .full_document
+                .then(|| render_document(&lines, window.start + 1, language.name, options));
+            if options.wrap_in_pre {
+                wrap_in_pre(&mut lines, options.theme);
+            }
+            Ok(HtmlDocument { lines, rendered })
         })
         .map_err(|e: ts::Error| NonFatalError::from(e));
 
         match result {
-            Ok(lines) => Outcome::Success {
+            Ok(document) => Outcome::Success {
                 ident,
                 filename,
                 language,
-                contents: lines,
+                contents: document,
             },
-            Err(err) => {
-                Outcome::failure(ident, filename, Some(language), NonFatalError::from(err))
-            }
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
         }
     }
 
     #[instrument(skip(outputs), fields(count = outputs.len()))]
     fn build_response(
-        outputs: Vec<Outcome<String>>,
+        outputs: Vec<Outcome<HtmlDocument>>,
     ) -> Result<axum::response::Response, FatalError> {
-        ThreadState::build_flatbuffers(|mut builder| {
+        ThreadState::build_flatbuffers(|builder| {
             builder.reset();
             let documents = outputs
                 .into_iter()
                 .map(|doc| {
                     let filename = builder.create_string(doc.filename());
-                    let lines = match doc {
+                    let (lines, rendered) = match doc {
                         Outcome::Success { ref contents, .. } => {
                             let line_offsets: Vec<_> = contents
-                                .into_iter()
+                                .lines
+                                .iter()
                                 .map(|line| builder.create_string(line))
                                 .collect();
-                            Some(builder.create_vector(&line_offsets))
+                            let rendered = contents.rendered.as_deref().map(|r| builder.create_string(r));
+                            (Some(builder.create_vector(&line_offsets)), rendered)
                         }
-                        _ => None,
+                        _ => (None, None),
                     };
                     html::Document::create(
-                        &mut builder,
+                        builder,
                         &html::DocumentArgs {
                             ident: doc.ident(),
                             filename: Some(filename),
                             language: doc.language(),
                             lines,
                             error_code: doc.error_code(),
+                            rendered,
                         },
                     )
                 })
                 .collect::<Vec<_>>();
             let documents = Some(builder.create_vector(&documents));
-            let response = html::Response::create(&mut builder, &html::ResponseArgs { documents });
+            let response = html::Response::create(builder, &html::ResponseArgs { documents });
             builder.finish(response, None);
             let response_bytes = builder.finished_data();
             Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
         })
     }
 }
+
+/// The attribute written for each highlight: a `class` for use with a stylesheet, or a
+/// self-contained `style` drawn from a theme.
+pub(crate) enum Attributes {
+    /// The `class` attribute value for each index into `ALL_HIGHLIGHT_NAMES`.
+    Classes(Vec<String>),
+    Inline(SharedTheme),
+}
+
+impl Attributes {
+    pub(crate) fn new(theme: SharedTheme, inline_styles: bool, classes: &ClassNames) -> Self {
+        if inline_styles {
+            Attributes::Inline(theme)
+        } else {
+            Attributes::Classes(classes.attributes())
+        }
+    }
+
+    /// Write the start tag of a highlight's `<span>`. A capture with no classes gets a bare
+    /// `<span>`, rather than an empty `class`.
+    pub(crate) fn write(&self, output: &mut Vec<u8>, highlight: ts::Highlight) {
+        let (name, value) = match self {
+            Attributes::Classes(classes) => ("class", Cow::Borrowed(classes[highlight.0].as_str())),
+            Attributes::Inline(theme) => ("style", Cow::Owned(theme.style(highlight.0).inline_css())),
+        };
+        output.extend_from_slice(b"<span");
+        if !value.is_empty() {
+            output.extend_from_slice(format!(" {name}=\"{value}\"").as_bytes());
+        }
+        output.extend_from_slice(b">");
+    }
+}
+
+/// Render highlight events as lines of HTML, the way `ts::HtmlRenderer` does: text is escaped,
+/// carriage returns are dropped, and open elements are closed at the end of each line and opened
+/// again on the next. Unlike it, each highlight's element is up to `open`, which writes the start
+/// tag and returns the end tag, so that links can be `<a>` elements.
+pub(crate) fn render_lines(
+    events: impl Iterator<Item = Result<ts::HighlightEvent, ts::Error>>,
+    source: &[u8],
+    mut open: impl FnMut(ts::Highlight, &mut Vec<u8>) -> &'static str,
+) -> Result<Vec<String>, ts::Error> {
+    let mut lines = vec![];
+    let mut line = vec![];
+    let mut stack: Vec<(ts::Highlight, &'static str)> = vec![];
+    for event in events {
+        match event? {
+            ts::HighlightEvent::HighlightStart(highlight) => {
+                let end = open(highlight, &mut line);
+                stack.push((highlight, end));
+            }
+            ts::HighlightEvent::HighlightEnd => {
+                if let Some((_, end)) = stack.pop() {
+                    line.extend_from_slice(end.as_bytes());
+                }
+            }
+            ts::HighlightEvent::Source { start, end } => {
+                for c in String::from_utf8_lossy(&source[start..end]).chars() {
+                    match c {
+                        '\r' => {}
+                        '\n' => {
+                            for (_, end) in stack.iter().rev() {
+                                line.extend_from_slice(end.as_bytes());
+                            }
+                            line.push(b'\n');
+                            lines.push(String::from_utf8(std::mem::take(&mut line)).expect("Rendered valid UTF-8"));
+                            for (highlight, end) in stack.iter_mut() {
+                                *end = open(*highlight, &mut line);
+                            }
+                        }
+                        '&' => line.extend_from_slice(b"&amp;"),
+                        '<' => line.extend_from_slice(b"&lt;"),
+                        '>' => line.extend_from_slice(b"&gt;"),
+                        '"' => line.extend_from_slice(b"&quot;"),
+                        '\'' => line.extend_from_slice(b"&#39;"),
+                        c => line.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                    }
+                }
+            }
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        line.push(b'\n');
+        lines.push(String::from_utf8(line).expect("Rendered valid UTF-8"));
+    }
+    Ok(lines)
+}
+
+pub(crate) fn escape_html(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Open a themed `<pre>` at the start of the first line and close it at the end of the last.
+fn wrap_in_pre(lines: &mut [String], theme: SharedTheme) {
+    let open = format!("<pre style=\"{}\">", theme.base_style().inline_css());
+    if let Some(first) = lines.first_mut() {
+        first.insert_str(0, &open);
+    }
+    if let Some(last) = lines.last_mut() {
+        last.push_str("</pre>");
+    }
+}
+
+/// Lay rendered lines out as a complete document: a `<pre><code>` grid with a row per line,
+/// each led by a line number that links to the row's own anchor. The first line is `first_line`.
+fn render_document(lines: &[String], first_line: usize, language: &str, options: &HtmlOptions) -> String {
+    let style = |css: &str| {
+        if options.inline_styles {
+            format!(" style=\"{css}\"")
+        } else {
+            String::new()
+        }
+    };
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<pre class=\"daylight\" data-language=\"{language}\"{}><code{}>",
+        style(&options.theme.base_style().inline_css()),
+        style(CODE_CSS),
+    );
+    for (i, line) in lines.iter().enumerate() {
+        let number = first_line + i;
+        let emphasized = options
+            .emphasized_lines
+            .iter()
+            .any(|&(start, end)| (start..=end).contains(&number));
+        let (class, number_css, content_css) = if emphasized {
+            ("line emphasized", format!("{LINE_NUMBER_CSS};{EMPHASIZED_CSS}"), EMPHASIZED_CSS)
+        } else {
+            ("line", LINE_NUMBER_CSS.to_string(), "")
+        };
+        let content_style = if content_css.is_empty() { String::new() } else { style(content_css) };
+        let _ = write!(
+            out,
+            "<span class=\"{class}\" id=\"L{number}\"{}><a class=\"line-number\" href=\"#L{number}\"{}>{number}</a><span class=\"line-content\"{content_style}>{line}</span></span>",
+            style(LINE_CSS),
+            style(&number_css),
+        );
+    }
+    out.push_str("</code></pre>");
+    out
+}
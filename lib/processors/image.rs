@@ -0,0 +1,583 @@
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ab_glyph::{Font, FontArc, PxScale, PxScaleFont, ScaleFont, point};
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tiny_skia as sk;
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::image::{self, ImageFormat};
+use crate::errors::{FatalError, NonFatalError};
+use crate::fonts;
+use crate::languages;
+use crate::themes::{self, Color, SharedTheme, Style};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, highlight};
+
+/// Font sizes are clamped to this range, in pixels.
+const MIN_FONT_SIZE: f32 = 4.0;
+const MAX_FONT_SIZE: f32 = 128.0;
+/// Tabs are expanded to stops this many columns apart.
+const TAB_WIDTH: usize = 4;
+/// PNGs with more pixels than this are refused rather than allocated.
+const MAX_PIXELS: u64 = 64 * 1024 * 1024;
+/// Used when a theme doesn't set its own colors.
+const DEFAULT_FOREGROUND: Color = Color::hex(0x000000);
+const DEFAULT_BACKGROUND: Color = Color::hex(0xffffff);
+/// The close, minimize and zoom buttons in a window's title bar.
+const WINDOW_BUTTONS: [Color; 3] = [Color::hex(0xff5f56), Color::hex(0xffbd2e), Color::hex(0x27c93f)];
+/// How far italic text leans, as a fraction of its height above the baseline.
+const SLANT: f32 = 0.2;
+/// Line numbers are drawn at this opacity, as they are in HTML.
+const LINE_NUMBER_OPACITY: f32 = 0.5;
+/// SVG text is drawn by the viewer, which may not have the server's font.
+const SVG_FONT_FAMILY: &str = "'DejaVu Sans Mono', ui-monospace, Menlo, Consolas, monospace";
+
+/// Image processor that draws each document as an SVG or PNG.
+pub struct ImageProcessor;
+
+pub struct ImageOptions {
+    theme: SharedTheme,
+    format: ImageFormat,
+    font: FontArc,
+    font_size: f32,
+    padding: f32,
+    window_chrome: bool,
+    line_numbers: bool,
+}
+
+/// An encoded image and its size in pixels.
+#[derive(Default)]
+pub struct CodeImage {
+    pub data: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Processor for ImageProcessor {
+    type Output = CodeImage;
+    type Options = ImageOptions;
+    type WireRequest = wire::image::Request;
+    type WireResponse = wire::image::Response;
+
+    fn options(request: &Bytes) -> Result<ImageOptions, FatalError> {
+        let request = flatbuffers::root::<image::Request>(request)?;
+        let name = request.theme().unwrap_or(themes::DEFAULT_THEME);
+        let theme = themes::from_name(name).ok_or_else(|| FatalError::UnknownTheme(name.to_string()))?;
+        Ok(ImageOptions {
+            theme,
+            format: request.format(),
+            // Every file in a request is drawn with the same font, even if another is loaded meanwhile.
+            font: fonts::current(),
+            font_size: match request.font_size() {
+                size if size.is_nan() => MIN_FONT_SIZE,
+                size => size.clamp(MIN_FONT_SIZE, MAX_FONT_SIZE),
+            },
+            padding: request.padding() as f32,
+            window_chrome: request.window_chrome(),
+            line_numbers: request.line_numbers(),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &ImageOptions,
+    ) -> Outcome<CodeImage> {
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let iter = highlight(highlighter, language, &contents, file.include_injections, &cancellation_flag)?;
+            styled_lines(iter, &contents, options.theme)
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e))
+        .and_then(|lines| {
+            let layout = Layout::new(&lines, options);
+            match options.format {
+                ImageFormat::Png => render_png(&lines, &layout, options, &cancellation_flag),
+                _ => Ok(render_svg(&lines, &layout, options)),
+            }
+        });
+
+        match result {
+            Ok(image) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: image,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<CodeImage>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let (image, width, height) = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            (Some(builder.create_vector(&contents.data)), contents.width, contents.height)
+                        }
+                        _ => (None, 0, 0),
+                    };
+                    image::Document::create(
+                        builder,
+                        &image::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            image,
+                            width,
+                            height,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = image::Response::create(builder, &image::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Text drawn in a single style, starting at a column of its line.
+struct Run {
+    style: Style,
+    column: usize,
+    text: String,
+}
+
+impl Run {
+    fn end(&self) -> usize {
+        self.column + self.text.chars().count()
+    }
+}
+
+/// Split highlight events into lines of styled runs. Tabs are expanded, and other control
+/// characters become spaces, so that every character takes up exactly one column.
+fn styled_lines(
+    iter: impl Iterator<Item = Result<ts::HighlightEvent, ts::Error>>,
+    contents: &[u8],
+    theme: SharedTheme,
+) -> Result<Vec<Vec<Run>>, ts::Error> {
+    let mut lines: Vec<Vec<Run>> = vec![vec![]];
+    let mut column = 0;
+    let mut stack: Vec<Style> = vec![];
+    for event in iter {
+        match event? {
+            ts::HighlightEvent::HighlightStart(h) => {
+                // Unstyled captures inherit whatever their enclosing capture looks like.
+                let style = theme.style(h.0);
+                let inherited = stack.last().copied().unwrap_or_default();
+                stack.push(if style.is_empty() { inherited } else { style });
+            }
+            ts::HighlightEvent::HighlightEnd => {
+                stack.pop();
+            }
+            ts::HighlightEvent::Source { start, end } => {
+                let style = stack.last().copied().unwrap_or_default();
+                let text = String::from_utf8_lossy(&contents[start..end]);
+                for (i, segment) in text.split('\n').enumerate() {
+                    if i > 0 {
+                        lines.push(vec![]);
+                        column = 0;
+                    }
+                    let segment = segment.strip_suffix('\r').unwrap_or(segment);
+                    if segment.is_empty() {
+                        continue;
+                    }
+                    let start_column = column;
+                    let mut expanded = String::with_capacity(segment.len());
+                    for c in segment.chars() {
+                        if c == '\t' {
+                            let width = TAB_WIDTH - column % TAB_WIDTH;
+                            expanded.extend(std::iter::repeat_n(' ', width));
+                            column += width;
+                        } else {
+                            expanded.push(if c.is_control() { ' ' } else { c });
+                            column += 1;
+                        }
+                    }
+                    let line = lines.last_mut().expect("there is always a line");
+                    match line.last_mut() {
+                        Some(run) if run.style == style => run.text.push_str(&expanded),
+                        _ => line.push(Run {
+                            style,
+                            column: start_column,
+                            text: expanded,
+                        }),
+                    }
+                }
+            }
+        }
+    }
+    // A trailing newline ends the last line, rather than starting one more.
+    if lines.len() > 1 && lines.last().is_some_and(Vec::is_empty) {
+        lines.pop();
+    }
+    Ok(lines)
+}
+
+/// Where things go in an image, in pixels. Every character is one column, as wide as a space.
+struct Layout {
+    width: u32,
+    height: u32,
+    /// The width of a column.
+    advance: f32,
+    line_height: f32,
+    /// The distance from the top of a line to its baseline.
+    baseline: f32,
+    /// The height of the title bar, if there is one.
+    title_bar: f32,
+    /// The number of columns taken up by line numbers, not counting the gap after them.
+    gutter_columns: usize,
+    code_x: f32,
+    code_y: f32,
+}
+
+impl Layout {
+    fn new(lines: &[Vec<Run>], options: &ImageOptions) -> Self {
+        let font = options.font.as_scaled(px_scale(&options.font, options.font_size));
+        let advance = font.h_advance(font.glyph_id(' '));
+        let line_height = (options.font_size * 1.5).round();
+        let baseline = (line_height - font.height()) / 2.0 + font.ascent();
+        let title_bar = if options.window_chrome {
+            (options.font_size * 2.5).round()
+        } else {
+            0.0
+        };
+        let gutter_columns = if options.line_numbers {
+            lines.len().to_string().len()
+        } else {
+            0
+        };
+        let gutter = if options.line_numbers {
+            (gutter_columns + 2) as f32 * advance
+        } else {
+            0.0
+        };
+        let columns = lines
+            .iter()
+            .filter_map(|line| line.last().map(Run::end))
+            .max()
+            .unwrap_or(0);
+        let code_x = options.padding + gutter;
+        let code_y = title_bar + options.padding;
+        Self {
+            width: (code_x + columns as f32 * advance + options.padding).ceil() as u32,
+            height: (code_y + lines.len() as f32 * line_height + options.padding).ceil() as u32,
+            advance,
+            line_height,
+            baseline,
+            title_bar,
+            gutter_columns,
+            code_x,
+            code_y,
+        }
+    }
+
+    fn x(&self, column: usize) -> f32 {
+        self.code_x + column as f32 * self.advance
+    }
+
+    fn top(&self, line: usize) -> f32 {
+        self.code_y + line as f32 * self.line_height
+    }
+
+    /// Where a line number goes, so that numbers are right-aligned in the gutter.
+    fn line_number_x(&self, number: &str, padding: f32) -> f32 {
+        padding + (self.gutter_columns - number.len()) as f32 * self.advance
+    }
+
+    fn corner_radius(&self) -> f32 {
+        self.title_bar / 5.0
+    }
+
+    /// The center of each window button, and their radius.
+    fn window_buttons(&self) -> impl Iterator<Item = (f32, f32, f32)> + '_ {
+        let radius = self.title_bar / 5.0;
+        (0..WINDOW_BUTTONS.len()).map(move |i| {
+            let x = self.title_bar / 2.0 + i as f32 * radius * 3.2;
+            (x, self.title_bar / 2.0, radius)
+        })
+    }
+}
+
+/// The scale at which a font's em square is `size` pixels, as in CSS.
+fn px_scale(font: &FontArc, size: f32) -> PxScale {
+    let em = font.units_per_em().unwrap_or(font.height_unscaled());
+    PxScale::from(size * font.height_unscaled() / em)
+}
+
+/// Round to hundredths of a pixel, to keep SVG attributes short.
+fn px(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+fn escape_xml(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Draw lines as SVG, with a `<text>` element per line and a `<tspan>` per run. Each run is
+/// placed at its own column, so that a viewer's font can't push later runs out of line.
+fn render_svg(lines: &[Vec<Run>], layout: &Layout, options: &ImageOptions) -> CodeImage {
+    let theme = options.theme;
+    let foreground = theme.foreground.unwrap_or(DEFAULT_FOREGROUND);
+    let background = theme.background.unwrap_or(DEFAULT_BACKGROUND);
+    let (width, height) = (layout.width, layout.height);
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    if options.window_chrome {
+        let radius = px(layout.corner_radius());
+        let _ = write!(
+            svg,
+            r#"<rect width="{width}" height="{height}" rx="{radius}" fill="{background}"/>"#
+        );
+        for ((x, y, r), color) in layout.window_buttons().zip(WINDOW_BUTTONS) {
+            let _ = write!(svg, r#"<circle cx="{}" cy="{}" r="{}" fill="{color}"/>"#, px(x), px(y), px(r));
+        }
+    } else {
+        let _ = write!(svg, r#"<rect width="{width}" height="{height}" fill="{background}"/>"#);
+    }
+    let _ = write!(
+        svg,
+        r#"<g font-family="{SVG_FONT_FAMILY}" font-size="{}" fill="{foreground}" xml:space="preserve">"#,
+        px(options.font_size)
+    );
+    for (i, line) in lines.iter().enumerate() {
+        let top = layout.top(i);
+        for run in line {
+            if let Some(bg) = run.style.background {
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{bg}"/>"#,
+                    px(layout.x(run.column)),
+                    px(top),
+                    px((run.end() - run.column) as f32 * layout.advance),
+                    px(layout.line_height)
+                );
+            }
+        }
+        if line.is_empty() && !options.line_numbers {
+            continue;
+        }
+        let _ = write!(svg, r#"<text y="{}">"#, px(top + layout.baseline));
+        if options.line_numbers {
+            let number = (i + 1).to_string();
+            let _ = write!(
+                svg,
+                r#"<tspan x="{}" fill-opacity="{LINE_NUMBER_OPACITY}">{number}</tspan>"#,
+                px(layout.line_number_x(&number, options.padding))
+            );
+        }
+        for run in line {
+            let _ = write!(svg, r#"<tspan x="{}""#, px(layout.x(run.column)));
+            if let Some(fg) = run.style.foreground {
+                let _ = write!(svg, r#" fill="{fg}""#);
+            }
+            if run.style.bold {
+                svg.push_str(r#" font-weight="bold""#);
+            }
+            if run.style.italic {
+                svg.push_str(r#" font-style="italic""#);
+            }
+            if run.style.underline {
+                svg.push_str(r#" text-decoration="underline""#);
+            }
+            svg.push('>');
+            escape_xml(&mut svg, &run.text);
+            svg.push_str("</tspan>");
+        }
+        svg.push_str("</text>");
+    }
+    svg.push_str("</g></svg>");
+    CodeImage {
+        data: svg.into_bytes(),
+        width,
+        height,
+    }
+}
+
+fn sk_color(color: Color) -> sk::Color {
+    sk::Color::from_rgba8(color.r, color.g, color.b, 255)
+}
+
+fn solid(color: Color) -> sk::Paint<'static> {
+    let mut paint = sk::Paint::default();
+    paint.set_color(sk_color(color));
+    paint.anti_alias = true;
+    paint
+}
+
+/// A rectangle with circular corners, drawn with the usual cubic approximation.
+fn rounded_rect(width: f32, height: f32, radius: f32) -> Option<sk::Path> {
+    let k = radius * (1.0 - 0.552_284_8);
+    let mut path = sk::PathBuilder::new();
+    path.move_to(radius, 0.0);
+    path.line_to(width - radius, 0.0);
+    path.cubic_to(width - k, 0.0, width, k, width, radius);
+    path.line_to(width, height - radius);
+    path.cubic_to(width, height - k, width - k, height, width - radius, height);
+    path.line_to(radius, height);
+    path.cubic_to(k, height, 0.0, height - k, 0.0, height - radius);
+    path.line_to(0.0, radius);
+    path.cubic_to(0.0, k, k, 0.0, radius, 0.0);
+    path.close();
+    path.finish()
+}
+
+/// Composite `color` onto a pixel with some coverage. Pixels outside the image are ignored.
+fn blend(pixmap: &mut sk::Pixmap, x: i32, y: i32, color: Color, coverage: f32) {
+    let (width, height) = (pixmap.width() as i32, pixmap.height() as i32);
+    if !(0..width).contains(&x) || !(0..height).contains(&y) {
+        return;
+    }
+    let pixel = &mut pixmap.pixels_mut()[(y * width + x) as usize];
+    let a = coverage.clamp(0.0, 1.0);
+    let mix = |src: u8, dst: u8| (src as f32 * a + dst as f32 * (1.0 - a)).round() as u8;
+    let alpha = mix(255, pixel.alpha());
+    let (r, g, b) = (
+        mix(color.r, pixel.red()).min(alpha),
+        mix(color.g, pixel.green()).min(alpha),
+        mix(color.b, pixel.blue()).min(alpha),
+    );
+    if let Some(blended) = sk::PremultipliedColorU8::from_rgba(r, g, b, alpha) {
+        *pixel = blended;
+    }
+}
+
+/// Draws text onto a pixmap, one column per character.
+struct Painter<'a> {
+    pixmap: sk::Pixmap,
+    font: PxScaleFont<&'a FontArc>,
+    advance: f32,
+}
+
+impl Painter<'_> {
+    /// Bold and italic are synthesized, by drawing glyphs again a little to the right and by
+    /// shearing them, so that a single font file is enough.
+    fn text(&mut self, x: f32, baseline: f32, text: &str, color: Color, style: Style, opacity: f32) {
+        let emboldening = if style.bold {
+            (self.font.scale().y / 16.0).round().max(1.0) as i32
+        } else {
+            0
+        };
+        for (i, c) in text.chars().enumerate() {
+            if c == ' ' {
+                continue;
+            }
+            let mut glyph = self.font.scaled_glyph(c);
+            glyph.position = point(x + i as f32 * self.advance, baseline);
+            let Some(outline) = self.font.outline_glyph(glyph) else {
+                continue;
+            };
+            let bounds = outline.px_bounds();
+            outline.draw(|gx, gy, coverage| {
+                let y = bounds.min.y as i32 + gy as i32;
+                let mut x = bounds.min.x as i32 + gx as i32;
+                if style.italic {
+                    x += ((baseline - y as f32) * SLANT).round() as i32;
+                }
+                for offset in 0..=emboldening {
+                    blend(&mut self.pixmap, x + offset, y, color, coverage * opacity);
+                }
+            });
+        }
+    }
+}
+
+/// Rasterize lines as a PNG with the server's font.
+fn render_png(
+    lines: &[Vec<Run>],
+    layout: &Layout,
+    options: &ImageOptions,
+    cancellation_flag: &AtomicUsize,
+) -> Result<CodeImage, NonFatalError> {
+    let (width, height) = (layout.width, layout.height);
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(NonFatalError::FileTooLarge);
+    }
+    let pixmap = sk::Pixmap::new(width, height).ok_or(NonFatalError::FileTooLarge)?;
+    let mut painter = Painter {
+        pixmap,
+        font: options.font.as_scaled(px_scale(&options.font, options.font_size)),
+        advance: layout.advance,
+    };
+    let theme = options.theme;
+    let foreground = theme.foreground.unwrap_or(DEFAULT_FOREGROUND);
+    let background = theme.background.unwrap_or(DEFAULT_BACKGROUND);
+    let identity = sk::Transform::identity();
+
+    if options.window_chrome {
+        if let Some(window) = rounded_rect(width as f32, height as f32, layout.corner_radius()) {
+            painter.pixmap.fill_path(&window, &solid(background), sk::FillRule::Winding, identity, None);
+        }
+        for ((x, y, r), color) in layout.window_buttons().zip(WINDOW_BUTTONS) {
+            if let Some(button) = sk::PathBuilder::from_circle(x, y, r) {
+                painter.pixmap.fill_path(&button, &solid(color), sk::FillRule::Winding, identity, None);
+            }
+        }
+    } else {
+        painter.pixmap.fill(sk_color(background));
+    }
+
+    let underline_thickness = (options.font_size / 14.0).max(1.0);
+    for (i, line) in lines.iter().enumerate() {
+        // Drawing a long file takes a while, so give up as soon as the request has timed out.
+        if cancellation_flag.load(Ordering::Relaxed) != 0 {
+            return Err(NonFatalError::TimedOut);
+        }
+        let top = layout.top(i);
+        let baseline = top + layout.baseline;
+        if options.line_numbers {
+            let number = (i + 1).to_string();
+            let x = layout.line_number_x(&number, options.padding);
+            painter.text(x, baseline, &number, foreground, Style::default(), LINE_NUMBER_OPACITY);
+        }
+        for run in line {
+            let x = layout.x(run.column);
+            let run_width = (run.end() - run.column) as f32 * layout.advance;
+            if let Some(bg) = run.style.background
+                && let Some(rect) = sk::Rect::from_xywh(x, top, run_width, layout.line_height)
+            {
+                painter.pixmap.fill_rect(rect, &solid(bg), identity, None);
+            }
+            let color = run.style.foreground.unwrap_or(foreground);
+            painter.text(x, baseline, &run.text, color, run.style, 1.0);
+            if run.style.underline
+                && let Some(rect) = sk::Rect::from_xywh(x, baseline + underline_thickness, run_width, underline_thickness)
+            {
+                painter.pixmap.fill_rect(rect, &solid(color), identity, None);
+            }
+        }
+    }
+
+    let data = painter.pixmap.encode_png().map_err(|_| NonFatalError::UnknownError)?;
+    Ok(CodeImage { data, width, height })
+}
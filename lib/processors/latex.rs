@@ -0,0 +1,203 @@
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::latex::{self, LatexStyle};
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages::{self, ALL_HIGHLIGHT_NAMES};
+use crate::themes::{Style, Theme};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::markup::{self, Markup};
+use super::{FileOptions, Outcome, Processor, highlight};
+
+/// LaTeX processor that returns lines of markup using the macros defined by `preamble`.
+pub struct LatexProcessor;
+
+pub struct LatexOptions {
+    style: LatexStyle,
+}
+
+impl Processor for LatexProcessor {
+    type Output = Vec<String>;
+    type Options = LatexOptions;
+    type WireRequest = wire::latex::Request;
+    type WireResponse = wire::latex::Response;
+
+    fn options(request: &Bytes) -> Result<LatexOptions, FatalError> {
+        let request = flatbuffers::root::<latex::Request>(request)?;
+        Ok(LatexOptions { style: request.style() })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &LatexOptions,
+    ) -> Outcome<Vec<String>> {
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let iter = highlight(highlighter, language, &contents, file.include_injections, &cancellation_flag)?;
+            markup::render_lines(iter, &contents, &options.style)
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(lines) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: lines,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<Vec<String>>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let lines = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let line_offsets: Vec<_> = contents
+                                .iter()
+                                .map(|line| builder.create_string(line))
+                                .collect();
+                            Some(builder.create_vector(&line_offsets))
+                        }
+                        _ => None,
+                    };
+                    latex::Document::create(
+                        builder,
+                        &latex::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            lines,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = latex::Response::create(builder, &latex::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+impl Markup for LatexStyle {
+    fn open(&self, out: &mut String, highlight: usize) {
+        let _ = write!(out, "\\{}{{", macro_name(ALL_HIGHLIGHT_NAMES[highlight]));
+    }
+
+    fn close(&self, out: &mut String) {
+        out.push('}');
+    }
+
+    fn text(&self, out: &mut String, text: &str) {
+        for c in text.chars() {
+            match (*self, c) {
+                // With `commandchars=\\\{\}`, only these three are special inside Verbatim.
+                (LatexStyle::Verbatim, '\\') => out.push_str("\\DaylightZbs{}"),
+                (LatexStyle::Verbatim, '{') => out.push_str("\\DaylightZob{}"),
+                (LatexStyle::Verbatim, '}') => out.push_str("\\DaylightZcb{}"),
+                (LatexStyle::Verbatim, c) => out.push(c),
+                (_, '\\') => out.push_str("\\textbackslash{}"),
+                (_, '{' | '}' | '$' | '&' | '#' | '_' | '%') => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                (_, '^') => out.push_str("\\textasciicircum{}"),
+                (_, '~') => out.push_str("\\textasciitilde{}"),
+                (_, '<') => out.push_str("\\textless{}"),
+                (_, '>') => out.push_str("\\textgreater{}"),
+                (_, '|') => out.push_str("\\textbar{}"),
+                // Outside of Verbatim, whitespace would collapse and take indentation with it.
+                (_, ' ') => out.push_str("\\ "),
+                (_, '\t') => out.push_str("\\ \\ \\ \\ "),
+                (_, c) => out.push(c),
+            }
+        }
+    }
+}
+
+/// The macro used for a capture: `function.method` is `\DaylightFunctionMethod`.
+fn macro_name(capture: &str) -> String {
+    let mut name = String::from("Daylight");
+    for part in capture.split('.') {
+        let mut chars = part.chars();
+        name.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+        name.extend(chars);
+    }
+    name
+}
+
+/// Wrap `#1` in the LaTeX that draws a style.
+fn style_body(style: Style) -> String {
+    let mut body = String::from("#1");
+    if style.bold {
+        body = format!("\\textbf{{{body}}}");
+    }
+    if style.italic {
+        body = format!("\\textit{{{body}}}");
+    }
+    if style.underline {
+        body = format!("\\underline{{{body}}}");
+    }
+    if let Some(fg) = style.foreground {
+        body = format!("\\textcolor[HTML]{{{}}}{{{body}}}", hex(fg));
+    }
+    if let Some(bg) = style.background {
+        body = format!("{{\\setlength{{\\fboxsep}}{{0pt}}\\colorbox[HTML]{{{}}}{{{body}}}}}", hex(bg));
+    }
+    body
+}
+
+fn hex(color: crate::themes::Color) -> String {
+    color.to_string().trim_start_matches('#').to_ascii_uppercase()
+}
+
+/// A preamble defining the macros used by `/v1/latex` output, styled by `theme`. Every capture
+/// gets a macro, so output from any theme (or none) compiles against any preamble.
+pub fn preamble(theme: &Theme) -> String {
+    let mut tex = format!("% daylight theme: {}\n", theme.name);
+    tex.push_str("% Requires \\usepackage{xcolor}. Verbatim output also requires \\usepackage{fancyvrb},\n");
+    tex.push_str("% with lines placed in \\begin{Verbatim}[commandchars=\\\\\\{\\}].\n");
+    tex.push_str("\\newcommand{\\DaylightZbs}{\\char`\\\\}\n");
+    tex.push_str("\\newcommand{\\DaylightZob}{\\char`\\{}\n");
+    tex.push_str("\\newcommand{\\DaylightZcb}{\\char`\\}}\n");
+    if let Some(fg) = theme.foreground {
+        let _ = writeln!(tex, "\\definecolor{{DaylightForeground}}{{HTML}}{{{}}}", hex(fg));
+    }
+    if let Some(bg) = theme.background {
+        let _ = writeln!(tex, "\\definecolor{{DaylightBackground}}{{HTML}}{{{}}}", hex(bg));
+    }
+    for (i, name) in ALL_HIGHLIGHT_NAMES.iter().enumerate() {
+        let _ = writeln!(
+            tex,
+            "\\newcommand{{\\{}}}[1]{{{}}}",
+            macro_name(name),
+            style_body(theme.style(i))
+        );
+    }
+    tex
+}
@@ -0,0 +1,242 @@
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, Parser, Tag, TagEnd};
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::classes::ClassNames;
+use crate::daylight_generated::daylight::markdown;
+use crate::encoding::Encoding;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages;
+use crate::themes::{self, SharedTheme};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::html::{Attributes, escape_html, render_lines};
+use super::{FileOptions, Outcome, Processor, highlight};
+
+/// Markdown processor that returns documents with their fenced code blocks highlighted as HTML.
+pub struct MarkdownProcessor;
+
+pub struct MarkdownOptions {
+    theme: SharedTheme,
+    inline_styles: bool,
+    attributes: Attributes,
+    render_prose: bool,
+}
+
+impl Processor for MarkdownProcessor {
+    type Output = String;
+    type Options = MarkdownOptions;
+    type WireRequest = wire::markdown::Request;
+    type WireResponse = wire::markdown::Response;
+
+    fn options(request: &Bytes) -> Result<MarkdownOptions, FatalError> {
+        let request = flatbuffers::root::<markdown::Request>(request)?;
+        let name = request.theme().unwrap_or(themes::DEFAULT_THEME);
+        let theme = themes::from_name(name).ok_or_else(|| FatalError::UnknownTheme(name.to_string()))?;
+        let classes = ClassNames::for_request(request.class_style(), request.class_prefix())
+            .map_err(|reason| FatalError::InvalidRequest(Encoding::Flatbuffers, reason))?;
+        Ok(MarkdownOptions {
+            theme,
+            inline_styles: request.inline_styles(),
+            attributes: Attributes::new(theme, request.inline_styles(), &classes),
+            render_prose: request.render_prose(),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &MarkdownOptions,
+    ) -> Outcome<String> {
+        let text = String::from_utf8_lossy(&contents);
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let mut blocks = CodeBlocks {
+                highlighter,
+                include_injections: file.include_injections,
+                cancellation_flag: &cancellation_flag,
+                options,
+            };
+            if options.render_prose {
+                render_prose(&text, &mut blocks)
+            } else {
+                replace_blocks(&text, &mut blocks)
+            }
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(rendered) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: rendered,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<String>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let rendered = match doc {
+                        Outcome::Success { ref contents, .. } => Some(builder.create_string(contents)),
+                        _ => None,
+                    };
+                    markdown::Document::create(
+                        builder,
+                        &markdown::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            rendered,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = markdown::Response::create(builder, &markdown::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Renders the code blocks of one document.
+struct CodeBlocks<'a> {
+    highlighter: &'a mut ts::Highlighter,
+    include_injections: bool,
+    cancellation_flag: &'a AtomicUsize,
+    options: &'a MarkdownOptions,
+}
+
+impl CodeBlocks<'_> {
+    /// Render a fenced block as a `<pre>`. Its language is the first word of its info string,
+    /// ignoring rustdoc-style attributes (`rust,ignore`). Blocks in unknown languages are
+    /// escaped instead of highlighted.
+    fn render(&mut self, info: &str, code: &str) -> Result<String, ts::Error> {
+        let name = info.split_whitespace().next().unwrap_or_default();
+        let name = name.split(',').next().unwrap_or_default().to_ascii_lowercase();
+        let language = languages::from_info_string(&name);
+
+        let mut out = String::from("<pre class=\"daylight\"");
+        if let Some(language) = language {
+            let _ = write!(out, " data-language=\"{}\"", language.name);
+        }
+        if self.options.inline_styles {
+            let _ = write!(out, " style=\"{}\"", self.options.theme.base_style().inline_css());
+        }
+        out.push_str("><code>");
+        match language {
+            Some(language) => {
+                let iter = highlight(
+                    self.highlighter,
+                    language,
+                    code.as_bytes(),
+                    self.include_injections,
+                    self.cancellation_flag,
+                )?;
+                let attributes = &self.options.attributes;
+                let lines = render_lines(iter, code.as_bytes(), |highlight, output| {
+                    attributes.write(output, highlight);
+                    "</span>"
+                })?;
+                lines.iter().for_each(|line| out.push_str(line));
+            }
+            None => escape_html(&mut out, code),
+        }
+        // The last line's newline would show up as an empty line at the end of the block.
+        if out.ends_with('\n') {
+            out.pop();
+        }
+        out.push_str("</code></pre>");
+        Ok(out)
+    }
+}
+
+fn parser(text: &str) -> Parser<'_> {
+    use pulldown_cmark::Options;
+    Parser::new_ext(
+        text,
+        Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS,
+    )
+}
+
+/// Leave the Markdown as it is, but swap each fenced block for a `<pre>` on a single line.
+/// A line starting with `<pre` begins an HTML block that lasts until `</pre>`, so renderers
+/// pass it through untouched, even inside a list or a block quote.
+fn replace_blocks(text: &str, blocks: &mut CodeBlocks) -> Result<String, ts::Error> {
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut block: Option<(CowStr, String)> = None;
+    for (event, range) in parser(text).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                out.push_str(&text[copied..range.start]);
+                copied = range.end;
+                block = Some((info, String::new()));
+            }
+            Event::Text(code) if block.is_some() => {
+                block.as_mut().expect("checked above").1.push_str(&code);
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some((info, code)) = block.take() {
+                    out.push_str(&blocks.render(&info, &code)?.replace('\n', "&#10;"));
+                    if text[..copied].ends_with('\n') {
+                        out.push('\n');
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out.push_str(&text[copied..]);
+    Ok(out)
+}
+
+/// Render the whole document to HTML, with fenced blocks highlighted.
+fn render_prose(text: &str, blocks: &mut CodeBlocks) -> Result<String, ts::Error> {
+    let mut events = vec![];
+    let mut block: Option<(CowStr, String)> = None;
+    for event in parser(text) {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                block = Some((info, String::new()));
+            }
+            Event::Text(code) if block.is_some() => {
+                block.as_mut().expect("checked above").1.push_str(&code);
+            }
+            Event::End(TagEnd::CodeBlock) if block.is_some() => {
+                let (info, code) = block.take().expect("checked above");
+                let mut html = blocks.render(&info, &code)?;
+                html.push('\n');
+                events.push(Event::Html(html.into()));
+            }
+            event => events.push(event),
+        }
+    }
+    let mut out = String::with_capacity(text.len() * 2);
+    pulldown_cmark::html::push_html(&mut out, events.into_iter());
+    Ok(out)
+}
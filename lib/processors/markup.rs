@@ -0,0 +1,54 @@
+use tree_sitter_highlight as ts;
+
+/// A markup language in which highlights nest as groups, like LaTeX macros or Typst functions.
+pub(crate) trait Markup {
+    /// Open a group for a capture, by index into `ALL_HIGHLIGHT_NAMES`.
+    fn open(&self, out: &mut String, highlight: usize);
+    /// Close the innermost open group.
+    fn close(&self, out: &mut String);
+    /// Write source text, escaped. Never contains a newline.
+    fn text(&self, out: &mut String, text: &str);
+}
+
+/// Render highlight events as lines of markup. Groups never span lines: the groups open at
+/// the end of a line are closed there and reopened at the start of the next, so each line
+/// stands on its own, as `HtmlRenderer` does for HTML.
+pub(crate) fn render_lines(
+    iter: impl Iterator<Item = Result<ts::HighlightEvent, ts::Error>>,
+    contents: &[u8],
+    markup: &impl Markup,
+) -> Result<Vec<String>, ts::Error> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    let mut stack: Vec<usize> = vec![];
+    for event in iter {
+        match event? {
+            ts::HighlightEvent::HighlightStart(h) => {
+                markup.open(&mut line, h.0);
+                stack.push(h.0);
+            }
+            ts::HighlightEvent::HighlightEnd => {
+                markup.close(&mut line);
+                stack.pop();
+            }
+            ts::HighlightEvent::Source { start, end } => {
+                let text = String::from_utf8_lossy(&contents[start..end]);
+                for (i, segment) in text.split('\n').enumerate() {
+                    if i > 0 {
+                        stack.iter().for_each(|_| markup.close(&mut line));
+                        lines.push(std::mem::take(&mut line));
+                        stack.iter().for_each(|&h| markup.open(&mut line, h));
+                    }
+                    let segment = segment.strip_suffix('\r').unwrap_or(segment);
+                    if !segment.is_empty() {
+                        markup.text(&mut line, segment);
+                    }
+                }
+            }
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    Ok(lines)
+}
@@ -1,19 +1,56 @@
+mod ansi;
+mod diagnostics;
+mod diff;
+mod folds;
 mod html;
+mod image;
+mod latex;
+mod markdown;
+mod markup;
+mod patch;
+mod query;
+mod references;
+mod rtf;
+mod semantic_tokens;
 mod spans;
+mod tags;
+mod tree;
+mod typst;
 
-pub use html::HtmlProcessor;
+pub use ansi::AnsiProcessor;
+pub use diagnostics::{Diagnostic, DiagnosticKind, DiagnosticsDocument, DiagnosticsProcessor};
+pub use diff::{DiffDocument, DiffLine, DiffProcessor, Hunk};
+pub use folds::{Fold, FoldKind, FoldsProcessor};
+pub use html::{HtmlDocument, HtmlProcessor};
+pub use image::{CodeImage, ImageProcessor};
+pub use latex::{LatexProcessor, preamble as latex_preamble};
+pub use markdown::MarkdownProcessor;
+pub use patch::{PatchDocument, PatchFile, PatchProcessor};
+pub use query::{
+    Capture, MATCH_LIMIT, QueryDocument, QueryError, QueryErrorKind, QueryMatch, QueryProcessor, error_code as query_error_code,
+    query_errors,
+};
+pub use references::{Definition, Reference, ReferencesProcessor, Scope, ScopeGraph};
+pub use rtf::RtfProcessor;
+pub use semantic_tokens::{SemanticTokensProcessor, TOKEN_MODIFIERS, TOKEN_TYPES};
 use opentelemetry::trace::Status;
-pub use spans::SpansProcessor;
+pub use spans::{Span, SpansDocument, SpansProcessor};
+pub use tags::{Tag, TagsProcessor};
+pub use tree::{Node, TreeDocument, TreeFormat, TreeProcessor};
+pub use typst::{TypstProcessor, preamble as typst_preamble};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
+use std::ops::Range;
 use std::sync::Arc;
-use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use axum::body::Bytes;
+use tree_sitter_highlight as ts;
 
 use crate::errors::{FatalError, NonFatalError};
 use crate::languages;
 use crate::daylight_generated::daylight::common;
+use crate::wire;
 
 /// The result of an enqueued highlight task. Not a Result<> because my brain is too small
 /// to handle nested Result types in associated Future output times.
@@ -23,7 +60,7 @@ pub enum Outcome<T> {
         // don't LOVE the Arc but lifetimes become quite difficult without them
         filename: Arc<str>,
         language: languages::SharedConfig,
-        contents: Vec<T>,
+        contents: T,
     },
     Failure {
         ident: u16,
@@ -48,7 +85,7 @@ impl<T> Outcome<T> {
         }
     }
 
-    pub fn filename<'a>(&'a self) -> &'a str {
+    pub fn filename(&self) -> &str {
         match self {
             Self::Success { filename, .. } => filename.as_ref(),
             Self::Failure { .. } => Default::default(),
@@ -70,21 +107,113 @@ impl<T> Outcome<T> {
     }
 }
 
+/// Settings for a single file, as opposed to the request-wide `Processor::Options`.
+#[derive(Clone, Debug, Default)]
+pub struct FileOptions {
+    /// Whether to resolve injected grammars.
+    pub include_injections: bool,
+    /// The first line to produce output for, numbered from 1. Zero means the first line.
+    pub start_line: u32,
+    /// The last line to produce output for, inclusive. Zero means the last line.
+    pub end_line: u32,
+}
+
+impl FileOptions {
+    /// The zero-based lines to produce output for, out of a document's `count`. The whole
+    /// file is still highlighted, because a window's highlighting depends on what precedes it.
+    pub fn window(&self, count: usize) -> Range<usize> {
+        let start = (self.start_line as usize).saturating_sub(1).min(count);
+        let end = match self.end_line {
+            0 => count,
+            end => (end as usize).clamp(start, count),
+        };
+        start..end
+    }
+}
+
+/// Start highlighting a file, resolving injected grammars only if they were asked for.
+pub(crate) fn highlight<'a>(
+    highlighter: &'a mut ts::Highlighter,
+    language: languages::SharedConfig,
+    contents: &'a [u8],
+    include_injections: bool,
+    cancellation_flag: &'a AtomicUsize,
+) -> Result<impl Iterator<Item = Result<ts::HighlightEvent, ts::Error>> + 'a, ts::Error> {
+    highlighter.highlight(&language.ts_config, contents, Some(cancellation_flag), move |s| {
+        if include_injections {
+            languages::from_name(s).map(|l| &l.ts_config)
+        } else {
+            None
+        }
+    })
+}
+
+/// Parse a file into a syntax tree, giving up if the cancellation flag is raised.
+pub(crate) fn parse(
+    parser: &mut tree_sitter::Parser,
+    language: languages::SharedConfig,
+    contents: &[u8],
+    cancellation_flag: &AtomicUsize,
+) -> Result<tree_sitter::Tree, ts::Error> {
+    reparse(parser, language, contents, None, cancellation_flag)
+}
+
+/// Parse a file again after editing it, reusing the unchanged parts of its old tree, which
+/// must have been told about the edits with `Tree::edit`.
+pub(crate) fn reparse(
+    parser: &mut tree_sitter::Parser,
+    language: languages::SharedConfig,
+    contents: &[u8],
+    old_tree: Option<&tree_sitter::Tree>,
+    cancellation_flag: &AtomicUsize,
+) -> Result<tree_sitter::Tree, ts::Error> {
+    parser
+        .set_language(&language.ts_config.language)
+        .map_err(|_| ts::Error::InvalidLanguage)?;
+    parser
+        .parse_with_options(
+            &mut |i, _| contents.get(i..).unwrap_or_default(),
+            old_tree,
+            Some(tree_sitter::ParseOptions::new().progress_callback(&mut |_| {
+                cancellation_flag.load(Ordering::SeqCst) != 0
+            })),
+        )
+        .ok_or(ts::Error::Cancelled)
+}
+
 /// Trait for processing highlight events into different output formats.
 pub trait Processor: Send + Sync + 'static {
+    /// Everything produced for a single file.
     type Output: Send;
 
+    /// Request-wide settings, shared by every file in a request.
+    type Options: Send + Sync + 'static;
+
+    /// Serde mirror of this processor's request, for the JSON, CBOR and MessagePack encodings.
+    type WireRequest: wire::Request;
+
+    /// Serde mirror of this processor's response, for the JSON, CBOR and MessagePack encodings.
+    type WireResponse: serde::Serialize + From<Vec<Outcome<Self::Output>>>;
+
+    /// Whether an empty file is processed, rather than failing with `EmptyFile`.
+    const ALLOWS_EMPTY_FILES: bool = false;
+
+    /// Read this processor's options out of a request body. Options can hold on to parts of
+    /// the body with `Bytes::slice_ref`, rather than copying them.
+    fn options(request: &Bytes) -> Result<Self::Options, FatalError>;
+
     /// Process file contents and return the processed output.
     fn process(
         ident: u16,
         filename: Arc<str>,
         language: languages::SharedConfig,
         contents: Bytes,
-        include_injections: bool,
+        file: &FileOptions,
         cancellation_flag: Arc<AtomicUsize>,
+        options: &Self::Options,
     ) -> Outcome<Self::Output>;
 
-    /// Build the final HTTP response from a collection of outputs.
+    /// Build the final Flatbuffers HTTP response from a collection of outputs.
     fn build_response(
         outputs: Vec<Outcome<Self::Output>>,
     ) -> Result<axum::response::Response, FatalError>;
@@ -0,0 +1,339 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::classes;
+use crate::daylight_generated::daylight::patch;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages;
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::diff::Highlighted;
+use super::html::escape_html;
+use super::{FileOptions, Outcome, Processor};
+
+/// Patch processor that returns unified diffs as HTML lines, with each file's hunks highlighted
+/// in that file's language.
+pub struct PatchProcessor;
+
+pub struct PatchOptions {
+    /// The `class` attribute value for each index into `ALL_HIGHLIGHT_NAMES`.
+    classes: Vec<String>,
+}
+
+/// A file changed by a patch.
+pub struct PatchFile {
+    pub old_path: String,
+    pub new_path: String,
+    pub language: Option<languages::SharedConfig>,
+}
+
+impl PatchFile {
+    fn new(old_path: String, new_path: String) -> Self {
+        let language = [&new_path, &old_path]
+            .into_iter()
+            .find_map(|path| languages::from_path(std::path::Path::new(path)));
+        PatchFile {
+            old_path,
+            new_path,
+            language,
+        }
+    }
+}
+
+/// The lines of a highlighted patch, and the files it changes.
+#[derive(Default)]
+pub struct PatchDocument {
+    pub lines: Vec<String>,
+    pub files: Vec<PatchFile>,
+}
+
+impl Processor for PatchProcessor {
+    type Output = PatchDocument;
+    type Options = PatchOptions;
+    type WireRequest = wire::patch::Request;
+    type WireResponse = wire::patch::Response;
+
+    fn options(request: &Bytes) -> Result<PatchOptions, FatalError> {
+        flatbuffers::root::<patch::Request>(request)?;
+        Ok(PatchOptions {
+            classes: classes::server_default().attributes(),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &PatchOptions,
+    ) -> Outcome<PatchDocument> {
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let mut parser = PatchParser {
+                highlighter,
+                include_injections: file.include_injections,
+                cancellation_flag: &cancellation_flag,
+                options,
+                document: PatchDocument::default(),
+                in_hunks: false,
+            };
+            parser.parse(&contents)?;
+            Ok(parser.document)
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(document) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: document,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<PatchDocument>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let (lines, files) = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let lines: Vec<_> = contents.lines.iter().map(|line| builder.create_string(line)).collect();
+                            let files: Vec<_> = contents
+                                .files
+                                .iter()
+                                .map(|file| {
+                                    let old_path = Some(builder.create_string(&file.old_path));
+                                    let new_path = Some(builder.create_string(&file.new_path));
+                                    patch::PatchFile::create(
+                                        builder,
+                                        &patch::PatchFileArgs {
+                                            old_path,
+                                            new_path,
+                                            language: file.language.map(|l| l.fb_language).unwrap_or_default(),
+                                        },
+                                    )
+                                })
+                                .collect();
+                            (Some(builder.create_vector(&lines)), Some(builder.create_vector(&files)))
+                        }
+                        _ => (None, None),
+                    };
+                    patch::Document::create(
+                        builder,
+                        &patch::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            lines,
+                            files,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = patch::Response::create(builder, &patch::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Walks a patch line by line, rendering headers as they come and each hunk as a whole.
+struct PatchParser<'a> {
+    highlighter: &'a mut ts::Highlighter,
+    include_injections: bool,
+    cancellation_flag: &'a AtomicUsize,
+    options: &'a PatchOptions,
+    document: PatchDocument,
+    /// Whether the current file has had a hunk yet, so a `---` line starts another file.
+    in_hunks: bool,
+}
+
+impl PatchParser<'_> {
+    fn parse(&mut self, contents: &[u8]) -> Result<(), ts::Error> {
+        let lines: Vec<&[u8]> = contents
+            .split_inclusive(|b| *b == b'\n')
+            .map(|line| line.strip_suffix(b"\n").unwrap_or(line))
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .collect();
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+            i += 1;
+            let Some((mut old_left, mut new_left)) = hunk_header(line) else {
+                self.header(line);
+                continue;
+            };
+            self.document.lines.push(wrap("diff-hunk", &escaped(line)));
+            // A hunk's header says how long it is. Counting keeps a removed line that looks
+            // like a header (`--- a/file`) in the hunk, and a trailer (`-- `) out of it.
+            let start = i;
+            while let Some(line) = lines.get(i) {
+                match line.first() {
+                    Some(b'\\') => {}
+                    _ if old_left == 0 && new_left == 0 => break,
+                    Some(b'+') => new_left = new_left.saturating_sub(1),
+                    Some(b'-') => old_left = old_left.saturating_sub(1),
+                    // Some tools trim the space off empty context lines.
+                    Some(b' ') | None => {
+                        old_left = old_left.saturating_sub(1);
+                        new_left = new_left.saturating_sub(1);
+                    }
+                    Some(_) => break,
+                }
+                i += 1;
+            }
+            self.hunk(&lines[start..i])?;
+        }
+        Ok(())
+    }
+
+    /// Render a line outside any hunk, noting which file the patch has moved on to.
+    fn header(&mut self, line: &[u8]) {
+        let text = String::from_utf8_lossy(line);
+        if let Some(paths) = text.strip_prefix("diff --git ") {
+            let (old_path, new_path) = paths.rsplit_once(" b/").unwrap_or((paths, ""));
+            let old_path = old_path.strip_prefix("a/").unwrap_or(old_path);
+            self.document.files.push(PatchFile::new(old_path.to_string(), new_path.to_string()));
+            self.in_hunks = false;
+        } else if let Some(path) = text.strip_prefix("--- ") {
+            if self.in_hunks || self.document.files.is_empty() {
+                self.document.files.push(PatchFile::new(String::new(), String::new()));
+                self.in_hunks = false;
+            }
+            let file = self.document.files.last_mut().expect("pushed above");
+            *file = PatchFile::new(header_path(path, "a/"), std::mem::take(&mut file.new_path));
+        } else if let Some(path) = text.strip_prefix("+++ ")
+            && let Some(file) = self.document.files.last_mut()
+            && !self.in_hunks
+        {
+            *file = PatchFile::new(std::mem::take(&mut file.old_path), header_path(path, "b/"));
+        }
+        self.document.lines.push(wrap("diff-header", &escaped(line)));
+    }
+
+    /// Render the lines of a hunk. The old and new sides of the hunk are highlighted separately,
+    /// as fragments of the file, which tree-sitter makes the best of.
+    fn hunk(&mut self, lines: &[&[u8]]) -> Result<(), ts::Error> {
+        self.in_hunks = true;
+        let language = self.document.files.last().and_then(|file| file.language);
+        let (mut old, mut new) = (Side::default(), Side::default());
+        // For each line, whether it's on the new side, and its index among that side's lines.
+        let mut sides = vec![];
+        for line in lines {
+            let code = line.get(1..).unwrap_or_default();
+            sides.push(match line.first() {
+                Some(b'\\') => None,
+                Some(b'-') => Some((false, old.push(code))),
+                Some(b'+') => Some((true, new.push(code))),
+                _ => {
+                    old.push(code);
+                    Some((true, new.push(code)))
+                }
+            });
+        }
+
+        let (old, new) = match language {
+            Some(language) => {
+                let mut highlighted = |contents| {
+                    Highlighted::new(
+                        self.highlighter,
+                        language,
+                        contents,
+                        self.include_injections,
+                        self.cancellation_flag,
+                    )
+                };
+                (Some(highlighted(&old.contents)?), Some(highlighted(&new.contents)?))
+            }
+            None => (None, None),
+        };
+        for (line, side) in lines.iter().zip(sides) {
+            let Some((is_new, index)) = side else {
+                self.document.lines.push(wrap("diff-note", &escaped(line)));
+                continue;
+            };
+            let class = match line.first() {
+                Some(b'-') => "diff-removed",
+                Some(b'+') => "diff-added",
+                _ => "diff-context",
+            };
+            let marker = escaped(line.get(..1).unwrap_or_default());
+            let side = if is_new { &new } else { &old };
+            let code = match side {
+                Some(side) => side.html(index, &[], "", &self.options.classes),
+                None => escaped(line.get(1..).unwrap_or_default()),
+            };
+            self.document.lines.push(wrap(class, &format!("{}{code}", wrap("diff-marker", &marker))));
+        }
+        Ok(())
+    }
+}
+
+/// The code on one side of a hunk.
+#[derive(Default)]
+struct Side {
+    contents: Vec<u8>,
+    lines: usize,
+}
+
+impl Side {
+    /// Add a line of code, returning its index.
+    fn push(&mut self, code: &[u8]) -> usize {
+        self.contents.extend_from_slice(code);
+        self.contents.push(b'\n');
+        self.lines += 1;
+        self.lines - 1
+    }
+}
+
+/// The old and new line counts of a hunk header, `@@ -12,7 +12,8 @@`. A missing count is 1.
+fn hunk_header(line: &[u8]) -> Option<(usize, usize)> {
+    let line = std::str::from_utf8(line).ok()?.strip_prefix("@@ -")?;
+    let (ranges, _) = line.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let count = |range: &str| match range.split_once(',') {
+        Some((_, count)) => count.parse().ok(),
+        None => range.parse::<usize>().ok().map(|_| 1),
+    };
+    Some((count(old)?, count(new)?))
+}
+
+/// The path in a `---` or `+++` header, without its `a/` or `b/` prefix or a timestamp.
+/// `/dev/null` stands for a file that doesn't exist, and becomes an empty path.
+fn header_path(text: &str, prefix: &str) -> String {
+    let path = text.split('\t').next().unwrap_or_default().trim_end();
+    if path == "/dev/null" {
+        return String::new();
+    }
+    path.strip_prefix(prefix).unwrap_or(path).to_string()
+}
+
+fn escaped(text: &[u8]) -> String {
+    let mut out = String::new();
+    escape_html(&mut out, &String::from_utf8_lossy(text));
+    out
+}
+
+fn wrap(class: &str, html: &str) -> String {
+    format!("<span class=\"{class}\">{html}</span>")
+}
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter::{Query, QueryCursor, QueryCursorOptions, StreamingIterator};
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::{common, query};
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages;
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, parse};
+
+pub use crate::daylight_generated::daylight::query::QueryErrorKind;
+
+/// Query processor that runs client-supplied tree-sitter queries over files.
+pub struct QueryProcessor;
+
+pub struct QueryOptions {
+    query: Option<String>,
+    language_queries: Vec<(common::Language, String)>,
+}
+
+impl QueryOptions {
+    /// The query to run over files in a language, if there is one.
+    fn source(&self, language: common::Language) -> Option<&str> {
+        self.language_queries
+            .iter()
+            .find(|(l, _)| *l == language)
+            .map(|(_, query)| query.as_str())
+            .or(self.query.as_deref())
+    }
+}
+
+/// A node a pattern captured.
+#[derive(Debug)]
+pub struct Capture {
+    pub name: String,
+    /// Byte offsets.
+    pub start: usize,
+    pub end: usize,
+    /// Zero-based lines and byte columns of `start` and `end`.
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub text: String,
+}
+
+/// A match of one of a query's patterns.
+#[derive(Debug)]
+pub struct QueryMatch {
+    pub pattern: usize,
+    pub captures: Vec<Capture>,
+}
+
+/// Why a language's query didn't compile.
+#[derive(Clone, Debug)]
+pub struct QueryError {
+    pub language: common::Language,
+    pub kind: QueryErrorKind,
+    pub message: String,
+    pub row: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+/// A file's matches, or why its language's query didn't compile.
+#[derive(Default)]
+pub struct QueryDocument {
+    pub matches: Vec<QueryMatch>,
+    pub error: Option<QueryError>,
+    /// Whether there were more than `MATCH_LIMIT` matches, and only the first were kept.
+    pub truncated: bool,
+}
+
+/// A file's error code, which is `InvalidQuery` if its language's query didn't compile.
+pub fn error_code(outcome: &Outcome<QueryDocument>) -> common::ErrorCode {
+    match outcome {
+        Outcome::Success { contents, .. } if contents.error.is_some() => common::ErrorCode::InvalidQuery,
+        outcome => outcome.error_code(),
+    }
+}
+
+/// The errors of the files whose queries didn't compile, one for each language.
+pub fn query_errors(outputs: &[Outcome<QueryDocument>]) -> Vec<QueryError> {
+    let mut errors: Vec<QueryError> = vec![];
+    let documents = outputs.iter().filter_map(|outcome| match outcome {
+        Outcome::Success { contents, .. } => contents.error.as_ref(),
+        _ => None,
+    });
+    for error in documents {
+        if !errors.iter().any(|e| e.language == error.language) {
+            errors.push(error.clone());
+        }
+    }
+    errors
+}
+
+impl Processor for QueryProcessor {
+    type Output = QueryDocument;
+    type Options = QueryOptions;
+    type WireRequest = wire::query::Request;
+    type WireResponse = wire::query::Response;
+
+    fn options(request: &Bytes) -> Result<QueryOptions, FatalError> {
+        let request = flatbuffers::root::<query::Request>(request)?;
+        Ok(QueryOptions {
+            query: request.query().map(String::from),
+            language_queries: request
+                .language_queries()
+                .unwrap_or_default()
+                .iter()
+                .map(|query| (query.language(), query.query().unwrap_or_default().to_string()))
+                .collect(),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        _file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &QueryOptions,
+    ) -> Outcome<QueryDocument> {
+        let result = match options.source(language.fb_language).map(|source| compile(language, source)) {
+            None => Ok(QueryDocument::default()),
+            Some(Err(error)) => Ok(QueryDocument {
+                error: Some(error),
+                ..Default::default()
+            }),
+            Some(Ok(query)) => find_matches(language, &query, &contents, &cancellation_flag),
+        }
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(document) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: document,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<QueryDocument>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let truncated = matches!(doc, Outcome::Success { contents, .. } if contents.truncated);
+                    let matches = match doc {
+                        Outcome::Success { contents, .. } => {
+                            let matches: Vec<_> = contents
+                                .matches
+                                .iter()
+                                .map(|m| {
+                                    let captures: Vec<_> = m
+                                        .captures
+                                        .iter()
+                                        .map(|capture| {
+                                            let name = Some(builder.create_string(&capture.name));
+                                            let text = Some(builder.create_string(&capture.text));
+                                            query::Capture::create(
+                                                builder,
+                                                &query::CaptureArgs {
+                                                    name,
+                                                    start: capture.start as u64,
+                                                    end: capture.end as u64,
+                                                    start_line: capture.start_line as u32,
+                                                    start_column: capture.start_column as u32,
+                                                    end_line: capture.end_line as u32,
+                                                    end_column: capture.end_column as u32,
+                                                    text,
+                                                },
+                                            )
+                                        })
+                                        .collect();
+                                    let captures = Some(builder.create_vector(&captures));
+                                    query::Match::create(
+                                        builder,
+                                        &query::MatchArgs {
+                                            pattern: m.pattern as u32,
+                                            captures,
+                                        },
+                                    )
+                                })
+                                .collect();
+                            Some(builder.create_vector(&matches))
+                        }
+                        _ => None,
+                    };
+                    query::Document::create(
+                        builder,
+                        &query::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            matches,
+                            error_code: error_code(doc),
+                            truncated,
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let query_errors: Vec<_> = query_errors(&outputs)
+                .iter()
+                .map(|error| {
+                    let message = Some(builder.create_string(&error.message));
+                    query::QueryError::create(
+                        builder,
+                        &query::QueryErrorArgs {
+                            language: error.language,
+                            kind: error.kind,
+                            message,
+                            row: error.row as u32,
+                            column: error.column as u32,
+                            offset: error.offset as u64,
+                        },
+                    )
+                })
+                .collect();
+            let query_errors = Some(builder.create_vector(&query_errors));
+            let response = query::Response::create(builder, &query::ResponseArgs { documents, query_errors });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// How many compiled queries to keep. Tools tend to send the same few queries over and over,
+/// so the cache is simply emptied when it fills up.
+const CACHE_SIZE: usize = 64;
+
+/// A query's source, and its compiled form once the first file to need it has compiled it.
+type Entry = (String, OnceLock<Result<Arc<Query>, QueryError>>);
+
+/// Compiled queries and compile errors, by language and a hash of the query.
+type Cache = HashMap<(&'static str, u64), Arc<Entry>>;
+
+static CACHE: LazyLock<Mutex<Cache>> = LazyLock::new(Default::default);
+
+/// Compile a query for a language, or take it from the cache. The cache is only locked to find
+/// the query's entry; compiling happens outside it, so a slow query only holds up the files
+/// waiting on that same query, which is still only compiled once.
+fn compile(language: languages::SharedConfig, source: &str) -> Result<Arc<Query>, QueryError> {
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    let key = (language.name, hasher.finish());
+
+    let entry = {
+        let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        match cache.get(&key) {
+            Some(entry) if entry.0 == source => entry.clone(),
+            _ => {
+                if cache.len() >= CACHE_SIZE {
+                    cache.clear();
+                }
+                let entry = Arc::new((source.to_string(), OnceLock::new()));
+                cache.insert(key, entry.clone());
+                entry
+            }
+        }
+    };
+    entry
+        .1
+        .get_or_init(|| {
+            Query::new(&language.ts_config.language, source).map(Arc::new).map_err(|e| QueryError {
+                language: language.fb_language,
+                kind: match e.kind {
+                    tree_sitter::QueryErrorKind::Syntax => QueryErrorKind::Syntax,
+                    tree_sitter::QueryErrorKind::NodeType => QueryErrorKind::NodeType,
+                    tree_sitter::QueryErrorKind::Field => QueryErrorKind::Field,
+                    tree_sitter::QueryErrorKind::Capture => QueryErrorKind::Capture,
+                    tree_sitter::QueryErrorKind::Predicate => QueryErrorKind::Predicate,
+                    tree_sitter::QueryErrorKind::Structure => QueryErrorKind::Structure,
+                    tree_sitter::QueryErrorKind::Language => QueryErrorKind::Language,
+                },
+                message: e.message,
+                row: e.row,
+                column: e.column,
+                offset: e.offset,
+            })
+        })
+        .clone()
+}
+
+/// The most matches returned for a file. Each capture carries its node's text, so a query that
+/// matches every node (`(_) @node`) would otherwise copy out a large file many times over.
+pub const MATCH_LIMIT: usize = 10_000;
+
+/// Run a query over a file, returning its first `MATCH_LIMIT` matches in order.
+fn find_matches(
+    language: languages::SharedConfig,
+    query: &Query,
+    contents: &[u8],
+    cancellation_flag: &AtomicUsize,
+) -> Result<QueryDocument, ts::Error> {
+    ThreadState::parse_with_tree_sitter(|parser| {
+        let tree = parse(parser, language, contents, cancellation_flag)?;
+        let mut cursor = QueryCursor::new();
+        let mut cancelled = |_: &_| cancellation_flag.load(Ordering::SeqCst) != 0;
+        let options = QueryCursorOptions::new().progress_callback(&mut cancelled);
+        let mut matches = cursor.matches_with_options(query, tree.root_node(), contents, options);
+        let mut found = vec![];
+        while found.len() < MATCH_LIMIT
+            && let Some(m) = matches.next()
+        {
+            let captures = m
+                .captures
+                .iter()
+                .map(|capture| {
+                    let node = capture.node;
+                    Capture {
+                        name: query.capture_names()[capture.index as usize].to_string(),
+                        start: node.start_byte(),
+                        end: node.end_byte(),
+                        start_line: node.start_position().row,
+                        start_column: node.start_position().column,
+                        end_line: node.end_position().row,
+                        end_column: node.end_position().column,
+                        text: String::from_utf8_lossy(&contents[node.byte_range()]).into_owned(),
+                    }
+                })
+                .collect();
+            found.push(QueryMatch {
+                pattern: m.pattern_index,
+                captures,
+            });
+        }
+        let truncated = found.len() == MATCH_LIMIT && matches.next().is_some();
+        // The progress callback stops the cursor without saying so.
+        if cancellation_flag.load(Ordering::SeqCst) != 0 {
+            return Err(ts::Error::Cancelled);
+        }
+        Ok(QueryDocument {
+            matches: found,
+            error: None,
+            truncated,
+        })
+    })
+}
@@ -0,0 +1,461 @@
+use std::collections::{HashSet, VecDeque};
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter::{Node, Query, QueryCursor, StreamingIterator};
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::references;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages::{self, ALL_HIGHLIGHT_NAMES};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, parse};
+
+/// References processor that returns where each file's local variables are defined and used.
+pub struct ReferencesProcessor;
+
+/// Where a file's local variables are defined and used, and which definition each use
+/// refers to.
+#[derive(Debug, Default)]
+pub struct ScopeGraph {
+    pub scopes: Vec<Scope>,
+    pub definitions: Vec<Definition>,
+    pub references: Vec<Reference>,
+}
+
+/// A region that definitions are local to.
+#[derive(Debug)]
+pub struct Scope {
+    /// Byte offsets.
+    pub start: usize,
+    pub end: usize,
+    /// Zero-based lines of `start` and `end`.
+    pub start_line: usize,
+    pub end_line: usize,
+    pub parent: Option<usize>,
+    /// Whether references inside can't see the definitions of enclosing scopes.
+    pub isolated: bool,
+}
+
+/// Where a variable is defined.
+#[derive(Debug)]
+pub struct Definition {
+    pub name: String,
+    /// From a `@local.definition.<kind>` capture, or empty.
+    pub kind: &'static str,
+    /// Byte offsets of the name.
+    pub start: usize,
+    pub end: usize,
+    /// The zero-based line and byte column of `start`.
+    pub line: usize,
+    pub column: usize,
+    pub scope: usize,
+}
+
+/// A use of a variable.
+#[derive(Debug)]
+pub struct Reference {
+    pub name: String,
+    /// Byte offsets of the name.
+    pub start: usize,
+    pub end: usize,
+    /// The zero-based line and byte column of `start`.
+    pub line: usize,
+    pub column: usize,
+    pub definition: Option<usize>,
+}
+
+impl Processor for ReferencesProcessor {
+    type Output = ScopeGraph;
+    type Options = ();
+    type WireRequest = wire::references::Request;
+    type WireResponse = wire::references::Response;
+
+    fn options(request: &Bytes) -> Result<(), FatalError> {
+        flatbuffers::root::<references::Request>(request)?;
+        Ok(())
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, _options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        _file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        _options: &(),
+    ) -> Outcome<ScopeGraph> {
+        let result = scope_graph(language, &contents, &cancellation_flag).map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(graph) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: graph,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<ScopeGraph>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let (scopes, definitions, references) = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let scopes: Vec<_> = contents
+                                .scopes
+                                .iter()
+                                .map(|scope| {
+                                    references::Scope::create(
+                                        builder,
+                                        &references::ScopeArgs {
+                                            start: scope.start as u64,
+                                            end: scope.end as u64,
+                                            start_line: scope.start_line as u32,
+                                            end_line: scope.end_line as u32,
+                                            parent: scope.parent.map_or(-1, |i| i as i32),
+                                            isolated: scope.isolated,
+                                        },
+                                    )
+                                })
+                                .collect();
+                            let definitions: Vec<_> = contents
+                                .definitions
+                                .iter()
+                                .map(|definition| {
+                                    let name = Some(builder.create_string(&definition.name));
+                                    let kind = Some(builder.create_string(definition.kind));
+                                    references::Definition::create(
+                                        builder,
+                                        &references::DefinitionArgs {
+                                            name,
+                                            kind,
+                                            start: definition.start as u64,
+                                            end: definition.end as u64,
+                                            line: definition.line as u32,
+                                            column: definition.column as u32,
+                                            scope: definition.scope as u32,
+                                        },
+                                    )
+                                })
+                                .collect();
+                            let references: Vec<_> = contents
+                                .references
+                                .iter()
+                                .map(|reference| {
+                                    let name = Some(builder.create_string(&reference.name));
+                                    references::Reference::create(
+                                        builder,
+                                        &references::ReferenceArgs {
+                                            name,
+                                            start: reference.start as u64,
+                                            end: reference.end as u64,
+                                            line: reference.line as u32,
+                                            column: reference.column as u32,
+                                            definition: reference.definition.map_or(-1, |i| i as i32),
+                                        },
+                                    )
+                                })
+                                .collect();
+                            (
+                                Some(builder.create_vector(&scopes)),
+                                Some(builder.create_vector(&definitions)),
+                                Some(builder.create_vector(&references)),
+                            )
+                        }
+                        _ => (None, None, None),
+                    };
+                    references::Document::create(
+                        builder,
+                        &references::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            scopes,
+                            definitions,
+                            references,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = references::Response::create(builder, &references::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Build the scope graph of a file from its language's locals query, resolving references as
+/// tree-sitter's highlighter does: to the latest definition with the same name before them, in
+/// the innermost enclosing scope that has one.
+pub(crate) fn scope_graph(
+    language: languages::SharedConfig,
+    contents: &[u8],
+    cancellation_flag: &AtomicUsize,
+) -> Result<ScopeGraph, ts::Error> {
+    let Some(query) = language.locals() else {
+        return Ok(ScopeGraph::default());
+    };
+    ThreadState::parse_with_tree_sitter(|parser| {
+        let tree = parse(parser, language, contents, cancellation_flag)?;
+        let mut builder = GraphBuilder::new(query, tree.root_node(), contents);
+        let mut cursor = QueryCursor::new();
+        let mut captures = cursor.captures(query, tree.root_node(), contents);
+        while let Some((m, index)) = captures.next() {
+            if cancellation_flag.load(Ordering::SeqCst) != 0 {
+                return Err(ts::Error::Cancelled);
+            }
+            builder.add(m.captures[*index].node, m.captures[*index].index, m.pattern_index);
+        }
+        Ok(builder.finish())
+    })
+}
+
+/// Builds a scope graph from the captures of a locals query, in the order of their nodes.
+struct GraphBuilder<'a> {
+    query: &'static Query,
+    contents: &'a [u8],
+    graph: ScopeGraph,
+    /// The scopes enclosing the current capture, innermost last.
+    stack: Vec<usize>,
+    /// The definitions of each scope, by index into `graph.definitions`.
+    scope_definitions: Vec<Vec<usize>>,
+    /// The byte ranges of the definitions.
+    defined: HashSet<(usize, usize)>,
+}
+
+impl<'a> GraphBuilder<'a> {
+    fn new(query: &'static Query, root: Node, contents: &'a [u8]) -> Self {
+        let mut builder = GraphBuilder {
+            query,
+            contents,
+            graph: ScopeGraph::default(),
+            stack: vec![],
+            scope_definitions: vec![],
+            defined: HashSet::new(),
+        };
+        builder.push_scope(root, false);
+        builder
+    }
+
+    fn push_scope(&mut self, node: Node, isolated: bool) {
+        self.graph.scopes.push(Scope {
+            start: node.start_byte(),
+            end: node.end_byte(),
+            start_line: node.start_position().row,
+            end_line: node.end_position().row,
+            parent: self.stack.last().copied(),
+            isolated,
+        });
+        self.scope_definitions.push(vec![]);
+        self.stack.push(self.graph.scopes.len() - 1);
+    }
+
+    fn add(&mut self, node: Node, capture: u32, pattern: usize) {
+        // Leave the scopes that ended before this node, but never the whole file's.
+        while self.stack.len() > 1 && self.graph.scopes[*self.stack.last().expect("checked above")].end <= node.start_byte() {
+            self.stack.pop();
+        }
+        let name = || String::from_utf8_lossy(&self.contents[node.byte_range()]).into_owned();
+        let capture_name = self.query.capture_names()[capture as usize];
+        let scope = *self.stack.last().expect("the whole file is always a scope");
+        if capture_name == "local.scope" {
+            let isolated = self.query.property_settings(pattern).iter().any(|property| {
+                &*property.key == "local.scope-inherits" && property.value.as_deref() == Some("false")
+            });
+            self.push_scope(node, isolated);
+        } else if let Some(kind) = capture_name.strip_prefix("local.definition") {
+            self.graph.definitions.push(Definition {
+                name: name(),
+                kind: kind.strip_prefix('.').unwrap_or(kind),
+                start: node.start_byte(),
+                end: node.end_byte(),
+                line: node.start_position().row,
+                column: node.start_position().column,
+                scope,
+            });
+            self.scope_definitions[scope].push(self.graph.definitions.len() - 1);
+            self.defined.insert((node.start_byte(), node.end_byte()));
+        } else if capture_name == "local.reference" && !self.defined.contains(&(node.start_byte(), node.end_byte())) {
+            let name = name();
+            let mut definition = None;
+            for &scope in self.stack.iter().rev() {
+                definition = self.scope_definitions[scope]
+                    .iter()
+                    .rev()
+                    .copied()
+                    .find(|&i| self.graph.definitions[i].name == name);
+                if definition.is_some() || self.graph.scopes[scope].isolated {
+                    break;
+                }
+            }
+            self.graph.references.push(Reference {
+                name,
+                start: node.start_byte(),
+                end: node.end_byte(),
+                line: node.start_position().row,
+                column: node.start_position().column,
+                definition,
+            });
+        }
+    }
+
+    fn finish(mut self) -> ScopeGraph {
+        // A definition can be captured after a reference to the same node.
+        let defined = self.defined;
+        self.graph.references.retain(|reference| !defined.contains(&(reference.start, reference.end)));
+        self.graph
+    }
+}
+
+/// A definition or resolved reference to link, by the definition's index.
+pub(crate) enum Link {
+    Definition(usize),
+    Reference(usize),
+}
+
+/// The definitions and resolved references of a scope graph, in order.
+pub(crate) fn links(graph: &ScopeGraph) -> Vec<(Range<usize>, Link)> {
+    let definitions = graph
+        .definitions
+        .iter()
+        .enumerate()
+        .map(|(i, definition)| (definition.start..definition.end, Link::Definition(i)));
+    let references = graph
+        .references
+        .iter()
+        .filter_map(|reference| Some((reference.start..reference.end, Link::Reference(reference.definition?))));
+    let mut links: Vec<_> = definitions.chain(references).collect();
+    links.sort_by_key(|(range, _)| range.start);
+    links
+}
+
+/// The highlight standing for the link at an index into `links`. These come after the real
+/// highlights, so the renderer's attribute callback can tell them apart.
+pub(crate) fn link_highlight(index: usize) -> ts::Highlight {
+    ts::Highlight(ALL_HIGHLIGHT_NAMES.len() + index)
+}
+
+/// The link at an index into `links` that a highlight stands for, if it stands for one.
+pub(crate) fn link_index(highlight: ts::Highlight) -> Option<usize> {
+    highlight.0.checked_sub(ALL_HIGHLIGHT_NAMES.len())
+}
+
+/// Splices a highlight for each link into a stream of highlight events, around the source it
+/// covers. A link is closed around the starts and ends of other highlights, and reopened after
+/// them, so that the spans stay nested.
+pub(crate) struct LinkedEvents<'a, I> {
+    events: I,
+    links: &'a [(Range<usize>, Link)],
+    /// The first link that hasn't been passed yet.
+    next: usize,
+    open: bool,
+    queue: VecDeque<Result<ts::HighlightEvent, ts::Error>>,
+}
+
+impl<'a, I> LinkedEvents<'a, I> {
+    pub(crate) fn new(events: I, links: &'a [(Range<usize>, Link)]) -> Self {
+        LinkedEvents {
+            events,
+            links,
+            next: 0,
+            open: false,
+            queue: VecDeque::new(),
+        }
+    }
+
+    fn close(&mut self) {
+        if self.open {
+            self.queue.push_back(Ok(ts::HighlightEvent::HighlightEnd));
+            self.open = false;
+        }
+    }
+
+    fn source(&mut self, mut start: usize, end: usize) {
+        while start < end {
+            while self.links.get(self.next).is_some_and(|(range, _)| range.end <= start) {
+                self.next += 1;
+            }
+            let Some((range, _)) = self.links.get(self.next) else {
+                self.queue.push_back(Ok(ts::HighlightEvent::Source { start, end }));
+                return;
+            };
+            if range.start > start {
+                let stop = end.min(range.start);
+                self.queue.push_back(Ok(ts::HighlightEvent::Source { start, end: stop }));
+                start = stop;
+                continue;
+            }
+            if !self.open {
+                self.queue.push_back(Ok(ts::HighlightEvent::HighlightStart(link_highlight(self.next))));
+                self.open = true;
+            }
+            let stop = end.min(range.end);
+            self.queue.push_back(Ok(ts::HighlightEvent::Source { start, end: stop }));
+            start = stop;
+            if stop == range.end {
+                self.close();
+                self.next += 1;
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = Result<ts::HighlightEvent, ts::Error>>> Iterator for LinkedEvents<'_, I> {
+    type Item = Result<ts::HighlightEvent, ts::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.queue.is_empty() {
+            match self.events.next() {
+                Some(Ok(ts::HighlightEvent::Source { start, end })) => self.source(start, end),
+                Some(event) => {
+                    self.close();
+                    self.queue.push_back(event);
+                }
+                None => {
+                    self.close();
+                    break;
+                }
+            }
+        }
+        self.queue.pop_front()
+    }
+}
+
+/// Write the start tag of a link, returning its end tag: an `<a href="#def-N">` for a reference,
+/// and a `<span id="def-N">` for a definition. A definition split up by other highlights or line
+/// breaks only gets an element the first time, because ids must be unique. `defined` records
+/// which definitions already have one.
+pub(crate) fn open_link(output: &mut Vec<u8>, link: &Link, defined: &mut [bool]) -> &'static str {
+    match *link {
+        Link::Definition(i) if !defined[i] => {
+            defined[i] = true;
+            output.extend_from_slice(format!("<span id=\"def-{i}\">").as_bytes());
+            "</span>"
+        }
+        Link::Definition(_) => "",
+        Link::Reference(i) => {
+            output.extend_from_slice(format!("<a href=\"#def-{i}\">").as_bytes());
+            "</a>"
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::rtf;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages::{self, ALL_HIGHLIGHT_NAMES};
+use crate::themes::{self, Color, SharedTheme};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::markup::{self, Markup};
+use super::{FileOptions, Outcome, Processor, highlight};
+
+/// RTF processor that returns each document as a complete RTF document.
+pub struct RtfProcessor;
+
+pub struct RtfOptions {
+    theme: SharedTheme,
+    /// Every color the theme uses, in color table order. RTF numbers them from 1, because
+    /// entry 0 is the reader's default color.
+    colors: Vec<Color>,
+}
+
+impl RtfOptions {
+    fn color(&self, color: Color) -> usize {
+        self.colors.iter().position(|c| *c == color).map_or(0, |i| i + 1)
+    }
+}
+
+impl Processor for RtfProcessor {
+    type Output = String;
+    type Options = RtfOptions;
+    type WireRequest = wire::rtf::Request;
+    type WireResponse = wire::rtf::Response;
+
+    fn options(request: &Bytes) -> Result<RtfOptions, FatalError> {
+        let request = flatbuffers::root::<rtf::Request>(request)?;
+        let name = request.theme().unwrap_or(themes::DEFAULT_THEME);
+        let theme = themes::from_name(name).ok_or_else(|| FatalError::UnknownTheme(name.to_string()))?;
+        let mut colors: Vec<Color> = vec![];
+        let styles = (0..ALL_HIGHLIGHT_NAMES.len()).map(|i| theme.style(i));
+        for color in [theme.base_style()].into_iter().chain(styles).flat_map(|s| [s.foreground, s.background]) {
+            if let Some(color) = color
+                && !colors.contains(&color)
+            {
+                colors.push(color);
+            }
+        }
+        Ok(RtfOptions { theme, colors })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &RtfOptions,
+    ) -> Outcome<String> {
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let iter = highlight(highlighter, language, &contents, file.include_injections, &cancellation_flag)?;
+            let lines = markup::render_lines(iter, &contents, &RtfMarkup(options))?;
+            Ok(document(&lines, options))
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(document) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: document,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<String>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let rtf = match doc {
+                        Outcome::Success { ref contents, .. } => Some(builder.create_string(contents)),
+                        _ => None,
+                    };
+                    rtf::Document::create(
+                        builder,
+                        &rtf::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            rtf,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = rtf::Response::create(builder, &rtf::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Highlights are groups that set character formatting, which the groups nested inside them
+/// inherit unless they override it.
+struct RtfMarkup<'a>(&'a RtfOptions);
+
+impl Markup for RtfMarkup<'_> {
+    fn open(&self, out: &mut String, highlight: usize) {
+        let style = self.0.theme.style(highlight);
+        out.push('{');
+        if let Some(fg) = style.foreground {
+            let _ = write!(out, "\\cf{}", self.0.color(fg));
+        }
+        if let Some(bg) = style.background {
+            let _ = write!(out, "\\chcbpat{}", self.0.color(bg));
+        }
+        if style.bold {
+            out.push_str("\\b");
+        }
+        if style.italic {
+            out.push_str("\\i");
+        }
+        if style.underline {
+            out.push_str("\\ul");
+        }
+        // A control word is ended by a space, which is not part of the text.
+        if !style.is_empty() {
+            out.push(' ');
+        }
+    }
+
+    fn close(&self, out: &mut String) {
+        out.push('}');
+    }
+
+    fn text(&self, out: &mut String, text: &str) {
+        for c in text.chars() {
+            match c {
+                '\\' | '{' | '}' => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                '\t' => out.push_str("\\tab "),
+                ' '..='~' => out.push(c),
+                c if c.is_control() => {}
+                // `\uN` takes a signed 16-bit UTF-16 code unit, followed by a `?` for readers
+                // that can't display it.
+                c => {
+                    for unit in c.encode_utf16(&mut [0; 2]) {
+                        let _ = write!(out, "\\u{}?", *unit as i16);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Wrap lines of RTF markup in a document with a monospace font and the theme's colors.
+fn document(lines: &[String], options: &RtfOptions) -> String {
+    let mut rtf = String::from("{\\rtf1\\ansi\\deff0{\\fonttbl{\\f0\\fmodern Courier New;}}\n{\\colortbl;");
+    for color in &options.colors {
+        let _ = write!(rtf, "\\red{}\\green{}\\blue{};", color.r, color.g, color.b);
+    }
+    rtf.push_str("}\n\\f0\\fs20");
+    if let Some(fg) = options.theme.foreground {
+        let _ = write!(rtf, "\\cf{}", options.color(fg));
+    }
+    if let Some(bg) = options.theme.background {
+        let _ = write!(rtf, "\\chcbpat{}", options.color(bg));
+    }
+    rtf.push(' ');
+    rtf.push_str(&lines.join("\\par\n"));
+    rtf.push_str("\n}");
+    rtf
+}
@@ -0,0 +1,277 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, LazyLock};
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::common::OffsetEncoding;
+use crate::daylight_generated::daylight::semantic_tokens;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages::{self, ALL_HIGHLIGHT_NAMES};
+use crate::offsets::{self, LineIndex};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, highlight};
+
+/// The standard LSP token types, in legend order.
+pub const TOKEN_TYPES: [&str; 24] = [
+    "namespace",
+    "type",
+    "class",
+    "enum",
+    "interface",
+    "struct",
+    "typeParameter",
+    "parameter",
+    "variable",
+    "property",
+    "enumMember",
+    "event",
+    "function",
+    "method",
+    "macro",
+    "keyword",
+    "modifier",
+    "comment",
+    "string",
+    "number",
+    "regexp",
+    "operator",
+    "decorator",
+    "label",
+];
+
+/// The standard LSP token modifiers, in legend order.
+pub const TOKEN_MODIFIERS: [&str; 10] = [
+    "declaration",
+    "definition",
+    "readonly",
+    "static",
+    "deprecated",
+    "abstract",
+    "async",
+    "modification",
+    "documentation",
+    "defaultLibrary",
+];
+
+/// The token type and modifiers for each capture. Captures missing from this list fall back
+/// to their dotted parent, and captures with no parent here (punctuation) produce no tokens.
+const CAPTURES: &[(&str, &str, &[&str])] = &[
+    ("attribute", "decorator", &[]),
+    ("comment", "comment", &[]),
+    ("comment.documentation", "comment", &["documentation"]),
+    ("constant", "variable", &["readonly"]),
+    ("constant.builtin", "variable", &["readonly", "defaultLibrary"]),
+    ("constant.macro", "macro", &[]),
+    ("constructor", "class", &[]),
+    ("escape", "string", &[]),
+    ("function", "function", &[]),
+    ("function.builtin", "function", &["defaultLibrary"]),
+    ("function.macro", "macro", &[]),
+    ("function.method", "method", &[]),
+    ("function.method.builtin", "method", &["defaultLibrary"]),
+    ("function.special", "macro", &[]),
+    ("include", "keyword", &[]),
+    ("keyword", "keyword", &[]),
+    ("label", "label", &[]),
+    ("module", "namespace", &[]),
+    ("number", "number", &[]),
+    ("operator", "operator", &[]),
+    ("property", "property", &[]),
+    ("property.builtin", "property", &["defaultLibrary"]),
+    ("string", "string", &[]),
+    ("string.special.key", "property", &[]),
+    ("string.special.regex", "regexp", &[]),
+    ("tag", "type", &[]),
+    ("type", "type", &[]),
+    ("type.builtin", "type", &["defaultLibrary"]),
+    ("variable", "variable", &[]),
+    ("variable.builtin", "variable", &["defaultLibrary"]),
+    ("variable.parameter", "parameter", &[]),
+];
+
+/// `(tokenType, tokenModifiers)` for each entry in `ALL_HIGHLIGHT_NAMES`.
+static TOKENS: LazyLock<Vec<Option<(u32, u32)>>> = LazyLock::new(|| {
+    let lookup = |name: &str| {
+        let (_, token_type, modifiers) = CAPTURES.iter().find(|(c, _, _)| *c == name)?;
+        let token_type = TOKEN_TYPES.iter().position(|t| t == token_type)? as u32;
+        let modifiers = modifiers
+            .iter()
+            .filter_map(|m| TOKEN_MODIFIERS.iter().position(|n| n == m))
+            .fold(0, |bits, i| bits | 1 << i);
+        Some((token_type, modifiers))
+    };
+    ALL_HIGHLIGHT_NAMES
+        .iter()
+        .map(|name| {
+            let mut name = *name;
+            loop {
+                if let Some(token) = lookup(name) {
+                    return Some(token);
+                }
+                name = &name[..name.rfind('.')?];
+            }
+        })
+        .collect()
+});
+
+/// Semantic tokens processor that returns LSP delta-encoded token data.
+pub struct SemanticTokensProcessor;
+
+pub struct SemanticTokensOptions {
+    position_encoding: OffsetEncoding,
+}
+
+impl Processor for SemanticTokensProcessor {
+    type Output = Vec<u32>;
+    type Options = SemanticTokensOptions;
+    type WireRequest = wire::semantic_tokens::Request;
+    type WireResponse = wire::semantic_tokens::Response;
+
+    fn options(request: &Bytes) -> Result<SemanticTokensOptions, FatalError> {
+        let request = flatbuffers::root::<semantic_tokens::Request>(request)?;
+        Ok(SemanticTokensOptions {
+            position_encoding: request.position_encoding(),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &SemanticTokensOptions,
+    ) -> Outcome<Vec<u32>> {
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let iter = highlight(highlighter, language, &contents, file.include_injections, &cancellation_flag)?;
+            let lines = LineIndex::new(&contents, options.position_encoding);
+            let mut encoder = Encoder::default();
+            let mut stack: Vec<Option<(u32, u32)>> = vec![];
+            for event in iter {
+                match event? {
+                    ts::HighlightEvent::HighlightStart(h) => {
+                        // Captures without a token type take on that of their enclosing capture.
+                        let inherited = stack.last().copied().flatten();
+                        stack.push(TOKENS[h.0].or(inherited));
+                    }
+                    ts::HighlightEvent::HighlightEnd => {
+                        stack.pop();
+                    }
+                    ts::HighlightEvent::Source { start, end } => {
+                        let Some(Some(token)) = stack.last().copied() else {
+                            continue;
+                        };
+                        // Tokens may not span lines, so multi-line captures are split up.
+                        let mut line_start = start;
+                        for piece in contents[start..end].split(|&b| b == b'\n') {
+                            let piece_end = line_start + piece.len();
+                            let text = piece.strip_suffix(b"\r").unwrap_or(piece);
+                            let length = offsets::units(text, options.position_encoding);
+                            if length > 0 {
+                                let (line, column) = lines.position(line_start);
+                                encoder.push(line as u32, column as u32, length as u32, token);
+                            }
+                            line_start = piece_end + 1;
+                        }
+                    }
+                }
+            }
+            Ok(encoder.data)
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(data) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: data,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<Vec<u32>>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let data = match doc {
+                        Outcome::Success { ref contents, .. } => Some(builder.create_vector(contents)),
+                        _ => None,
+                    };
+                    semantic_tokens::Document::create(
+                        builder,
+                        &semantic_tokens::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            data,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let token_types: Vec<_> = TOKEN_TYPES.iter().map(|t| builder.create_string(t)).collect();
+            let token_types = Some(builder.create_vector(&token_types));
+            let token_modifiers: Vec<_> = TOKEN_MODIFIERS.iter().map(|m| builder.create_string(m)).collect();
+            let token_modifiers = Some(builder.create_vector(&token_modifiers));
+            let legend = Some(semantic_tokens::Legend::create(
+                builder,
+                &semantic_tokens::LegendArgs {
+                    token_types,
+                    token_modifiers,
+                },
+            ));
+            let response = semantic_tokens::Response::create(
+                builder,
+                &semantic_tokens::ResponseArgs { legend, documents },
+            );
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Builds LSP's relative token encoding, where each token's position is given
+/// relative to the one before it.
+#[derive(Default)]
+struct Encoder {
+    data: Vec<u32>,
+    line: u32,
+    column: u32,
+}
+
+impl Encoder {
+    /// Tokens must arrive in document order. One that starts before the last token can't be
+    /// encoded, so it's dropped rather than wrapping the deltas around.
+    fn push(&mut self, line: u32, column: u32, length: u32, (token_type, modifiers): (u32, u32)) {
+        let delta_line = line.checked_sub(self.line);
+        let delta_column = match delta_line {
+            Some(0) => column.checked_sub(self.column),
+            _ => Some(column),
+        };
+        let (Some(delta_line), Some(delta_column)) = (delta_line, delta_column) else {
+            tracing::warn!("Dropping out-of-order token at {line}:{column}");
+            return;
+        };
+        self.data.extend([delta_line, delta_column, length, token_type, modifiers]);
+        self.line = line;
+        self.column = column;
+    }
+}
@@ -7,42 +7,67 @@ use http::StatusCode;
 use tracing::instrument;
 use tree_sitter_highlight as ts;
 
+use crate::daylight_generated::daylight::common::OffsetEncoding;
 use crate::daylight_generated::daylight::spans;
 use crate::errors::FatalError;
 use crate::languages::{self, ALL_HIGHLIGHT_NAMES};
+use crate::offsets::LineIndex;
 use crate::thread_locals::ThreadState;
+use crate::wire;
 
-use super::{Outcome, Processor};
+use super::{FileOptions, Outcome, Processor, highlight};
 
 /// Spans processor that returns numeric highlight span information.
 pub struct SpansProcessor;
 
+pub struct SpansOptions {
+    offset_encoding: OffsetEncoding,
+    include_positions: bool,
+}
+
+/// A highlighted range, with offsets already converted to the requested encoding.
+pub struct Span {
+    pub index: usize,
+    pub start: usize,
+    pub end: usize,
+    /// The `(line, column)` of `start` and `end`, if positions were requested.
+    pub positions: Option<((usize, usize), (usize, usize))>,
+    /// Every capture enclosing this range, outermost first. The last is `index`.
+    pub scopes: Vec<u16>,
+}
+
+/// The spans of a document, and where each of its lines starts.
+#[derive(Default)]
+pub struct SpansDocument {
+    pub spans: Vec<Span>,
+    pub line_starts: Vec<usize>,
+}
+
 impl Processor for SpansProcessor {
-    type Output = (usize, usize, usize);
+    type Output = SpansDocument;
+    type Options = SpansOptions;
+    type WireRequest = wire::spans::Request;
+    type WireResponse = wire::spans::Response;
+
+    fn options(request: &Bytes) -> Result<SpansOptions, FatalError> {
+        let request = flatbuffers::root::<spans::Request>(request)?;
+        Ok(SpansOptions {
+            offset_encoding: request.offset_encoding(),
+            include_positions: request.include_positions(),
+        })
+    }
 
     fn process(
         ident: u16,
         filename: Arc<str>,
         language: languages::SharedConfig,
         contents: Bytes,
-        include_injections: bool,
+        file: &FileOptions,
         cancellation_flag: Arc<AtomicUsize>,
-    ) -> Outcome<(usize, usize, usize)> {
+        options: &SpansOptions,
+    ) -> Outcome<SpansDocument> {
         ThreadState::highlight_with_tree_sitter(|highlighter| {
-            let iter_res = {
-                highlighter.highlight(
-                    &language.ts_config,
-                    &contents,
-                    Some(&cancellation_flag),
-                    |s| {
-                        if include_injections {
-                            languages::from_name(s).map(|l| &l.ts_config)
-                        } else {
-                            None
-                        }
-                    },
-                )
-            };
+            let iter_res = highlight(highlighter, language, &contents, file.include_injections, &cancellation_flag);
 
             let iter = match iter_res {
                 Ok(iter) => iter,
@@ -51,69 +76,109 @@ impl Processor for SpansProcessor {
                 }
             };
 
-            let mut spans: Vec<(usize, usize, usize)> = vec![];
-            let mut active_index: Option<usize> = None;
+            // Highlights nest (`string.escape` inside `string`, or a whole injected language
+            // inside a host capture), so every span carries the full stack of open captures.
+            let mut spans: Vec<(Vec<u16>, usize, usize)> = vec![];
+            let mut stack: Vec<u16> = vec![];
             use ts::HighlightEvent;
-            for item in iter {
-                if let Ok(evt) = item {
-                    match (evt, active_index) {
-                        (HighlightEvent::Source { start, end }, Some(active)) => {
-                            spans.push((active, start, end))
-                        }
-                        (HighlightEvent::HighlightStart(highlight), None) => {
-                            active_index = Some(highlight.0)
-                        }
-                        (HighlightEvent::HighlightEnd, None) => active_index = None,
-                        _ => tracing::warn!("Unexpected event {evt:?} with index {active_index:?}"),
+            for evt in iter.flatten() {
+                match evt {
+                    HighlightEvent::HighlightStart(highlight) => stack.push(highlight.0 as u16),
+                    HighlightEvent::HighlightEnd => {
+                        stack.pop();
+                    }
+                    HighlightEvent::Source { start, end } if !stack.is_empty() => {
+                        spans.push((stack.clone(), start, end))
                     }
+                    HighlightEvent::Source { .. } => {}
                 }
             }
+            let lines = LineIndex::new(&contents, options.offset_encoding);
+            let window = file.window(lines.line_starts().len());
+            let bytes = lines.byte_range(window.clone());
+            // Spans crossing the window's edges are clipped to it, so that they only cover the
+            // lines that are returned.
+            let spans = spans
+                .into_iter()
+                .filter(|(_, start, end)| *start < bytes.end && *end > bytes.start)
+                .map(|(scopes, start, end)| (scopes, start.max(bytes.start), end.min(bytes.end)))
+                .map(|(scopes, start, end)| {
+                    // Each end is looked up once, in ascending order, so that the index can
+                    // carry on from the last one.
+                    let (start, end) = (lines.position(start), lines.position(end));
+                    let offset = |(line, column): (usize, usize)| lines.line_starts()[line] + column;
+                    Span {
+                        index: *scopes.last().unwrap() as usize,
+                        start: offset(start),
+                        end: offset(end),
+                        positions: options.include_positions.then_some((start, end)),
+                        scopes,
+                    }
+                })
+                .collect();
             Outcome::Success {
                 ident,
                 filename,
                 language,
-                contents: spans,
+                contents: SpansDocument {
+                    spans,
+                    line_starts: lines.line_starts()[window].to_vec(),
+                },
             }
         })
     }
 
     #[instrument(skip(outputs), fields(count = outputs.len()))]
     fn build_response(
-        outputs: Vec<Outcome<(usize, usize, usize)>>,
+        outputs: Vec<Outcome<SpansDocument>>,
     ) -> Result<axum::response::Response, FatalError> {
-        ThreadState::build_flatbuffers(|mut builder| {
+        ThreadState::build_flatbuffers(|builder| {
             builder.reset();
             let documents = outputs
                 .into_iter()
                 .map(|doc| {
                     let filename = builder.create_string(doc.filename());
-                    let spans = match doc {
+                    let (spans, line_starts) = match doc {
                         Outcome::Success { ref contents, .. } => {
                             let line_offsets: Vec<_> = contents
-                                .into_iter()
-                                .map(|line| {
+                                .spans
+                                .iter()
+                                .map(|span| {
+                                    let ((start_line, start_column), (end_line, end_column)) =
+                                        span.positions.unwrap_or_default();
+                                    let scopes = Some(builder.create_vector(&span.scopes));
                                     spans::Span::create(
-                                        &mut builder,
+                                        builder,
                                         &spans::SpanArgs {
-                                            index: line.0 as u16,
-                                            start: line.1 as u64,
-                                            end: line.2 as u64,
+                                            index: span.index as u16,
+                                            start: span.start as u64,
+                                            end: span.end as u64,
+                                            start_line: start_line as u32,
+                                            start_column: start_column as u32,
+                                            end_line: end_line as u32,
+                                            end_column: end_column as u32,
+                                            scopes,
                                         },
                                     )
                                 })
                                 .collect();
-                            Some(builder.create_vector(&line_offsets))
+                            let line_starts: Vec<u64> = contents.line_starts.iter().map(|&s| s as u64).collect();
+                            (
+                                Some(builder.create_vector(&line_offsets)),
+                                Some(builder.create_vector(&line_starts)),
+                            )
                         }
-                        _ => None,
+                        _ => (None, None),
                     };
                     spans::Document::create(
-                        &mut builder,
+                        builder,
                         &spans::DocumentArgs {
                             ident: doc.ident(),
                             filename: Some(filename),
                             language: doc.language(),
                             spans,
                             error_code: doc.error_code(),
+                            line_starts,
                         },
                     )
                 })
@@ -127,7 +192,7 @@ impl Processor for SpansProcessor {
             let highlight_names = Some(builder.create_vector(&highlight_names));
 
             let response = spans::Response::create(
-                &mut builder,
+                builder,
                 &spans::ResponseArgs {
                     documents,
                     highlight_names,
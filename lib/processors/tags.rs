@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter::{Node, QueryCursor, StreamingIterator};
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::tags;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages::{self, TagsConfiguration};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, parse};
+
+/// Tags processor that returns the symbols each file defines and refers to.
+pub struct TagsProcessor;
+
+/// A symbol defined or referred to in a file.
+#[derive(Debug)]
+pub struct Tag {
+    /// The kind of symbol, from the tags query's `@definition.<kind>` or `@reference.<kind>`.
+    pub kind: &'static str,
+    pub name: String,
+    pub is_definition: bool,
+    /// Byte offsets of the whole definition or reference.
+    pub start: usize,
+    pub end: usize,
+    /// Byte offsets of the name.
+    pub name_start: usize,
+    pub name_end: usize,
+    /// Zero-based lines of `start` and `end`.
+    pub start_line: usize,
+    pub end_line: usize,
+    pub docs: Option<String>,
+}
+
+impl Processor for TagsProcessor {
+    type Output = Vec<Tag>;
+    type Options = ();
+    type WireRequest = wire::tags::Request;
+    type WireResponse = wire::tags::Response;
+
+    fn options(request: &Bytes) -> Result<(), FatalError> {
+        flatbuffers::root::<tags::Request>(request)?;
+        Ok(())
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, _options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        _file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        _options: &(),
+    ) -> Outcome<Vec<Tag>> {
+        let result = match language.tags() {
+            Some(config) => ThreadState::parse_with_tree_sitter(|parser| {
+                let tree = parse(parser, language, &contents, &cancellation_flag)?;
+                find_tags(config, tree.root_node(), &contents, &cancellation_flag)
+            }),
+            None => Ok(vec![]),
+        }
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(tags) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: tags,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<Vec<Tag>>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let tags = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let tags: Vec<_> = contents
+                                .iter()
+                                .map(|tag| {
+                                    let kind = Some(builder.create_string(tag.kind));
+                                    let name = Some(builder.create_string(&tag.name));
+                                    let docs = tag.docs.as_deref().map(|docs| builder.create_string(docs));
+                                    tags::Tag::create(
+                                        builder,
+                                        &tags::TagArgs {
+                                            kind,
+                                            name,
+                                            is_definition: tag.is_definition,
+                                            start: tag.start as u64,
+                                            end: tag.end as u64,
+                                            name_start: tag.name_start as u64,
+                                            name_end: tag.name_end as u64,
+                                            start_line: tag.start_line as u32,
+                                            end_line: tag.end_line as u32,
+                                            docs,
+                                        },
+                                    )
+                                })
+                                .collect();
+                            Some(builder.create_vector(&tags))
+                        }
+                        _ => None,
+                    };
+                    tags::Document::create(
+                        builder,
+                        &tags::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            tags,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = tags::Response::create(builder, &tags::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Run a tags query over a syntax tree. When several patterns tag the same name (a Rust
+/// function inside an `impl` is both a method and a function), the first pattern wins.
+fn find_tags(
+    config: &'static TagsConfiguration,
+    root: Node,
+    contents: &[u8],
+    cancellation_flag: &AtomicUsize,
+) -> Result<Vec<Tag>, ts::Error> {
+    let query = &config.query;
+    let names = query.capture_names();
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(query, root, contents);
+    let mut found: BTreeMap<(usize, usize), (usize, Tag)> = BTreeMap::new();
+    while let Some(m) = matches.next() {
+        if cancellation_flag.load(Ordering::SeqCst) != 0 {
+            return Err(ts::Error::Cancelled);
+        }
+        let mut name = None;
+        let mut syntax = None;
+        let mut docs = vec![];
+        for capture in m.captures {
+            match names[capture.index as usize] {
+                "name" => name = Some(capture.node),
+                "doc" => docs.push(capture.node),
+                capture_name => {
+                    if let Some(kind) = capture_name.strip_prefix("definition.") {
+                        syntax = Some((kind, true, capture));
+                    } else if let Some(kind) = capture_name.strip_prefix("reference.") {
+                        syntax = Some((kind, false, capture));
+                    }
+                }
+            }
+        }
+        let (Some(name), Some((kind, is_definition, capture))) = (name, syntax) else {
+            continue;
+        };
+        let key = (name.start_byte(), name.end_byte());
+        if found.get(&key).is_some_and(|(pattern, _)| *pattern <= m.pattern_index) {
+            continue;
+        }
+
+        let pattern = &config.patterns[m.pattern_index];
+        // Only the comments that run, line after line, up to the definition document it.
+        let mut first_doc = 0;
+        if let Some(adjacent) = pattern.doc_adjacent_to {
+            let mut row = m
+                .captures
+                .iter()
+                .find(|c| c.index == adjacent)
+                .map_or(capture.node.start_position().row, |c| c.node.start_position().row);
+            first_doc = docs.len();
+            for (i, doc) in docs.iter().enumerate().rev() {
+                if doc.end_position().row + 1 < row {
+                    break;
+                }
+                first_doc = i;
+                row = doc.start_position().row;
+            }
+        }
+        let docs: Vec<String> = docs[first_doc..]
+            .iter()
+            .map(|doc| {
+                let text = String::from_utf8_lossy(&contents[doc.byte_range()]);
+                match &pattern.doc_strip {
+                    Some(regex) => regex.replace_all(&text, "").into_owned(),
+                    None => text.into_owned(),
+                }
+            })
+            .collect();
+
+        let node = capture.node;
+        let tag = Tag {
+            kind,
+            name: String::from_utf8_lossy(&contents[name.byte_range()]).into_owned(),
+            is_definition,
+            start: node.start_byte(),
+            end: node.end_byte(),
+            name_start: name.start_byte(),
+            name_end: name.end_byte(),
+            start_line: node.start_position().row,
+            end_line: node.end_position().row,
+            docs: (!docs.is_empty()).then(|| docs.join("\n")),
+        };
+        found.insert(key, (m.pattern_index, tag));
+    }
+    Ok(found.into_values().map(|(_, tag)| tag).collect())
+}
@@ -0,0 +1,264 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::tree;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages;
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::{FileOptions, Outcome, Processor, parse};
+
+pub use crate::daylight_generated::daylight::tree::TreeFormat;
+
+/// Tree processor that returns the syntax trees tree-sitter parses files into.
+pub struct TreeProcessor;
+
+pub struct TreeOptions {
+    format: TreeFormat,
+    max_depth: Option<usize>,
+    byte_start: usize,
+    /// The end of the file if not set.
+    byte_end: Option<usize>,
+}
+
+/// A node of a syntax tree.
+#[derive(Debug)]
+pub struct Node {
+    pub kind: &'static str,
+    pub field_name: Option<&'static str>,
+    pub is_named: bool,
+    /// Byte offsets.
+    pub start: usize,
+    pub end: usize,
+    /// Zero-based lines and byte columns of `start` and `end`.
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub parent: Option<usize>,
+}
+
+/// The syntax tree of a file, as nodes or as an S-expression.
+#[derive(Default)]
+pub struct TreeDocument {
+    pub nodes: Vec<Node>,
+    pub sexp: Option<String>,
+}
+
+impl Processor for TreeProcessor {
+    type Output = TreeDocument;
+    type Options = TreeOptions;
+    type WireRequest = wire::tree::Request;
+    type WireResponse = wire::tree::Response;
+
+    fn options(request: &Bytes) -> Result<TreeOptions, FatalError> {
+        let request = flatbuffers::root::<tree::Request>(request)?;
+        Ok(TreeOptions {
+            format: request.format(),
+            max_depth: (request.max_depth() != 0).then_some(request.max_depth() as usize),
+            byte_start: request.byte_start() as usize,
+            byte_end: (request.byte_end() != 0).then_some(request.byte_end() as usize),
+        })
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        _file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        options: &TreeOptions,
+    ) -> Outcome<TreeDocument> {
+        let result = ThreadState::parse_with_tree_sitter(|parser| {
+            let tree = parse(parser, language, &contents, &cancellation_flag)?;
+            let mut walker = TreeWalker {
+                options,
+                byte_end: options.byte_end.unwrap_or(contents.len()),
+                document: TreeDocument {
+                    nodes: vec![],
+                    sexp: (options.format == TreeFormat::SExpression).then(String::new),
+                },
+                cancellation_flag: &cancellation_flag,
+            };
+            walker.walk(&tree)?;
+            Ok(walker.document)
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(document) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: document,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<TreeDocument>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let (nodes, sexp) = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let nodes: Vec<_> = contents
+                                .nodes
+                                .iter()
+                                .map(|node| {
+                                    let kind = Some(builder.create_string(node.kind));
+                                    let field_name = node.field_name.map(|name| builder.create_string(name));
+                                    tree::Node::create(
+                                        builder,
+                                        &tree::NodeArgs {
+                                            kind,
+                                            field_name,
+                                            is_named: node.is_named,
+                                            start: node.start as u64,
+                                            end: node.end as u64,
+                                            start_line: node.start_line as u32,
+                                            start_column: node.start_column as u32,
+                                            end_line: node.end_line as u32,
+                                            end_column: node.end_column as u32,
+                                            parent: node.parent.map_or(-1, |i| i as i32),
+                                        },
+                                    )
+                                })
+                                .collect();
+                            let sexp = contents.sexp.as_deref().map(|sexp| builder.create_string(sexp));
+                            (Some(builder.create_vector(&nodes)), sexp)
+                        }
+                        _ => (None, None),
+                    };
+                    tree::Document::create(
+                        builder,
+                        &tree::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            nodes,
+                            sexp,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = tree::Response::create(builder, &tree::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Walks a syntax tree in preorder, writing out the nodes within the requested depth and range.
+struct TreeWalker<'a> {
+    options: &'a TreeOptions,
+    byte_end: usize,
+    document: TreeDocument,
+    cancellation_flag: &'a AtomicUsize,
+}
+
+impl TreeWalker<'_> {
+    /// Walk with a cursor rather than by recursion, which deeply nested files would overflow.
+    fn walk(&mut self, tree: &tree_sitter::Tree) -> Result<(), ts::Error> {
+        let mut cursor = tree.walk();
+        // For each node from the root to the cursor, its index in `nodes` and whether it opened
+        // a list in `sexp`.
+        let mut path: Vec<(Option<usize>, bool)> = vec![];
+        loop {
+            if self.cancellation_flag.load(Ordering::SeqCst) != 0 {
+                return Err(ts::Error::Cancelled);
+            }
+            let node = cursor.node();
+            let included = path.is_empty() || self.overlaps(node);
+            if included {
+                let parent = path.last().and_then(|(index, _)| *index);
+                path.push(self.open(node, cursor.field_name(), parent));
+                let deeper = self.options.max_depth.is_none_or(|max| path.len() <= max);
+                if deeper && cursor.goto_first_child() {
+                    continue;
+                }
+                self.close(path.pop().expect("pushed above"));
+            }
+            // Move on to the next sibling, leaving the nodes that have none.
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    return Ok(());
+                }
+                self.close(path.pop().expect("the cursor's ancestors are on the path"));
+            }
+        }
+    }
+
+    /// Whether a node overlaps the requested range. An empty node, such as a missing one,
+    /// overlaps it if it's inside it.
+    fn overlaps(&self, node: tree_sitter::Node) -> bool {
+        let (start, end) = (node.start_byte(), node.end_byte());
+        start < self.byte_end && (end > self.options.byte_start || start >= self.options.byte_start)
+    }
+
+    /// Write out a node, returning its index in `nodes` and whether it opened a list in `sexp`.
+    fn open(
+        &mut self,
+        node: tree_sitter::Node,
+        field_name: Option<&'static str>,
+        parent: Option<usize>,
+    ) -> (Option<usize>, bool) {
+        let Some(sexp) = &mut self.document.sexp else {
+            self.document.nodes.push(Node {
+                kind: node.kind(),
+                field_name,
+                is_named: node.is_named(),
+                start: node.start_byte(),
+                end: node.end_byte(),
+                start_line: node.start_position().row,
+                start_column: node.start_position().column,
+                end_line: node.end_position().row,
+                end_column: node.end_position().column,
+                parent,
+            });
+            return (Some(self.document.nodes.len() - 1), false);
+        };
+        // Like tree-sitter's own S-expressions, leave out anonymous nodes unless they're missing.
+        if !node.is_named() && !node.is_missing() {
+            return (None, false);
+        }
+        if !sexp.is_empty() {
+            sexp.push(' ');
+        }
+        if let Some(field_name) = field_name {
+            sexp.push_str(field_name);
+            sexp.push_str(": ");
+        }
+        sexp.push('(');
+        match (node.is_missing(), node.is_named()) {
+            (true, true) => sexp.push_str(&format!("MISSING {}", node.kind())),
+            (true, false) => sexp.push_str(&format!("MISSING {:?}", node.kind())),
+            _ => sexp.push_str(node.kind()),
+        }
+        (None, true)
+    }
+
+    fn close(&mut self, (_, opened): (Option<usize>, bool)) {
+        if opened && let Some(sexp) = &mut self.document.sexp {
+            sexp.push(')');
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use axum::body::Bytes;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use tracing::instrument;
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::typst;
+use crate::errors::{FatalError, NonFatalError};
+use crate::languages::{self, ALL_HIGHLIGHT_NAMES};
+use crate::themes::{Style, Theme};
+use crate::thread_locals::ThreadState;
+use crate::wire;
+
+use super::markup::{self, Markup};
+use super::{FileOptions, Outcome, Processor, highlight};
+
+/// Typst processor that returns lines of markup calling the functions defined by `preamble`.
+pub struct TypstProcessor;
+
+impl Processor for TypstProcessor {
+    type Output = Vec<String>;
+    type Options = ();
+    type WireRequest = wire::typst::Request;
+    type WireResponse = wire::typst::Response;
+
+    fn options(_request: &Bytes) -> Result<(), FatalError> {
+        Ok(())
+    }
+
+    #[instrument(skip(language, contents, cancellation_flag, _options))]
+    fn process(
+        ident: u16,
+        filename: Arc<str>,
+        language: languages::SharedConfig,
+        contents: Bytes,
+        file: &FileOptions,
+        cancellation_flag: Arc<AtomicUsize>,
+        _options: &(),
+    ) -> Outcome<Vec<String>> {
+        let result = ThreadState::highlight_with_tree_sitter(|highlighter| {
+            let iter = highlight(highlighter, language, &contents, file.include_injections, &cancellation_flag)?;
+            markup::render_lines(iter, &contents, &TypstMarkup)
+        })
+        .map_err(|e: ts::Error| NonFatalError::from(e));
+
+        match result {
+            Ok(lines) => Outcome::Success {
+                ident,
+                filename,
+                language,
+                contents: lines,
+            },
+            Err(err) => Outcome::failure(ident, filename, Some(language), err),
+        }
+    }
+
+    #[instrument(skip(outputs), fields(count = outputs.len()))]
+    fn build_response(
+        outputs: Vec<Outcome<Vec<String>>>,
+    ) -> Result<axum::response::Response, FatalError> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let documents = outputs
+                .into_iter()
+                .map(|doc| {
+                    let filename = builder.create_string(doc.filename());
+                    let lines = match doc {
+                        Outcome::Success { ref contents, .. } => {
+                            let line_offsets: Vec<_> = contents
+                                .iter()
+                                .map(|line| builder.create_string(line))
+                                .collect();
+                            Some(builder.create_vector(&line_offsets))
+                        }
+                        _ => None,
+                    };
+                    typst::Document::create(
+                        builder,
+                        &typst::DocumentArgs {
+                            ident: doc.ident(),
+                            filename: Some(filename),
+                            language: doc.language(),
+                            lines,
+                            error_code: doc.error_code(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            let documents = Some(builder.create_vector(&documents));
+            let response = typst::Response::create(builder, &typst::ResponseArgs { documents });
+            builder.finish(response, None);
+            let response_bytes = builder.finished_data();
+            Ok((StatusCode::OK, Bytes::copy_from_slice(response_bytes)).into_response())
+        })
+    }
+}
+
+/// Highlights are function calls with a content block, and source text is always a string
+/// literal, so nothing in the source is ever parsed as Typst markup.
+struct TypstMarkup;
+
+impl Markup for TypstMarkup {
+    fn open(&self, out: &mut String, highlight: usize) {
+        let _ = write!(out, "#{}[", function_name(ALL_HIGHLIGHT_NAMES[highlight]));
+    }
+
+    fn close(&self, out: &mut String) {
+        out.push(']');
+    }
+
+    fn text(&self, out: &mut String, text: &str) {
+        out.push_str("#\"");
+        for c in text.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '"' => out.push_str("\\\""),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+}
+
+/// The function used for a capture: `function.method` is `daylight-function-method`.
+fn function_name(capture: &str) -> String {
+    format!("daylight-{}", capture.replace('.', "-"))
+}
+
+/// Wrap `body` in the Typst that draws a style.
+fn style_body(style: Style) -> String {
+    let mut args = vec![];
+    if let Some(fg) = style.foreground {
+        args.push(format!("fill: rgb(\"{fg}\")"));
+    }
+    if style.bold {
+        args.push("weight: \"bold\"".to_string());
+    }
+    if style.italic {
+        args.push("style: \"italic\"".to_string());
+    }
+    let mut body = if args.is_empty() {
+        "body".to_string()
+    } else {
+        format!("text({}, body)", args.join(", "))
+    };
+    if style.underline {
+        body = format!("underline({body})");
+    }
+    if let Some(bg) = style.background {
+        body = format!("highlight(fill: rgb(\"{bg}\"), {body})");
+    }
+    body
+}
+
+/// A preamble defining the functions used by `/v1/typst` output, styled by `theme`. Every
+/// capture gets a function, so output from any theme (or none) compiles against any preamble.
+pub fn preamble(theme: &Theme) -> String {
+    let mut typ = format!("// daylight theme: {}\n", theme.name);
+    if let Some(fg) = theme.foreground {
+        let _ = writeln!(typ, "#let daylight-foreground = rgb(\"{fg}\")");
+    }
+    if let Some(bg) = theme.background {
+        let _ = writeln!(typ, "#let daylight-background = rgb(\"{bg}\")");
+    }
+    for (i, name) in ALL_HIGHLIGHT_NAMES.iter().enumerate() {
+        let _ = writeln!(typ, "#let {}(body) = {}", function_name(name), style_body(theme.style(i)));
+    }
+    typ
+}
@@ -1,21 +1,32 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use crate::classes::{ClassNames, ClassStyle};
 use crate::daylight_generated::daylight::common::{self};
-use crate::daylight_generated::daylight::html;
+use crate::encoding::{Encoding, Streaming};
 use crate::errors::{FatalError, NonFatalError};
 use crate::languages;
-use crate::processors::{HtmlProcessor, Processor, SpansProcessor};
+use crate::daylight_generated::daylight::session;
+use crate::processors::{
+    AnsiProcessor, DiagnosticsProcessor, DiffProcessor, FileOptions, FoldsProcessor, HtmlProcessor, ImageProcessor, LatexProcessor, MarkdownProcessor, Outcome, PatchProcessor, QueryProcessor, Processor, ReferencesProcessor, RtfProcessor, SemanticTokensProcessor, SpansProcessor, TagsProcessor, TreeProcessor, TypstProcessor,
+    latex_preamble, typst_preamble,
+};
+use crate::sessions::{self, Edit, EditError, Session, SessionUpdate};
+use crate::streaming;
+use crate::themes;
+use crate::websocket;
+use crate::wire;
 
 use axum::{
     body::Bytes,
     extract,
-    routing::{get, post},
+    response::IntoResponse,
+    routing::{delete, get, post},
     Router,
 };
 use futures::stream::FuturesUnordered;
 use futures::{FutureExt, StreamExt};
-use http::Request;
+use http::{header, HeaderMap, Request, StatusCode};
 use tokio::time::Duration;
 use tower_http::request_id::RequestId;
 use tracing::instrument;
@@ -38,16 +49,16 @@ fn prepare_file_contents(
     filename: Arc<str>,
     // Sent by reference to avoid writing Result<(Bytes, Language), (NonFatalError, Language)>.
     language: &mut Option<languages::SharedConfig>,
+    allows_empty: bool,
 ) -> Result<Bytes, NonFatalError> {
-    *language = if file.language() == common::Language::Unspecified {
-        languages::from_path(std::path::Path::new(filename.as_ref()))
-    } else {
-        file.language().try_into().ok()
-    };
+    *language = file_language(file, &filename);
 
     if language.is_none() {
         Err(NonFatalError::InvalidLanguage)?
     } else if file.contents().is_none_or(|s| s.is_empty()) {
+        if allows_empty {
+            return Ok(Bytes::new());
+        }
         Err(NonFatalError::EmptyFile)?
     } else if file.contents().unwrap().bytes().len() > MAX_FILE_SIZE {
         Err(NonFatalError::FileTooLarge)?
@@ -59,15 +70,29 @@ fn prepare_file_contents(
     Ok(contents)
 }
 
-/// Generic handler that processes files using a specific Processor implementation.
-#[instrument(err, skip(state, body), fields(num_files, timeout_ms, request_size = body.len()))]
-pub async fn generic_handler<P: Processor>(
-    extract::State(state): extract::State<Server>,
-    body: Bytes,
-) -> Result<axum::response::Response, FatalError> {
-    // Prepare this request.
-    let request = flatbuffers::root::<html::Request>(&body)?;
-    let timeout_ms = request.timeout_ms();
+/// The language a file is in: the one it names, or else the one its filename suggests.
+fn file_language(file: &common::File<'_>, filename: &str) -> Option<languages::SharedConfig> {
+    if file.language() == common::Language::Unspecified {
+        languages::from_path(std::path::Path::new(filename))
+    } else {
+        file.language().try_into().ok()
+    }
+}
+
+/// Convert a request body to Flatbuffers, returning it with the encoding to respond in.
+/// Other encodings are converted up front, so that handlers only deal with one request format.
+fn decode_request<R: wire::Request>(headers: &HeaderMap, body: Bytes) -> Result<(Bytes, Encoding), FatalError> {
+    let request_encoding = Encoding::of_request(headers)?;
+    let response_encoding = Encoding::of_response(headers, request_encoding);
+    let body = match request_encoding {
+        Encoding::Flatbuffers => body,
+        encoding => Bytes::from(encoding.decode::<R>(&body)?.to_flatbuffers()),
+    };
+    Ok((body, response_encoding))
+}
+
+/// The time each file may take, from a request's `timeout_ms`.
+fn per_file_timeout(state: &Server, timeout_ms: u64) -> Result<Duration, FatalError> {
     let timeout = if timeout_ms == 0 {
         state.default_per_file_timeout
     } else {
@@ -76,13 +101,61 @@ pub async fn generic_handler<P: Processor>(
     if timeout > state.max_per_file_timeout {
         Err(FatalError::TimeoutTooLarge(state.max_per_file_timeout))?
     }
-    let timeout_flag: Arc<AtomicUsize> = Arc::default();
+    Ok(timeout)
+}
+
+/// Build a response in the negotiated encoding.
+fn respond<P: Processor>(
+    encoding: Encoding,
+    outputs: Vec<Outcome<P::Output>>,
+) -> Result<axum::response::Response, FatalError> {
+    match encoding {
+        Encoding::Flatbuffers => P::build_response(outputs),
+        encoding => encoding.encode(&P::WireResponse::from(outputs)),
+    }
+}
+
+/// Build a Flatbuffers response holding a single document, to be sent on its own.
+pub(crate) async fn document_flatbuffers<P: Processor>(outcome: Outcome<P::Output>) -> Result<Bytes, FatalError> {
+    let body = P::build_response(vec![outcome])?.into_body();
+    axum::body::to_bytes(body, usize::MAX)
+        .await
+        .map_err(|e| FatalError::EncodeError(Encoding::Flatbuffers, e.to_string()))
+}
+
+/// Generic handler that processes files using a specific Processor implementation.
+/// Requests and responses are Flatbuffers unless `Content-Type` or `Accept` say otherwise.
+/// If `Accept` asks for a stream, each document is written as soon as it's done.
+#[instrument(err, skip(state, headers, body), fields(num_files, timeout_ms, request_size = body.len()))]
+pub async fn generic_handler<P: Processor>(
+    extract::State(state): extract::State<Server>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<axum::response::Response, FatalError> {
+    let (body, response_encoding) = decode_request::<P::WireRequest>(&headers, body)?;
+    let tasks = process_files::<P>(&state, body, Arc::default())?;
+    if let Some(streaming) = Streaming::of_request(&headers) {
+        return Ok(streaming::respond::<P>(streaming, tasks));
+    }
+    // Wait on all in-flight tasks simultaneously with .collect() and build a response.
+    respond::<P>(response_encoding, tasks.collect().await)
+}
+
+/// Start processing every file in a Flatbuffers request, yielding each file's outcome as it
+/// finishes. Raising `timeout_flag` cancels every file that's still being processed.
+pub(crate) fn process_files<P: Processor>(
+    state: &Server,
+    body: Bytes,
+    timeout_flag: Arc<AtomicUsize>,
+) -> Result<FuturesUnordered<impl Future<Output = Outcome<P::Output>> + Send + use<P>>, FatalError> {
+    // Prepare this request.
+    let request = flatbuffers::root::<common::Request>(&body)?;
+    let timeout_ms = request.timeout_ms();
+    let timeout = per_file_timeout(state, timeout_ms)?;
+    let options = Arc::new(P::options(&body)?);
     let files = request.files().unwrap_or_default();
     tracing::Span::current().record("num_files", files.len());
     tracing::Span::current().record("timeout_ms", timeout_ms);
-    if files.is_empty() {
-        return P::build_response(vec![]);
-    }
 
     // This is the heart of the app: efficiently enqueuing concurrent highlighting requests,
     // propagating cancellation signals, and returning them in a stream, without
@@ -94,19 +167,25 @@ pub async fn generic_handler<P: Processor>(
             let filename: Arc<str> = file.filename().unwrap_or_default().into();
             let body = body.clone(); // not a full memory copy, Bytes has zero-cost clone()
             let timeout_flag = timeout_flag.clone();
-            let include_injections = file.include_injections();
+            let options = options.clone();
+            let file_options = FileOptions {
+                include_injections: file.include_injections(),
+                start_line: file.start_line(),
+                end_line: file.end_line(),
+            };
+            // Slicing out the contents up front leaves the task with nothing borrowed from the request.
+            let mut language_ptr: Option<languages::SharedConfig> = None;
+            let contents = prepare_file_contents(&file, body, filename.clone(), &mut language_ptr, P::ALLOWS_EMPTY_FILES);
 
             async move {
-                let mut language_ptr: Option<languages::SharedConfig> = None;
-                let contents =
-                    match prepare_file_contents(&file, body, filename.clone(), &mut language_ptr) {
-                        Ok(ok) => ok,
-                        Err(reason) => {
-                            return crate::processors::Outcome::failure(ident, filename, language_ptr, reason);
-                        }
-                    };
+                let contents = match contents {
+                    Ok(ok) => ok,
+                    Err(reason) => {
+                        return Outcome::failure(ident, filename, language_ptr, reason);
+                    }
+                };
                 let Some(language) = language_ptr else {
-                    return crate::processors::Outcome::failure(ident, filename, None, NonFatalError::InvalidLanguage);
+                    return Outcome::failure(ident, filename, None, NonFatalError::InvalidLanguage);
                 };
 
                 // Clones are needed for error handling paths (but are cheap, because these are Arcs).
@@ -122,15 +201,16 @@ pub async fn generic_handler<P: Processor>(
                         filename,
                         language,
                         contents,
-                        include_injections,
+                        &file_options,
                         cancellation_flag,
+                        &options,
                     )
                 })
                 .map(move |t| {
                     // Thread-join errors are unlikely but possible
                     t.map_err(NonFatalError::from).unwrap_or_else(|reason| {
                         tracing::warn!("Join error encountered, this is upsetting: {reason}");
-                        crate::processors::Outcome::failure(ident, filename_for_join_error, Some(language), reason)
+                        Outcome::failure(ident, filename_for_join_error, Some(language), reason)
                     })
                 });
 
@@ -141,13 +221,189 @@ pub async fn generic_handler<P: Processor>(
                         // Timeout occurred - set the cancellation flag so inflight tree-sitter-side tasks
                         // know that they should cancel and return.
                         cancellation_flag_for_timeout.store(1, Ordering::SeqCst);
-                        crate::processors::Outcome::failure(ident, filename_for_timeout, language_ptr, NonFatalError::TimedOut)
+                        Outcome::failure(ident, filename_for_timeout, language_ptr, NonFatalError::TimedOut)
                     })
             }
         })
         .collect::<FuturesUnordered<_>>();
-    // Wait on all in-flight tasks simultaneously with .collect() and build a response.
-    P::build_response(tasks.collect().await)
+    Ok(tasks)
+}
+
+/// Run blocking work on a worker thread, raising its cancellation flag if it takes too long.
+async fn run_blocking<T, F>(timeout: Duration, func: F) -> Result<T, NonFatalError>
+where
+    T: Send + 'static,
+    F: FnOnce(&AtomicUsize) -> T + Send + 'static,
+{
+    let cancellation_flag: Arc<AtomicUsize> = Arc::default();
+    let task = tokio::task::spawn_blocking({
+        let cancellation_flag = cancellation_flag.clone();
+        move || func(&cancellation_flag)
+    });
+    match tokio::time::timeout(timeout, task).await {
+        Ok(result) => result.map_err(NonFatalError::from),
+        Err(_elapsed) => {
+            cancellation_flag.store(1, Ordering::SeqCst);
+            Err(NonFatalError::TimedOut)
+        }
+    }
+}
+
+/// Build a session response in the negotiated encoding.
+fn respond_session(encoding: Encoding, update: SessionUpdate) -> Result<axum::response::Response, FatalError> {
+    match encoding {
+        Encoding::Flatbuffers => Ok((StatusCode::OK, Bytes::from(update.to_flatbuffers())).into_response()),
+        encoding => encoding.encode(&wire::session::Response::from(update)),
+    }
+}
+
+/// Open an incremental highlighting session on a document (`POST /v1/sessions`), returning its
+/// ID and the highlights of every line.
+#[instrument(err, skip(state, headers, body), fields(request_size = body.len()))]
+pub async fn open_session(
+    extract::State(state): extract::State<Server>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<axum::response::Response, FatalError> {
+    let (body, response_encoding) = decode_request::<wire::session::OpenRequest>(&headers, body)?;
+    let request = flatbuffers::root::<session::OpenRequest>(&body)?;
+    let timeout = per_file_timeout(&state, request.timeout_ms())?;
+    let failed = |language: Option<languages::SharedConfig>, reason: NonFatalError| SessionUpdate {
+        session_id: String::new(),
+        language: language.map(|l| l.fb_language).unwrap_or_default(),
+        changes: vec![],
+        line_count: 0,
+        error_code: reason.into(),
+    };
+
+    let file = request.file();
+    let filename = file.and_then(|f| f.filename()).unwrap_or_default();
+    let Some(language) = file.and_then(|file| file_language(&file, filename)) else {
+        return respond_session(response_encoding, failed(None, NonFatalError::InvalidLanguage));
+    };
+    // Unlike a batch, an editor's document may well start out empty.
+    let contents = file.and_then(|f| f.contents()).map(|c| c.bytes()).unwrap_or_default();
+    if contents.len() > MAX_FILE_SIZE {
+        return respond_session(response_encoding, failed(Some(language), NonFatalError::FileTooLarge));
+    }
+    let include_injections = file.is_some_and(|f| f.include_injections());
+    let text = contents.to_vec();
+
+    let result = run_blocking(timeout, move |cancellation_flag| {
+        Session::open(language, include_injections, text, cancellation_flag)
+    })
+    .await
+    .and_then(|opened| opened.map_err(NonFatalError::from));
+    let update = match result {
+        Ok((session, change)) => {
+            let line_count = session.line_count();
+            match sessions::sessions().insert(session) {
+                Some(session_id) => SessionUpdate {
+                    session_id,
+                    language: language.fb_language,
+                    changes: vec![change],
+                    line_count,
+                    error_code: common::ErrorCode::NoError,
+                },
+                None => failed(Some(language), NonFatalError::FileTooLarge),
+            }
+        }
+        Err(reason) => failed(Some(language), reason),
+    };
+    respond_session(response_encoding, update)
+}
+
+/// Edit a session's document (`POST /v1/sessions/{id}/edits`), returning the highlights of the
+/// lines that changed.
+#[instrument(err, skip(state, headers, body), fields(request_size = body.len()))]
+pub async fn edit_session(
+    extract::State(state): extract::State<Server>,
+    extract::Path(id): extract::Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<axum::response::Response, FatalError> {
+    let (body, response_encoding) = decode_request::<wire::session::EditRequest>(&headers, body)?;
+    let request = flatbuffers::root::<session::EditRequest>(&body)?;
+    let timeout = per_file_timeout(&state, request.timeout_ms())?;
+    let (language, session) = sessions::sessions()
+        .get(&id)
+        .ok_or_else(|| FatalError::UnknownSession(id.clone()))?;
+    let max_size = sessions::sessions().limits().max_memory;
+    let edits: Vec<Edit> = request
+        .edits()
+        .unwrap_or_default()
+        .iter()
+        .map(|edit| Edit {
+            start: edit.start() as usize,
+            old_end: edit.old_end() as usize,
+            text: edit.text().unwrap_or_default().as_bytes().to_vec(),
+        })
+        .collect();
+
+    let result = run_blocking(timeout, move |cancellation_flag| {
+        let mut session = session.lock().unwrap_or_else(|e| e.into_inner());
+        let change = session.edit(&edits, MAX_FILE_SIZE, max_size, cancellation_flag);
+        (change, session.line_count(), session.size())
+    })
+    .await;
+    let mut update = SessionUpdate {
+        session_id: id.clone(),
+        language: language.fb_language,
+        changes: vec![],
+        line_count: 0,
+        error_code: common::ErrorCode::NoError,
+    };
+    match result {
+        Ok((Ok(change), line_count, size)) => {
+            sessions::sessions().resize(&id, size);
+            update.changes.extend(change);
+            update.line_count = line_count;
+        }
+        Ok((Err(EditError::Invalid(reason)), ..)) => Err(FatalError::InvalidEdit(reason))?,
+        Ok((Err(EditError::TooLarge), ..)) => update.error_code = NonFatalError::FileTooLarge.into(),
+        Ok((Err(EditError::Failed(e)), ..)) => update.error_code = NonFatalError::from(e).into(),
+        Err(reason) => update.error_code = reason.into(),
+    }
+    respond_session(response_encoding, update)
+}
+
+/// Close a session (`DELETE /v1/sessions/{id}`).
+#[instrument]
+pub async fn close_session(extract::Path(id): extract::Path<String>) -> StatusCode {
+    if sessions::sessions().remove(&id) {
+        StatusCode::NO_CONTENT
+    } else {
+        StatusCode::NOT_FOUND
+    }
+}
+
+/// How the classes styled by a stylesheet are named, like the `/v1/html` request fields.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct StylesheetQuery {
+    pub class_style: ClassStyle,
+    pub class_prefix: Option<String>,
+}
+
+/// Serve a registered theme as a stylesheet (`GET /v1/themes/dusk.css?class_style=Split`), or as
+/// the preamble for LaTeX (`.tex`) or Typst (`.typ`) output.
+#[instrument]
+pub async fn theme_file(
+    extract::Path(file): extract::Path<String>,
+    extract::Query(query): extract::Query<StylesheetQuery>,
+) -> axum::response::Response {
+    let (name, extension) = file.rsplit_once('.').unwrap_or((&file, ""));
+    let classes = match ClassNames::for_request(query.class_style, query.class_prefix.as_deref()) {
+        Ok(classes) => classes,
+        Err(reason) => return (StatusCode::BAD_REQUEST, reason).into_response(),
+    };
+    let (content_type, body) = match (themes::from_name(name), extension) {
+        (Some(theme), "css") => ("text/css; charset=utf-8", theme.stylesheet(&classes)),
+        (Some(theme), "tex") => ("application/x-tex; charset=utf-8", latex_preamble(theme)),
+        (Some(theme), "typ") => ("text/plain; charset=utf-8", typst_preamble(theme)),
+        _ => return (StatusCode::NOT_FOUND, format!("Unknown theme file: {file}")).into_response(),
+    };
+    ([(header::CONTENT_TYPE, content_type)], body).into_response()
 }
 
 async fn shutdown_signal() {
@@ -178,11 +434,17 @@ pub fn router(default_per_file_timeout: Duration, max_per_file_timeout: Duration
     };
     // use axum_tracing_opentelemetry::middleware;
     use tower_http::*;
+    use tower_http::compression::Predicate as _;
 
     let counter = metrics::in_flight_requests::InFlightRequestsCounter::new();
     let layer = tower::ServiceBuilder::new()
         .layer(catch_panic::CatchPanicLayer::new())
-        .layer(compression::CompressionLayer::new()) // Request ID must come before tracing to be available in spans
+        .layer(compression::CompressionLayer::new().compress_when(
+            // Compressing a stream would hold its documents back until the compressor's buffer fills.
+            compression::DefaultPredicate::new()
+                .and(compression::predicate::NotForContentType::const_new(Streaming::Flatbuffers.media_type()))
+                .and(compression::predicate::NotForContentType::const_new(Streaming::Ndjson.media_type())),
+        )) // Request ID must come before tracing to be available in spans
         .layer(decompression::DecompressionLayer::new())
         .layer(request_id::SetRequestIdLayer::x_request_id(
             request_id::MakeRequestUuid,
@@ -212,6 +474,26 @@ pub fn router(default_per_file_timeout: Duration, max_per_file_timeout: Duration
     Router::new()
         .route("/v1/html", post(generic_handler::<HtmlProcessor>))
         .route("/v1/spans", post(generic_handler::<SpansProcessor>))
+        .route("/v1/ansi", post(generic_handler::<AnsiProcessor>))
+        .route("/v1/semantic-tokens", post(generic_handler::<SemanticTokensProcessor>))
+        .route("/v1/latex", post(generic_handler::<LatexProcessor>))
+        .route("/v1/typst", post(generic_handler::<TypstProcessor>))
+        .route("/v1/image", post(generic_handler::<ImageProcessor>))
+        .route("/v1/rtf", post(generic_handler::<RtfProcessor>))
+        .route("/v1/markdown", post(generic_handler::<MarkdownProcessor>))
+        .route("/v1/diff", post(generic_handler::<DiffProcessor>))
+        .route("/v1/patch", post(generic_handler::<PatchProcessor>))
+        .route("/v1/tags", post(generic_handler::<TagsProcessor>))
+        .route("/v1/folds", post(generic_handler::<FoldsProcessor>))
+        .route("/v1/diagnostics", post(generic_handler::<DiagnosticsProcessor>))
+        .route("/v1/references", post(generic_handler::<ReferencesProcessor>))
+        .route("/v1/tree", post(generic_handler::<TreeProcessor>))
+        .route("/v1/query", post(generic_handler::<QueryProcessor>))
+        .route("/v1/sessions", post(open_session))
+        .route("/v1/sessions/:id/edits", post(edit_session))
+        .route("/v1/sessions/:id", delete(close_session))
+        .route("/v1/ws", get(websocket::handler))
+        .route("/v1/themes/:file", get(theme_file))
         .route("/health", get("ok"))
         .layer(layer)
         .with_state(state)
@@ -1,10 +1,19 @@
+use crate::daylight_generated::daylight::ansi;
 use crate::daylight_generated::daylight::common;
 use crate::daylight_generated::daylight::html;
-use crate::processors::HtmlProcessor;
+use crate::daylight_generated::daylight::image;
+use crate::daylight_generated::daylight::semantic_tokens;
+use crate::daylight_generated::daylight::spans;
+use crate::languages::ALL_HIGHLIGHT_NAMES;
+use crate::errors::FatalError;
+use crate::processors::{
+    AnsiProcessor, DiagnosticsProcessor, DiffProcessor, FoldsProcessor, HtmlProcessor, ImageProcessor, LatexProcessor, MarkdownProcessor, PatchProcessor, QueryProcessor, ReferencesProcessor, RtfProcessor, SemanticTokensProcessor, SpansProcessor, TagsProcessor, TreeProcessor, TypstProcessor,
+};
 use crate::server::*;
+use crate::themes::{self, Color};
 use axum::body::Bytes;
 use axum::extract::State;
-use http::StatusCode;
+use http::{HeaderMap, StatusCode};
 use quickcheck::TestResult;
 use quickcheck_macros::quickcheck;
 use tokio::time::Duration;
@@ -14,7 +23,15 @@ async fn html_handler(
     state: State<Server>,
     body: Bytes,
 ) -> Result<axum::response::Response, crate::errors::FatalError> {
-    generic_handler::<HtmlProcessor>(state, body).await
+    generic_handler::<HtmlProcessor>(state, HeaderMap::new(), body).await
+}
+
+// Helper to create a server with the default timeouts
+fn server() -> Server {
+    Server {
+        default_per_file_timeout: Duration::from_secs(30),
+        max_per_file_timeout: Duration::from_secs(60),
+    }
 }
 
 // Helper to create a FlatBuffers request with given files
@@ -35,6 +52,7 @@ fn build_request(files: Vec<(u16, &str, &str, common::Language)>) -> Vec<u8> {
                     include_injections: false,
                     language: *lang,
                     options: None,
+                    ..Default::default()
                 },
             )
         })
@@ -46,6 +64,7 @@ fn build_request(files: Vec<(u16, &str, &str, common::Language)>) -> Vec<u8> {
         &html::RequestArgs {
             files: Some(files_vec),
             timeout_ms: 0,
+            ..Default::default()
         },
     );
 
@@ -55,10 +74,7 @@ fn build_request(files: Vec<(u16, &str, &str, common::Language)>) -> Vec<u8> {
 
 #[tokio::test]
 async fn test_empty_request() {
-    let state = Server {
-        default_per_file_timeout: Duration::from_secs(30),
-        max_per_file_timeout: Duration::from_secs(60),
-    };
+    let state = server();
 
     let request_bytes = build_request(vec![]);
     let response = html_handler(State(state), Bytes::from(request_bytes))
@@ -70,10 +86,7 @@ async fn test_empty_request() {
 
 #[tokio::test]
 async fn test_single_c_file() {
-    let state = Server {
-        default_per_file_timeout: Duration::from_secs(30),
-        max_per_file_timeout: Duration::from_secs(60),
-    };
+    let state = server();
 
     let c_code = r#"
 #include <stdio.h>
@@ -104,15 +117,12 @@ int main() {
     assert_eq!(doc.ident(), 0);
     assert_eq!(doc.error_code(), common::ErrorCode::NoError);
     assert!(doc.lines().is_some());
-    assert!(doc.lines().unwrap().len() > 0);
+    assert!(!doc.lines().unwrap().is_empty());
 }
 
 #[tokio::test]
 async fn test_empty_file_contents() {
-    let state = Server {
-        default_per_file_timeout: Duration::from_secs(30),
-        max_per_file_timeout: Duration::from_secs(60),
-    };
+    let state = server();
 
     let request_bytes = build_request(vec![(0, "empty.c", "", common::Language::C)]);
     let response = html_handler(State(state), Bytes::from(request_bytes))
@@ -134,10 +144,7 @@ async fn test_empty_file_contents() {
 
 #[tokio::test]
 async fn test_multiple_files_concurrently() {
-    let state = Server {
-        default_per_file_timeout: Duration::from_secs(30),
-        max_per_file_timeout: Duration::from_secs(60),
-    };
+    let state = server();
 
     let files = vec![
         (0, "test1.c", "int main() { return 0; }", common::Language::C),
@@ -173,10 +180,7 @@ async fn test_multiple_files_concurrently() {
 
 #[tokio::test]
 async fn test_timeout_too_large() {
-    let state = Server {
-        default_per_file_timeout: Duration::from_secs(30),
-        max_per_file_timeout: Duration::from_secs(60),
-    };
+    let state = server();
 
     let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(1024);
     let files_vec = builder.create_vector::<flatbuffers::WIPOffset<common::File>>(&[]);
@@ -185,6 +189,7 @@ async fn test_timeout_too_large() {
         &html::RequestArgs {
             files: Some(files_vec),
             timeout_ms: 120_000, // 120 seconds, exceeds max of 60
+            ..Default::default()
         },
     );
     builder.finish(request, None);
@@ -269,3 +274,2080 @@ fn prop_idents_preserved(idents: Vec<u16>) -> TestResult {
         TestResult::from_bool(returned_idents == expected_idents)
     })
 }
+
+// Helper to create an ANSI request for a single file
+fn build_ansi_request(contents: &str, theme: Option<&str>, color_mode: ansi::ColorMode) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(4096);
+    let filename = builder.create_string("test.c");
+    let contents = builder.create_vector(contents.as_bytes());
+    let file = common::File::create(
+        &mut builder,
+        &common::FileArgs {
+            ident: 0,
+            filename: Some(filename),
+            contents: Some(contents),
+            language: common::Language::C,
+            ..Default::default()
+        },
+    );
+    let files = builder.create_vector(&[file]);
+    let theme = theme.map(|t| builder.create_string(t));
+    let request = ansi::Request::create(
+        &mut builder,
+        &ansi::RequestArgs {
+            files: Some(files),
+            timeout_ms: 0,
+            theme,
+            color_mode,
+        },
+    );
+    builder.finish(request, None);
+    builder.finished_data().to_vec()
+}
+
+async fn ansi_lines(request_bytes: Vec<u8>) -> Vec<String> {
+    let state = server();
+    let response = generic_handler::<AnsiProcessor>(State(state), HeaderMap::new(), Bytes::from(request_bytes))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let fb_response = flatbuffers::root::<ansi::Response>(&body).unwrap();
+    let doc = fb_response.documents().unwrap().get(0);
+    assert_eq!(doc.error_code(), common::ErrorCode::NoError);
+    doc.lines().unwrap().iter().map(String::from).collect()
+}
+
+#[tokio::test]
+async fn test_ansi_truecolor() {
+    let code = "int main() {\n    return 0;\n}\n";
+    let lines = ansi_lines(build_ansi_request(code, None, ansi::ColorMode::TrueColor)).await;
+
+    assert_eq!(lines.len(), 3);
+    // `return` is a keyword, which the default theme colors.
+    assert!(lines[1].contains("\x1b[38;2;"));
+    assert!(lines.iter().all(|l| l.ends_with('\n')));
+}
+
+#[tokio::test]
+async fn test_ansi_downsampled_colors() {
+    let code = "int main() { return 0; }";
+
+    let lines = ansi_lines(build_ansi_request(code, Some("dusk"), ansi::ColorMode::Ansi256)).await;
+    assert!(lines[0].contains("\x1b[38;5;"));
+    assert!(!lines[0].contains("38;2;"));
+
+    let lines = ansi_lines(build_ansi_request(code, Some("dusk"), ansi::ColorMode::Ansi16)).await;
+    assert!(!lines[0].contains("38;5;"));
+    assert!(!lines[0].contains("38;2;"));
+    assert!(lines[0].contains("\x1b[9") || lines[0].contains("\x1b[3"));
+}
+
+#[tokio::test]
+async fn test_ansi_unknown_theme() {
+    let state = server();
+    let request_bytes = build_ansi_request("int x;", Some("no-such-theme"), ansi::ColorMode::TrueColor);
+    let response = generic_handler::<AnsiProcessor>(State(state), HeaderMap::new(), Bytes::from(request_bytes)).await;
+
+    assert!(response.is_err());
+}
+
+const HELIX_THEME: &str = r##"
+"ui.background" = { bg = "base" }
+"ui.text" = "text"
+"keyword" = { fg = "red", modifiers = ["bold"] }
+"function" = "#00ff00"
+"constant.numeric" = "#0000ff"
+
+[palette]
+base = "#101010"
+text = "#eeeeee"
+red = "#ff0000"
+"##;
+
+const VSCODE_THEME: &str = r##"{
+  // VS Code tolerates comments and trailing commas.
+  "colors": { "editor.background": "#202020", "editor.foreground": "#dddddd", },
+  "tokenColors": [
+    { "scope": "keyword", "settings": { "foreground": "#aa0000" } },
+    { "scope": ["entity.name.function", "support.function"], "settings": { "foreground": "#00aa00", "fontStyle": "italic" } },
+    { "scope": "string, string.quoted", "settings": { "foreground": "#0000aaff" } },
+  ],
+}"##;
+
+const TEXTMATE_THEME: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+  <key>name</key><string>Test</string>
+  <key>settings</key>
+  <array>
+    <dict>
+      <key>settings</key>
+      <dict>
+        <key>background</key><string>#303030</string>
+        <key>foreground</key><string>#cccccc</string>
+      </dict>
+    </dict>
+    <dict>
+      <key>scope</key><string>comment</string>
+      <key>settings</key>
+      <dict>
+        <key>foreground</key><string>#777777</string>
+        <key>fontStyle</key><string>italic underline</string>
+      </dict>
+    </dict>
+  </array>
+</dict>
+</plist>"##;
+
+fn index_of(name: &str) -> usize {
+    ALL_HIGHLIGHT_NAMES.iter().position(|n| *n == name).unwrap()
+}
+
+#[test]
+fn test_load_theme_directory() {
+    let dir = std::env::temp_dir().join(format!("daylight-themes-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("test-helix.toml"), HELIX_THEME).unwrap();
+    std::fs::write(dir.join("test-vscode.json"), VSCODE_THEME).unwrap();
+    std::fs::write(dir.join("test-textmate.tmTheme"), TEXTMATE_THEME).unwrap();
+    std::fs::write(dir.join("README.md"), "not a theme").unwrap();
+
+    let loaded = themes::load_directory(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(loaded.len(), 3);
+
+    let helix = themes::from_name("test-helix").unwrap();
+    assert_eq!(helix.background, Color::parse("#101010"));
+    assert_eq!(helix.foreground, Color::parse("#eeeeee"));
+    assert_eq!(helix.style(index_of("keyword")).foreground, Color::parse("#ff0000"));
+    assert!(helix.style(index_of("keyword")).bold);
+    assert_eq!(helix.style(index_of("number")).foreground, Color::parse("#0000ff"));
+    // Dotted fallback: `function.method` inherits from `function`.
+    assert_eq!(helix.style(index_of("function.method")).foreground, Color::parse("#00ff00"));
+
+    let vscode = themes::from_name("test-vscode").unwrap();
+    assert_eq!(vscode.background, Color::parse("#202020"));
+    assert_eq!(vscode.style(index_of("keyword")).foreground, Color::parse("#aa0000"));
+    assert!(vscode.style(index_of("function.builtin")).italic);
+    assert_eq!(vscode.style(index_of("string.special")).foreground, Color::parse("#0000aa"));
+
+    let textmate = themes::from_name("test-textmate").unwrap();
+    assert_eq!(textmate.foreground, Color::parse("#cccccc"));
+    let comment = textmate.style(index_of("comment.documentation"));
+    assert_eq!(comment.foreground, Color::parse("#777777"));
+    assert!(comment.italic && comment.underline);
+}
+
+#[tokio::test]
+async fn test_theme_files() {
+    let response = theme_file(axum::extract::Path("daylight.css".to_string()), Default::default()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let css = std::str::from_utf8(&body).unwrap();
+    assert!(css.contains(".daylight {"));
+    assert!(css.contains(".function\\.method {"));
+
+    let response = theme_file(axum::extract::Path("no-such-theme.css".to_string()), Default::default()).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = theme_file(axum::extract::Path("daylight".to_string()), Default::default()).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_inline_styled_html() {
+    let state = server();
+
+    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(4096);
+    let filename = builder.create_string("test.c");
+    let contents = builder.create_vector(b"int main() {\n    return 0;\n}\n");
+    let file = common::File::create(
+        &mut builder,
+        &common::FileArgs {
+            ident: 0,
+            filename: Some(filename),
+            contents: Some(contents),
+            language: common::Language::C,
+            ..Default::default()
+        },
+    );
+    let files = builder.create_vector(&[file]);
+    let theme = builder.create_string("dusk");
+    let request = html::Request::create(
+        &mut builder,
+        &html::RequestArgs {
+            files: Some(files),
+            theme: Some(theme),
+            inline_styles: true,
+            wrap_in_pre: true,
+            ..Default::default()
+        },
+    );
+    builder.finish(request, None);
+    let request_bytes = builder.finished_data().to_vec();
+
+    let response = html_handler(State(state), Bytes::from(request_bytes))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let fb_response = flatbuffers::root::<html::Response>(&body).unwrap();
+    let doc = fb_response.documents().unwrap().get(0);
+    let lines: Vec<&str> = doc.lines().unwrap().iter().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("<pre style=\"color:#c9d1d9;background-color:#0d1117\">"));
+    assert!(lines[2].ends_with("</pre>"));
+    assert!(lines[1].contains("style=\"color:#"));
+    assert!(lines.iter().all(|l| !l.contains("class=")));
+}
+
+fn headers(pairs: &[(http::HeaderName, &'static str)]) -> HeaderMap {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.clone(), http::HeaderValue::from_static(value)))
+        .collect()
+}
+
+/// Send a JSON request to an endpoint, returning its JSON response.
+async fn json_response<P: crate::processors::Processor>(request: serde_json::Value) -> serde_json::Value {
+    let state = server();
+    let response = generic_handler::<P>(
+        State(state),
+        headers(&[(http::header::CONTENT_TYPE, "application/json")]),
+        Bytes::from(request.to_string()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+/// Send a JSON request to an endpoint, returning the first document of its response.
+async fn json_document<P: crate::processors::Processor>(request: serde_json::Value) -> serde_json::Value {
+    json_response::<P>(request).await["documents"][0].clone()
+}
+
+#[tokio::test]
+async fn test_json_html_request() {
+    let state = server();
+    let request = r#"{"files": [
+        {"ident": 7, "filename": "test.c", "contents": "int main() {}", "language": "C"},
+        {"ident": 8, "filename": "mystery", "contents": "???"}
+    ]}"#;
+
+    let response = generic_handler::<HtmlProcessor>(
+        State(state),
+        headers(&[(http::header::CONTENT_TYPE, "application/json")]),
+        Bytes::from(request),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.headers()[http::header::CONTENT_TYPE], "application/json");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let mut documents = json["documents"].as_array().unwrap().clone();
+    documents.sort_by_key(|d| d["ident"].as_u64());
+
+    assert_eq!(documents[0]["language"], "C");
+    assert_eq!(documents[0]["error_code"], "NoError");
+    assert!(documents[0]["lines"][0].as_str().unwrap().contains("class=\"type\""));
+    assert_eq!(documents[1]["language"], "Unspecified");
+    assert_eq!(documents[1]["error_code"], "UnknownLanguage");
+    assert_eq!(documents[1]["lines"], serde_json::json!([]));
+}
+
+async fn html_lines(request: serde_json::Value) -> String {
+    let document = json_document::<HtmlProcessor>(request).await;
+    let lines: Vec<&str> = document["lines"].as_array().unwrap().iter().filter_map(|l| l.as_str()).collect();
+    lines.concat()
+}
+
+#[tokio::test]
+async fn test_class_styles() {
+    let file = serde_json::json!({"filename": "test.rs", "contents": "// hi\nfn main() { let s = \"x\"; s.len(); }\n"});
+    let html = |style: &str, prefix: Option<&str>| {
+        html_lines(serde_json::json!({"files": [file], "class_style": style, "class_prefix": prefix}))
+    };
+
+    let capture = html("ServerDefault", None).await;
+    assert!(capture.contains("<span class=\"function.method\">len</span>"));
+    let split = html("Split", Some("dl-")).await;
+    assert!(split.contains("<span class=\"dl-function dl-method\">len</span>"));
+    assert!(split.contains("<span class=\"dl-keyword\">fn</span>"));
+    let abbreviated = html("Abbreviated", None).await;
+    assert!(abbreviated.contains("<span class=\"fn-m\">len</span>"));
+    assert!(abbreviated.contains("<span class=\"kw\">let</span>"));
+
+    let pygments = html("Pygments", None).await;
+    assert!(pygments.contains("<span class=\"k\">fn</span>"));
+    assert!(pygments.contains("<span class=\"s2\">&quot;x&quot;</span>"));
+    assert!(pygments.contains("<span class=\"c1\">// hi</span>"));
+    let hljs = html("HighlightJs", None).await;
+    assert!(hljs.contains("<span class=\"hljs-keyword\">fn</span>"));
+    assert!(hljs.contains("<span class=\"hljs-title function_\">main</span>"));
+    let prism = html("Prism", None).await;
+    assert!(prism.contains("<span class=\"token keyword\">fn</span>"));
+    assert!(prism.contains("<span class=\"token function\">len</span>"));
+    // Captures a scheme doesn't map get a bare element, rather than an empty class or a bare
+    // `token`.
+    let interpolated = serde_json::json!({"filename": "test.py", "contents": "f\"{x}\"\n"});
+    for style in ["Pygments", "HighlightJs", "Prism"] {
+        let html = html_lines(serde_json::json!({"files": [interpolated], "class_style": style})).await;
+        assert!(!html.contains("class=\"\"") && !html.contains("class=\"token\""), "{style}: {html}");
+        if style == "Prism" {
+            assert!(html.contains("<span><span class=\"token punctuation\">{</span>"));
+        }
+    }
+
+    let query = crate::server::StylesheetQuery {
+        class_style: html::ClassStyle::Split,
+        class_prefix: Some("dl-".to_string()),
+    };
+    let response = theme_file(axum::extract::Path("daylight.css".to_string()), axum::extract::Query(query)).await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let css = std::str::from_utf8(&body).unwrap();
+    assert!(css.contains(".dl-keyword {"));
+    assert!(css.contains(".dl-function.dl-method {"));
+
+    // Nor do they get a stylesheet rule, which would have been one for every `.token`.
+    let prism = crate::classes::ClassNames {
+        style: html::ClassStyle::Prism,
+        prefix: String::new(),
+    };
+    let embedded = crate::languages::ALL_HIGHLIGHT_NAMES.iter().position(|&name| name == "embedded").unwrap();
+    assert_eq!(prism.selector(embedded), "");
+}
+
+#[tokio::test]
+async fn test_invalid_class_prefix() {
+    let file = serde_json::json!({"filename": "test.rs", "contents": "fn main() {}"});
+    for prefix in ["x\" onmouseover=\"alert(1)", "x{}", "1x", "dl."] {
+        let state = server();
+        let response = generic_handler::<HtmlProcessor>(
+            State(state),
+            headers(&[(http::header::CONTENT_TYPE, "application/json")]),
+            Bytes::from(serde_json::json!({"files": [file], "class_prefix": prefix}).to_string()),
+        )
+        .await;
+        assert!(matches!(response, Err(FatalError::InvalidRequest(..))), "accepted {prefix:?}");
+    }
+    assert_eq!(crate::classes::parse_prefix("dl_-9").unwrap(), "dl_-9");
+    assert_eq!(crate::classes::parse_prefix("").unwrap(), "");
+
+    let query = crate::server::StylesheetQuery {
+        class_style: html::ClassStyle::Split,
+        class_prefix: Some("x{}".to_string()),
+    };
+    let response = theme_file(axum::extract::Path("daylight.css".to_string()), axum::extract::Query(query)).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_cbor_and_msgpack_spans() {
+    #[derive(serde::Serialize)]
+    struct File {
+        ident: u16,
+        filename: &'static str,
+        #[serde(with = "serde_bytes")]
+        contents: &'static [u8],
+    }
+    #[derive(serde::Serialize)]
+    struct Request {
+        files: Vec<File>,
+    }
+    #[derive(serde::Deserialize)]
+    struct Response {
+        highlight_names: Vec<String>,
+        documents: Vec<Document>,
+    }
+    #[derive(serde::Deserialize)]
+    struct Document {
+        language: String,
+        spans: Vec<Span>,
+    }
+    #[derive(serde::Deserialize)]
+    struct Span {
+        index: usize,
+        start: usize,
+        end: usize,
+    }
+
+    let request = Request {
+        files: vec![File {
+            ident: 0,
+            filename: "test.rs",
+            contents: b"fn main() {}",
+        }],
+    };
+    let mut cbor = vec![];
+    ciborium::into_writer(&request, &mut cbor).unwrap();
+    let msgpack = rmp_serde::to_vec_named(&request).unwrap();
+
+    for (content_type, body) in [("application/cbor", cbor), ("application/msgpack", msgpack)] {
+        let state = server();
+        let response = generic_handler::<SpansProcessor>(
+            State(state),
+            headers(&[(http::header::CONTENT_TYPE, content_type)]),
+            Bytes::from(body),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.headers()[http::header::CONTENT_TYPE], content_type);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let response: Response = if content_type == "application/cbor" {
+            ciborium::from_reader(&body[..]).unwrap()
+        } else {
+            rmp_serde::from_slice(&body).unwrap()
+        };
+
+        assert_eq!(response.highlight_names, ALL_HIGHLIGHT_NAMES);
+        let document = &response.documents[0];
+        assert_eq!(document.language, "Rust");
+        let first = &document.spans[0];
+        assert_eq!(response.highlight_names[first.index], "keyword");
+        assert_eq!((first.start, first.end), (0, 2));
+    }
+}
+
+#[tokio::test]
+async fn test_accept_header_selects_response_encoding() {
+    let state = server();
+    let request_bytes = build_request(vec![(0, "test.c", "int x;", common::Language::C)]);
+
+    let response = generic_handler::<HtmlProcessor>(
+        State(state),
+        headers(&[(http::header::ACCEPT, "application/cbor;q=0.5, application/json")]),
+        Bytes::from(request_bytes),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.headers()[http::header::CONTENT_TYPE], "application/json");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["documents"][0]["filename"], "test.c");
+}
+
+#[tokio::test]
+async fn test_unsupported_content_type() {
+    let state = server();
+    let response = generic_handler::<HtmlProcessor>(
+        State(state),
+        headers(&[(http::header::CONTENT_TYPE, "text/yaml")]),
+        Bytes::from("files: []"),
+    )
+    .await;
+    assert!(matches!(response, Err(FatalError::UnsupportedMediaType(_))));
+}
+
+/// Split the top-level fields out of a table's `Debug` output, like `Request { a: 1, b: [2, 3] }`.
+fn debug_fields(debug: &str) -> Vec<(String, String)> {
+    let body = &debug[debug.find('{').unwrap() + 1..debug.rfind('}').unwrap()];
+    let (mut fields, mut field) = (vec![], String::new());
+    let (mut depth, mut in_string, mut escaped) = (0, false, false);
+    for c in body.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            ',' if !in_string && depth == 0 => {
+                fields.push(std::mem::take(&mut field));
+                continue;
+            }
+            _ => {}
+        }
+        field.push(c);
+    }
+    fields.push(field);
+    fields
+        .iter()
+        .filter_map(|field| field.trim().split_once(": "))
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Re-encode `request` as Flatbuffers through its serde mirror, and check that every field in
+/// the schema came through with something other than its default. A field added to the schema
+/// but not to the mirror, or dropped by `to_flatbuffers`, fails here.
+fn assert_round_trips<R: crate::wire::Request>(request: serde_json::Value, decode: impl Fn(&[u8]) -> String) {
+    let full = decode(&serde_json::from_value::<R>(request).unwrap().to_flatbuffers());
+    let empty = decode(&serde_json::from_value::<R>(serde_json::json!({})).unwrap().to_flatbuffers());
+    let defaults: std::collections::HashMap<_, _> = debug_fields(&empty).into_iter().collect();
+    for (field, value) in debug_fields(&full) {
+        assert_ne!(defaults[&field], value, "`{field}` did not survive the round trip: {full}");
+    }
+}
+
+macro_rules! assert_round_trips {
+    ($mirror:ty, $table:ty, $request:expr) => {
+        assert_round_trips::<$mirror>($request, |bytes| format!("{:?}", flatbuffers::root::<$table>(bytes).unwrap()))
+    };
+}
+
+#[test]
+fn test_wire_requests_round_trip() {
+    use crate::daylight_generated::daylight as fb;
+    use crate::wire;
+    use serde_json::json;
+
+    let file = json!({
+        "ident": 7, "filename": "main.rs", "contents": "fn main() {}", "language": "Rust",
+        "include_injections": true, "options": ["x"], "start_line": 2, "end_line": 3,
+    });
+    let files = json!([file]);
+
+    // `File` is shared by every request, so check its own fields once.
+    let decode_file = |bytes: &[u8]| {
+        let request = flatbuffers::root::<fb::spans::Request>(bytes).unwrap();
+        format!("{:?}", request.files().unwrap().get(0))
+    };
+    let full = decode_file(&wire::Request::to_flatbuffers(
+        &serde_json::from_value::<wire::spans::Request>(json!({"files": [file]})).unwrap(),
+    ));
+    let empty = decode_file(&wire::Request::to_flatbuffers(
+        &serde_json::from_value::<wire::spans::Request>(json!({"files": [{}]})).unwrap(),
+    ));
+    let defaults: std::collections::HashMap<_, _> = debug_fields(&empty).into_iter().collect();
+    for (field, value) in debug_fields(&full) {
+        assert_ne!(defaults[&field], value, "`File.{field}` did not survive the round trip");
+    }
+
+    assert_round_trips!(wire::html::Request, fb::html::Request, json!({
+        "files": files, "timeout_ms": 5, "theme": "dark", "inline_styles": true, "wrap_in_pre": true,
+        "full_document": true, "emphasized_lines": [{"start": 1, "end": 2}], "class_style": "Split",
+        "class_prefix": "hl-", "link_references": true,
+    }));
+    assert_round_trips!(wire::spans::Request, fb::spans::Request, json!({
+        "files": files, "timeout_ms": 5, "offset_encoding": "Utf16", "include_positions": true,
+    }));
+    assert_round_trips!(wire::ansi::Request, fb::ansi::Request, json!({
+        "files": files, "timeout_ms": 5, "theme": "dark", "color_mode": "Ansi16",
+    }));
+    assert_round_trips!(wire::semantic_tokens::Request, fb::semantic_tokens::Request, json!({
+        "files": files, "timeout_ms": 5, "position_encoding": "Utf32",
+    }));
+    assert_round_trips!(wire::latex::Request, fb::latex::Request, json!({
+        "files": files, "timeout_ms": 5, "style": "TextColor",
+    }));
+    assert_round_trips!(wire::typst::Request, fb::typst::Request, json!({"files": files, "timeout_ms": 5}));
+    assert_round_trips!(wire::image::Request, fb::image::Request, json!({
+        "files": files, "timeout_ms": 5, "theme": "dark", "format": "Png", "font_size": 20.0,
+        "padding": 3, "window_chrome": true, "line_numbers": true,
+    }));
+    assert_round_trips!(wire::rtf::Request, fb::rtf::Request, json!({
+        "files": files, "timeout_ms": 5, "theme": "dark",
+    }));
+    assert_round_trips!(wire::markdown::Request, fb::markdown::Request, json!({
+        "files": files, "timeout_ms": 5, "theme": "dark", "inline_styles": true, "render_prose": true,
+        "class_style": "Capture", "class_prefix": "hl-",
+    }));
+    assert_round_trips!(wire::diff::Request, fb::diff::Request, json!({
+        "files": files, "timeout_ms": 5, "old_files": files, "layout": "SideBySide", "markup": "Spans",
+        "word_diff": true, "context_lines": 1,
+    }));
+    assert_round_trips!(wire::patch::Request, fb::patch::Request, json!({"files": files, "timeout_ms": 5}));
+    assert_round_trips!(wire::tags::Request, fb::tags::Request, json!({"files": files, "timeout_ms": 5}));
+    assert_round_trips!(wire::folds::Request, fb::folds::Request, json!({"files": files, "timeout_ms": 5}));
+    assert_round_trips!(wire::diagnostics::Request, fb::diagnostics::Request, json!({"files": files, "timeout_ms": 5}));
+    assert_round_trips!(wire::references::Request, fb::references::Request, json!({"files": files, "timeout_ms": 5}));
+    assert_round_trips!(wire::tree::Request, fb::tree::Request, json!({
+        "files": files, "timeout_ms": 5, "format": "SExpression", "max_depth": 2, "byte_start": 1,
+        "byte_end": 4,
+    }));
+    assert_round_trips!(wire::query::Request, fb::query::Request, json!({
+        "files": files, "timeout_ms": 5, "query": "(identifier) @id",
+        "language_queries": [{"language": "Rust", "query": "(identifier) @id"}],
+    }));
+    assert_round_trips!(wire::session::OpenRequest, fb::session::OpenRequest, json!({
+        "file": file, "timeout_ms": 5,
+    }));
+    assert_round_trips!(wire::session::EditRequest, fb::session::EditRequest, json!({
+        "edits": [{"start": 1, "old_end": 2, "text": "x"}], "timeout_ms": 5,
+    }));
+}
+
+async fn semantic_tokens(contents: &str, position_encoding: common::OffsetEncoding) -> Vec<u32> {
+    let state = server();
+    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(4096);
+    let filename = builder.create_string("test.rs");
+    let contents = builder.create_vector(contents.as_bytes());
+    let file = common::File::create(
+        &mut builder,
+        &common::FileArgs {
+            filename: Some(filename),
+            contents: Some(contents),
+            ..Default::default()
+        },
+    );
+    let files = builder.create_vector(&[file]);
+    let request = semantic_tokens::Request::create(
+        &mut builder,
+        &semantic_tokens::RequestArgs {
+            files: Some(files),
+            position_encoding,
+            ..Default::default()
+        },
+    );
+    builder.finish(request, None);
+    let request_bytes = builder.finished_data().to_vec();
+
+    let response = generic_handler::<SemanticTokensProcessor>(State(state), HeaderMap::new(), Bytes::from(request_bytes))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let response = flatbuffers::root::<semantic_tokens::Response>(&body).unwrap();
+    let legend = response.legend().unwrap();
+    assert_eq!(legend.token_types().unwrap().get(17), "comment");
+    assert_eq!(legend.token_modifiers().unwrap().get(9), "defaultLibrary");
+    response.documents().unwrap().get(0).data().unwrap().iter().collect()
+}
+
+#[tokio::test]
+async fn test_semantic_tokens_position_encodings() {
+    let source = "// 😀\nfn f() {} // 😀\n";
+    let (comment, keyword, function) = (17, 15, 12);
+
+    assert_eq!(
+        semantic_tokens(source, common::OffsetEncoding::Utf16).await,
+        [0, 0, 5, comment, 0, 1, 0, 2, keyword, 0, 0, 3, 1, function, 0, 0, 7, 5, comment, 0]
+    );
+    assert_eq!(
+        semantic_tokens(source, common::OffsetEncoding::Utf8).await,
+        [0, 0, 7, comment, 0, 1, 0, 2, keyword, 0, 0, 3, 1, function, 0, 0, 7, 7, comment, 0]
+    );
+}
+
+#[tokio::test]
+async fn test_semantic_tokens_split_lines_and_modifiers() {
+    let source = "/* a\n   b */ fn f(&self) {}";
+    let data = semantic_tokens(source, common::OffsetEncoding::Utf32).await;
+    let tokens: Vec<&[u32]> = data.chunks(5).collect();
+
+    // The block comment becomes one token per line.
+    assert_eq!(tokens[0], [0, 0, 4, 17, 0]);
+    assert_eq!(tokens[1], [1, 0, 7, 17, 0]);
+    // `self` is a builtin variable, which LSP calls a default-library variable.
+    let this = tokens.last().unwrap();
+    assert_eq!(this[3], 8);
+    assert_eq!(this[4], 1 << 9);
+}
+
+#[tokio::test]
+async fn test_spans_utf16_offsets_and_positions() {
+    let state = server();
+    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(4096);
+    let filename = builder.create_string("test.rs");
+    let contents = builder.create_vector("// 😀\nfn f() {}\n".as_bytes());
+    let file = common::File::create(
+        &mut builder,
+        &common::FileArgs {
+            filename: Some(filename),
+            contents: Some(contents),
+            ..Default::default()
+        },
+    );
+    let files = builder.create_vector(&[file]);
+    let request = spans::Request::create(
+        &mut builder,
+        &spans::RequestArgs {
+            files: Some(files),
+            offset_encoding: common::OffsetEncoding::Utf16,
+            include_positions: true,
+            ..Default::default()
+        },
+    );
+    builder.finish(request, None);
+    let request_bytes = builder.finished_data().to_vec();
+
+    let response = generic_handler::<SpansProcessor>(State(state), HeaderMap::new(), Bytes::from(request_bytes))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let response = flatbuffers::root::<spans::Response>(&body).unwrap();
+    let document = response.documents().unwrap().get(0);
+
+    // "// 😀\n" is 6 UTF-16 units long, so the second line starts at 6, not at byte 8.
+    let line_starts: Vec<u64> = document.line_starts().unwrap().iter().collect();
+    assert_eq!(line_starts, [0, 6, 16]);
+
+    let spans: Vec<_> = document.spans().unwrap().iter().collect();
+    let comment = spans[0];
+    assert_eq!((comment.start(), comment.end()), (0, 5));
+    assert_eq!((comment.start_line(), comment.start_column()), (0, 0));
+    assert_eq!((comment.end_line(), comment.end_column()), (0, 5));
+    let keyword = spans.iter().find(|s| s.start() == 6).unwrap();
+    assert_eq!(keyword.end(), 8);
+    assert_eq!((keyword.start_line(), keyword.start_column()), (1, 0));
+    assert_eq!((keyword.end_line(), keyword.end_column()), (1, 2));
+}
+
+#[tokio::test]
+async fn test_spans_keep_nested_scopes() {
+    let state = server();
+    let source = r#"let s = "a\nb";"#;
+    let request = format!(
+        r#"{{"files": [{{"filename": "test.rs", "contents": {}}}]}}"#,
+        serde_json::to_string(source).unwrap()
+    );
+    let response = generic_handler::<SpansProcessor>(
+        State(state),
+        headers(&[(http::header::CONTENT_TYPE, "application/json")]),
+        Bytes::from(request),
+    )
+    .await
+    .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let spans: Vec<(String, u64, Vec<usize>)> = json["documents"][0]["spans"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|span| {
+            let text = source[span["start"].as_u64().unwrap() as usize..span["end"].as_u64().unwrap() as usize].to_string();
+            let scopes = serde_json::from_value(span["scopes"].clone()).unwrap();
+            (text, span["index"].as_u64().unwrap(), scopes)
+        })
+        .collect();
+
+    let find = |text: &str| spans.iter().find(|(t, _, _)| t == text).unwrap();
+    assert_eq!(find("let").2, [index_of("keyword")]);
+    assert_eq!(find("\\n").1 as usize, index_of("escape"));
+    assert_eq!(find("\\n").2, [index_of("string"), index_of("escape")]);
+    assert_eq!(find("b\"").2, [index_of("string")]);
+}
+
+#[tokio::test]
+async fn test_line_windows() {
+    // Line 3 is inside a string that starts on line 2, which only a whole-file parse knows.
+    let source = "x = 1\ns = \"\"\"\nnot = code\n\"\"\"\ny = 2\n";
+    let file = serde_json::json!({"filename": "test.py", "contents": source, "start_line": 3, "end_line": 4});
+    let request = serde_json::json!({"files": [file], "full_document": true});
+    let json = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+    let state = server();
+    let content_type = headers(&[(http::header::CONTENT_TYPE, "application/json")]);
+
+    let response = generic_handler::<HtmlProcessor>(
+        State(state.clone()),
+        content_type.clone(),
+        Bytes::from(request.to_string()),
+    )
+    .await
+    .unwrap();
+    let document = json(response).await["documents"][0].clone();
+    let lines: Vec<&str> = document["lines"].as_array().unwrap().iter().filter_map(|l| l.as_str()).collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("<span class=\"string\">not = code"));
+    let rendered = document["rendered"].as_str().unwrap();
+    assert!(rendered.contains("id=\"L3\""));
+    assert!(rendered.contains("id=\"L4\""));
+    assert!(!rendered.contains("id=\"L2\"") && !rendered.contains("id=\"L5\""));
+
+    let response = generic_handler::<SpansProcessor>(
+        State(state.clone()),
+        content_type.clone(),
+        Bytes::from(request.to_string()),
+    )
+    .await
+    .unwrap();
+    let document = json(response).await["documents"][0].clone();
+    assert_eq!(document["line_starts"], serde_json::json!([14, 25]));
+    let spans = document["spans"].as_array().unwrap();
+    assert!(!spans.is_empty());
+    // The string starts before the window, so it's clipped to the window's first line.
+    assert_eq!(spans[0]["start"], 14);
+    assert!(spans.iter().all(|span| span["start"].as_u64() >= Some(14) && span["end"].as_u64() <= Some(29)));
+
+    // A window of just line 3 cuts the string off at both ends.
+    let file = serde_json::json!({"filename": "test.py", "contents": source, "start_line": 3, "end_line": 3});
+    let request = serde_json::json!({"files": [file], "include_positions": true});
+    let response = generic_handler::<SpansProcessor>(State(state), content_type, Bytes::from(request.to_string()))
+        .await
+        .unwrap();
+    let document = json(response).await["documents"][0].clone();
+    let spans = document["spans"].as_array().unwrap();
+    assert_eq!(spans.len(), 1);
+    assert_eq!((&spans[0]["start"], &spans[0]["end"]), (&serde_json::json!(14), &serde_json::json!(25)));
+    assert_eq!((&spans[0]["start_line"], &spans[0]["end_line"]), (&serde_json::json!(2), &serde_json::json!(3)));
+}
+
+async fn html_document(contents: &str, inline_styles: bool, emphasized: &[(u32, u32)]) -> String {
+    let state = server();
+    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(4096);
+    let filename = builder.create_string("test.py");
+    let contents = builder.create_vector(contents.as_bytes());
+    let file = common::File::create(
+        &mut builder,
+        &common::FileArgs {
+            filename: Some(filename),
+            contents: Some(contents),
+            ..Default::default()
+        },
+    );
+    let files = builder.create_vector(&[file]);
+    let ranges: Vec<_> = emphasized
+        .iter()
+        .map(|&(start, end)| common::LineRange::create(&mut builder, &common::LineRangeArgs { start, end }))
+        .collect();
+    let emphasized_lines = builder.create_vector(&ranges);
+    let request = html::Request::create(
+        &mut builder,
+        &html::RequestArgs {
+            files: Some(files),
+            inline_styles,
+            full_document: true,
+            emphasized_lines: Some(emphasized_lines),
+            ..Default::default()
+        },
+    );
+    builder.finish(request, None);
+    let request_bytes = builder.finished_data().to_vec();
+
+    let response = html_handler(State(state), Bytes::from(request_bytes))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let fb_response = flatbuffers::root::<html::Response>(&body).unwrap();
+    let document = fb_response.documents().unwrap().get(0);
+    assert_eq!(document.lines().unwrap().len(), 3);
+    document.rendered().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_full_html_document() {
+    let rendered = html_document("x = 1\ny = 2\nz = 3\n", false, &[(2, 3)]).await;
+
+    assert!(rendered.starts_with("<pre class=\"daylight\" data-language=\"python\"><code>"));
+    assert!(rendered.ends_with("</code></pre>"));
+    assert!(rendered.contains(
+        "<span class=\"line\" id=\"L1\"><a class=\"line-number\" href=\"#L1\">1</a><span class=\"line-content\">"
+    ));
+    assert!(rendered.contains("<span class=\"line emphasized\" id=\"L2\">"));
+    assert!(rendered.contains("<span class=\"line emphasized\" id=\"L3\">"));
+    assert!(!rendered.contains("style="));
+}
+
+#[tokio::test]
+async fn test_full_html_document_inline_styles() {
+    let rendered = html_document("x = 1\ny = 2\nz = 3\n", true, &[(1, 1)]).await;
+
+    assert!(rendered.starts_with("<pre class=\"daylight\" data-language=\"python\" style=\"color:#"));
+    assert!(rendered.contains(&format!("<code style=\"{}\">", themes::CODE_CSS)));
+    assert!(rendered.contains(themes::EMPHASIZED_CSS));
+    assert!(rendered.contains(&format!("id=\"L2\" style=\"{}\"", themes::LINE_CSS)));
+    assert!(!rendered.contains("class=\"number\""));
+}
+
+async fn markup_lines<P: crate::processors::Processor>(request: serde_json::Value) -> Vec<String> {
+    serde_json::from_value(json_document::<P>(request).await["lines"].clone()).unwrap()
+}
+
+#[tokio::test]
+async fn test_latex_output() {
+    let file = serde_json::json!({"filename": "test.py", "contents": "s = \"{\\}\"\nx = 50%\n"});
+
+    let verbatim = markup_lines::<LatexProcessor>(serde_json::json!({"files": [file]})).await;
+    assert_eq!(
+        verbatim[0],
+        "\\DaylightVariable{s} \\DaylightOperator{=} \\DaylightString{\"\\DaylightZob{}\\DaylightZbs{}\\DaylightZcb{}\"}"
+    );
+
+    let text = markup_lines::<LatexProcessor>(serde_json::json!({"files": [file], "style": "TextColor"})).await;
+    assert!(text[0].starts_with("\\DaylightVariable{s}\\ \\DaylightOperator{=}\\ "));
+    assert!(text[0].contains("\\DaylightString{\"\\{\\textbackslash{}\\}\"}"));
+    assert!(text[1].contains("\\%"));
+}
+
+#[tokio::test]
+async fn test_typst_output() {
+    let file = serde_json::json!({"filename": "test.py", "contents": "s = \"a\\n#\"\n"});
+    let lines = markup_lines::<TypstProcessor>(serde_json::json!({"files": [file]})).await;
+    assert_eq!(
+        lines,
+        ["#daylight-variable[#\"s\"]#\" \"#daylight-operator[#\"=\"]#\" \"#daylight-string[#\"\\\"a\"#daylight-escape[#\"\\\\n\"]#\"#\\\"\"]"]
+    );
+}
+
+#[tokio::test]
+async fn test_latex_and_typst_preambles() {
+    let body = |response: axum::response::Response| async {
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    };
+    let tex = body(theme_file(axum::extract::Path("dusk.tex".to_string()), Default::default()).await).await;
+    assert!(tex.contains("\\newcommand{\\DaylightZbs}{\\char`\\\\}"));
+    assert!(tex.contains("\\definecolor{DaylightBackground}{HTML}{0D1117}"));
+    assert!(tex.contains("\\newcommand{\\DaylightKeyword}[1]{\\textcolor[HTML]{"));
+    assert!(tex.contains("\\newcommand{\\DaylightPunctuationBracket}[1]{"));
+
+    let typ = body(theme_file(axum::extract::Path("dusk.typ".to_string()), Default::default()).await).await;
+    assert!(typ.contains("#let daylight-background = rgb(\"#0d1117\")"));
+    assert!(typ.contains("#let daylight-keyword(body) = text(fill: rgb(\"#"));
+    assert!(typ.contains("#let daylight-punctuation-bracket(body) = "));
+}
+
+/// Draw one file from a JSON request, returning the image and its size from a Flatbuffers response.
+async fn code_image(request: serde_json::Value) -> (Vec<u8>, u32, u32) {
+    let state = server();
+    let response = generic_handler::<ImageProcessor>(
+        State(state),
+        headers(&[
+            (http::header::CONTENT_TYPE, "application/json"),
+            (http::header::ACCEPT, "application/x-flatbuffers"),
+        ]),
+        Bytes::from(request.to_string()),
+    )
+    .await
+    .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let response = flatbuffers::root::<image::Response>(&body).unwrap();
+    let document = response.documents().unwrap().get(0);
+    assert_eq!(document.error_code(), common::ErrorCode::NoError);
+    (document.image().unwrap().bytes().to_vec(), document.width(), document.height())
+}
+
+#[tokio::test]
+async fn test_svg_image() {
+    let file = serde_json::json!({"filename": "test.rs", "contents": "fn f() -> bool {\n\ta < b\n}\n"});
+    let (svg, width, height) = code_image(serde_json::json!({"files": [file], "line_numbers": true})).await;
+    let svg = String::from_utf8(svg).unwrap();
+
+    assert!(svg.starts_with(&format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}""#
+    )));
+    // Three lines of 21px, between 32px of padding.
+    assert_eq!(height, 32 + 3 * 21 + 32);
+    assert!(svg.contains(r#"<rect width="#));
+    assert!(svg.contains(r##"fill="#cf222e">fn</tspan>"##));
+    assert!(svg.contains(r#"fill-opacity="0.5">3</tspan>"#));
+    // The tab is expanded, and `<` is escaped.
+    assert!(svg.contains(">    a &lt; b</tspan>"));
+}
+
+#[tokio::test]
+async fn test_png_image() {
+    let file = serde_json::json!({"filename": "test.py", "contents": "def f():\n    return 1\n"});
+    let request = serde_json::json!({"files": [file], "format": "Png", "theme": "dusk", "window_chrome": true});
+    let (png, width, height) = code_image(request).await;
+
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    // The IHDR chunk, which always comes first, holds the width and height.
+    assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), width);
+    assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), height);
+    // A title bar of 35px sits above the padding.
+    assert_eq!(height, 35 + 32 + 2 * 21 + 32);
+
+    let plain = serde_json::json!({"files": [file], "format": "Png", "theme": "dusk"});
+    let (_, plain_width, plain_height) = code_image(plain).await;
+    assert_eq!((plain_width, plain_height + 35), (width, height));
+}
+
+#[tokio::test]
+async fn test_rtf_output() {
+    let state = server();
+    let file = serde_json::json!({"filename": "test.py", "contents": "s = \"{\\}\"  # café 😀\nx = 1\n"});
+    let response = generic_handler::<RtfProcessor>(
+        State(state),
+        headers(&[(http::header::CONTENT_TYPE, "application/json")]),
+        Bytes::from(serde_json::json!({"files": [file], "theme": "dusk"}).to_string()),
+    )
+    .await
+    .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let rtf = json["documents"][0]["rtf"].as_str().unwrap();
+
+    assert!(rtf.is_ascii());
+    // Dusk's foreground and background come first in the color table.
+    assert!(rtf.contains("{\\colortbl;\\red201\\green209\\blue217;\\red13\\green17\\blue23;"));
+    assert!(rtf.contains("\\cf1\\chcbpat2 "));
+    assert!(rtf.contains("\"\\{\\\\\\}\""));
+    assert!(rtf.contains("caf\\u233?"));
+    assert!(rtf.contains("\\u-10179?\\u-8704?"));
+    assert!(rtf.contains("\\par\n"));
+    assert!(rtf.ends_with('}'));
+}
+
+#[tokio::test]
+async fn test_markdown_code_blocks() {
+    let readme = "# Title\n\nSome *prose*.\n\n```py\nx = 1\n\ny = 2\n```\n\n```klingon\nQapla' <3\n```\n\nThe end.\n";
+    let file = serde_json::json!({"filename": "README.md", "contents": readme});
+
+    let document = json_document::<MarkdownProcessor>(serde_json::json!({"files": [file]})).await;
+    assert_eq!(document["language"], "Markdown");
+    let rendered = document["rendered"].as_str().unwrap();
+    assert!(rendered.starts_with("# Title\n\nSome *prose*.\n\n<pre class=\"daylight\" data-language=\"python\"><code>"));
+    assert!(rendered.contains("<span class=\"variable\">x</span>"));
+    assert!(rendered.contains("&#10;&#10;<span class=\"variable\">y</span>"));
+    assert!(rendered.contains("</code></pre>\n\n<pre class=\"daylight\"><code>Qapla' &lt;3</code></pre>\n\nThe end.\n"));
+
+    let document = json_document::<MarkdownProcessor>(serde_json::json!({"files": [file], "render_prose": true})).await;
+    let rendered = document["rendered"].as_str().unwrap();
+    assert!(rendered.starts_with("<h1>Title</h1>\n<p>Some <em>prose</em>.</p>\n<pre class=\"daylight\" data-language=\"python\">"));
+    assert!(rendered.contains("\n\n<span class=\"variable\">y</span>"));
+    assert!(rendered.contains("<p>The end.</p>"));
+}
+
+#[test]
+fn test_language_aliases() {
+    use crate::languages;
+    assert_eq!(languages::from_info_string("python").unwrap().name, "python");
+    assert_eq!(languages::from_info_string("py").unwrap().name, "python");
+    assert_eq!(languages::from_info_string("c++").unwrap().name, "cpp");
+    assert_eq!(languages::from_info_string("shell").unwrap().name, "bash");
+    assert_eq!(languages::from_info_string("md").unwrap().name, "markdown");
+    assert!(languages::from_info_string("klingon").is_none());
+    // Injections still only resolve exact names.
+    assert!(languages::from_name("py").is_none());
+    assert!(languages::from_name("c++").is_none());
+}
+
+#[tokio::test]
+async fn test_diff() {
+    let old = "fn main() {\n    let x = 1;\n    println!(\"{x}\");\n}\n";
+    let new = "fn main() {\n    let x = 2;\n    println!(\"{x}\");\n    x\n}\n";
+    let files = serde_json::json!({
+        "files": [{"ident": 3, "filename": "main.rs", "contents": new}],
+        "old_files": [{"ident": 3, "contents": old}],
+    });
+    let request = |extra: serde_json::Value| {
+        let mut request = files.clone();
+        request.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+        request
+    };
+
+    let document = json_document::<DiffProcessor>(request(serde_json::json!({"word_diff": true}))).await;
+    assert_eq!(document["error_code"], "NoError");
+    let hunk = &document["hunks"][0];
+    assert_eq!((hunk["old_lines"].as_u64(), hunk["new_lines"].as_u64()), (Some(4), Some(5)));
+    let lines = hunk["lines"].as_array().unwrap();
+    let kinds: Vec<&str> = lines.iter().map(|l| l["kind"].as_str().unwrap()).collect();
+    assert_eq!(kinds, ["Context", "Removed", "Added", "Context", "Added", "Context"]);
+    assert_eq!((lines[1]["old_number"].as_u64(), lines[1]["new_number"].as_u64()), (Some(2), Some(0)));
+    assert!(lines[1]["html"].as_str().unwrap().contains("<del><span class=\"constant.builtin\">1</span></del>"));
+    assert!(lines[2]["html"].as_str().unwrap().contains("<ins><span class=\"constant.builtin\">2</span></ins>"));
+    assert_eq!(lines[2]["changes"], serde_json::json!([{"start": 12, "end": 13}]));
+    assert!(lines[0]["html"].as_str().unwrap().starts_with("<span class=\"keyword\">fn</span>"));
+
+    let side_by_side = serde_json::json!({"layout": "SideBySide", "markup": "Spans", "context_lines": 0});
+    let document = json_document::<DiffProcessor>(request(side_by_side)).await;
+    let hunks = document["hunks"].as_array().unwrap();
+    assert_eq!(hunks.len(), 2);
+    assert_eq!(hunks[0]["rows"].as_array().unwrap().len(), 1);
+    let row = &hunks[0]["rows"][0];
+    assert_eq!(row["old"]["text"], "    let x = 1;");
+    assert_eq!(row["new"]["text"], "    let x = 2;");
+    assert!(row["new"]["spans"].as_array().unwrap().iter().any(|span| span["start"] == 4 && span["end"] == 7));
+    assert!(hunks[1]["rows"][0]["old"].is_null());
+    assert_eq!(hunks[1]["rows"][0]["new"]["new_number"], 4);
+    assert_eq!((hunks[1]["old_start"].as_u64(), hunks[1]["old_lines"].as_u64()), (Some(3), Some(0)));
+
+    // Without an old version, every line was added.
+    let document = json_document::<DiffProcessor>(serde_json::json!({"files": [{"filename": "main.rs", "contents": new}], "context_lines": 3})).await;
+    let lines = document["hunks"][0]["lines"].as_array().unwrap();
+    assert!(lines.iter().all(|line| line["kind"] == "Added"));
+}
+
+#[tokio::test]
+async fn test_diff_deleted_files() {
+    let old = "fn main() {\n    let x = 1;\n}\n";
+    let request = serde_json::json!({
+        "files": [
+            {"ident": 0, "filename": "gone.rs", "contents": ""},
+            {"ident": 1, "filename": "new.rs", "contents": old},
+        ],
+        // An old version with no new one to compare it to is left out.
+        "old_files": [{"ident": 0, "contents": old}, {"ident": 7, "contents": old}],
+    });
+    let mut json = json_response::<DiffProcessor>(request).await;
+    let documents = json["documents"].as_array_mut().unwrap();
+    documents.sort_by_key(|d| d["ident"].as_u64());
+    assert_eq!(documents.len(), 2);
+    let kinds = |document: &serde_json::Value| -> Vec<String> {
+        let lines = document["hunks"][0]["lines"].as_array().unwrap();
+        lines.iter().map(|line| line["kind"].as_str().unwrap().to_string()).collect()
+    };
+
+    // A deleted file has every old line removed.
+    let deleted = &documents[0];
+    assert_eq!(deleted["error_code"], "NoError");
+    assert_eq!(kinds(deleted), ["Removed"; 3]);
+    let hunk = &deleted["hunks"][0];
+    assert_eq!((hunk["old_start"].as_u64(), hunk["old_lines"].as_u64()), (Some(1), Some(3)));
+    assert_eq!((hunk["new_start"].as_u64(), hunk["new_lines"].as_u64()), (Some(0), Some(0)));
+    assert!(hunk["lines"][1]["html"].as_str().unwrap().contains("<span class=\"keyword\">let</span>"));
+
+    assert_eq!(documents[1]["error_code"], "NoError");
+    assert_eq!(kinds(&documents[1]), ["Added"; 3]);
+}
+
+#[tokio::test]
+async fn test_diff_context_lines() {
+    let old: String = (1..=12).map(|n| format!("x{n} = {n}\n")).collect();
+    let new = old.replace("x4 = 4", "x4 = 40").replace("x6 = 6", "x6 = 60").replace("x11 = 11", "x11 = 110");
+    let request = |context_lines: i64| {
+        serde_json::json!({
+            "files": [{"filename": "x.py", "contents": new}],
+            "old_files": [{"contents": old}],
+            "context_lines": context_lines,
+        })
+    };
+
+    // Changes closer together than twice the context share a hunk.
+    let document = json_document::<DiffProcessor>(request(1)).await;
+    let hunks = document["hunks"].as_array().unwrap();
+    assert_eq!(hunks.len(), 2);
+    let ranges: Vec<_> = hunks.iter().map(|h| (h["old_start"].as_u64().unwrap(), h["old_lines"].as_u64().unwrap())).collect();
+    assert_eq!(ranges, [(3, 5), (10, 3)]);
+    let numbers: Vec<_> = hunks[0]["lines"].as_array().unwrap().iter().map(|l| l["old_number"].as_u64().unwrap()).collect();
+    assert_eq!(numbers, [3, 4, 0, 5, 6, 0, 7]);
+
+    // Without context, each change is a hunk of its own.
+    let document = json_document::<DiffProcessor>(request(0)).await;
+    assert_eq!(document["hunks"].as_array().unwrap().len(), 3);
+
+    // A negative count keeps every line in one hunk.
+    let document = json_document::<DiffProcessor>(request(-1)).await;
+    let hunks = document["hunks"].as_array().unwrap();
+    assert_eq!(hunks.len(), 1);
+    assert_eq!(hunks[0]["lines"].as_array().unwrap().len(), 15);
+}
+
+#[tokio::test]
+async fn test_patch() {
+    let patch = "\
+diff --git a/src/main.rs b/src/main.rs
+index 1111111..2222222 100644
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,3 +1,3 @@
+ fn main() {
+-    let x = 1;
++    let x = 2;
+ }
+\\ No newline at end of file
+diff --git a/notes.unknown b/notes.unknown
+--- a/notes.unknown
++++ b/notes.unknown
+@@ -1 +1 @@
+--- old <text>
++++ new
+";
+    let state = server();
+    let request = serde_json::json!({"files": [{"filename": "change.patch", "contents": patch}]});
+    let response = generic_handler::<PatchProcessor>(
+        State(state),
+        headers(&[(http::header::CONTENT_TYPE, "application/json")]),
+        Bytes::from(request.to_string()),
+    )
+    .await
+    .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let document = &json["documents"][0];
+    assert_eq!(document["error_code"], "NoError");
+    assert_eq!(document["language"], "Diff");
+
+    let files = document["files"].as_array().unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!((files[0]["old_path"].as_str(), files[0]["new_path"].as_str()), (Some("src/main.rs"), Some("src/main.rs")));
+    assert_eq!(files[0]["language"], "Rust");
+    assert_eq!(files[1]["language"], "Unspecified");
+
+    let lines: Vec<&str> = document["lines"].as_array().unwrap().iter().map(|l| l.as_str().unwrap()).collect();
+    assert_eq!(lines.len(), patch.lines().count());
+    assert_eq!(lines[0], "<span class=\"diff-header\">diff --git a/src/main.rs b/src/main.rs</span>");
+    assert_eq!(lines[4], "<span class=\"diff-hunk\">@@ -1,3 +1,3 @@</span>");
+    assert!(lines[5].starts_with(
+        "<span class=\"diff-context\"><span class=\"diff-marker\"> </span><span class=\"keyword\">fn</span>"
+    ));
+    assert!(lines[6].starts_with("<span class=\"diff-removed\"><span class=\"diff-marker\">-</span>"));
+    assert!(lines[7].contains("<span class=\"keyword\">let</span>"));
+    assert!(lines[7].starts_with("<span class=\"diff-added\">"));
+    assert!(lines[9].starts_with("<span class=\"diff-note\">"));
+    // Counting the hunk's lines keeps a removed line that looks like a header in the hunk, and an
+    // unknown language is escaped rather than highlighted.
+    assert_eq!(
+        lines[14],
+        "<span class=\"diff-removed\"><span class=\"diff-marker\">-</span>-- old &lt;text&gt;</span>"
+    );
+    assert!(lines[15].starts_with("<span class=\"diff-added\">"));
+}
+
+#[tokio::test]
+async fn test_patch_edge_cases() {
+    // A file that's added, and one that's deleted, whose removed lines look like the headers of
+    // another file.
+    let patch = "\
+diff --git a/new.py b/new.py
+new file mode 100644
+--- /dev/null
++++ b/new.py
+@@ -0,0 +1,2 @@
++def f():
++    return 1
+diff --git a/old.patch b/old.patch
+deleted file mode 100644
+--- a/old.patch
++++ /dev/null
+@@ -1,2 +0,0 @@
+---- a/x
+-+++ b/x
+";
+    let document = json_document::<PatchProcessor>(serde_json::json!({"files": [{"filename": "change.patch", "contents": patch}]})).await;
+    assert_eq!(document["error_code"], "NoError");
+
+    // `/dev/null` stands for no path at all, and the language comes from whichever side exists.
+    let files = document["files"].as_array().unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!((files[0]["old_path"].as_str(), files[0]["new_path"].as_str()), (Some(""), Some("new.py")));
+    assert_eq!(files[0]["language"], "Python");
+    assert_eq!((files[1]["old_path"].as_str(), files[1]["new_path"].as_str()), (Some("old.patch"), Some("")));
+    assert_eq!(files[1]["language"], "Diff");
+
+    let lines: Vec<&str> = document["lines"].as_array().unwrap().iter().map(|l| l.as_str().unwrap()).collect();
+    assert_eq!(lines.len(), patch.lines().count());
+    assert_eq!(lines[2], "<span class=\"diff-header\">--- /dev/null</span>");
+    assert!(lines[5].contains("<span class=\"keyword\">def</span>"));
+    assert_eq!(lines[10], "<span class=\"diff-header\">+++ /dev/null</span>");
+    // The hunk has two lines left to remove, so these are its lines rather than a new file.
+    assert!(lines[12].starts_with("<span class=\"diff-removed\"><span class=\"diff-marker\">-</span>"));
+    assert!(lines[12].contains("--- a/x"));
+    assert!(lines[13].starts_with("<span class=\"diff-removed\"><span class=\"diff-marker\">-</span>"));
+    assert!(lines[13].contains("+++ b/x"));
+}
+
+#[tokio::test]
+async fn test_tags() {
+    let rust = "struct Point;\n\nimpl Point {\n    fn new() -> Self {\n        Point\n    }\n}\n\nfn main() {\n    Point::new();\n}\n";
+    let document = json_document::<TagsProcessor>(serde_json::json!({"files": [{"filename": "main.rs", "contents": rust}]})).await;
+    assert_eq!(document["error_code"], "NoError");
+    let found: Vec<(&str, &str, bool)> = document["tags"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tag| (tag["kind"].as_str().unwrap(), tag["name"].as_str().unwrap(), tag["is_definition"].as_bool().unwrap()))
+        .collect();
+    assert!(found.contains(&("class", "Point", true)));
+    // A function in an `impl` is only tagged as a method, not also as a function.
+    assert!(found.contains(&("method", "new", true)));
+    assert!(!found.contains(&("function", "new", true)));
+    assert!(found.contains(&("function", "main", true)));
+    let main = document["tags"].as_array().unwrap().iter().find(|tag| tag["name"] == "main").unwrap();
+    assert_eq!((main["start_line"].as_u64(), main["end_line"].as_u64()), (Some(8), Some(10)));
+    assert_eq!(&rust[main["name_start"].as_u64().unwrap() as usize..main["name_end"].as_u64().unwrap() as usize], "main");
+    assert!(rust[main["start"].as_u64().unwrap() as usize..].starts_with("fn main()"));
+
+    // Only the comments directly above a definition document it.
+    let go = "package main\n\n// Unrelated.\n\n// Add adds\n// two numbers.\nfunc Add(a, b int) int {\n\treturn a + b\n}\n";
+    let document = json_document::<TagsProcessor>(serde_json::json!({"files": [{"filename": "add.go", "contents": go}]})).await;
+    let add = document["tags"].as_array().unwrap().iter().find(|tag| tag["name"] == "Add").unwrap();
+    assert_eq!(add["kind"], "function");
+    assert_eq!(add["docs"], "Add adds\ntwo numbers.");
+
+    // Languages without a tags query have no tags, even where there are definitions.
+    for (filename, source) in [("data.json", "{\"a\": 1}"), ("a.sh", "f() {\n  echo hi\n}\n")] {
+        let document = json_document::<TagsProcessor>(serde_json::json!({"files": [{"filename": filename, "contents": source}]})).await;
+        assert_eq!(document["error_code"], "NoError", "{filename}");
+        assert_eq!(document["tags"], serde_json::json!([]), "{filename}");
+    }
+
+    // Every language's tags query compiles against its grammar.
+    for filename in ["a.c", "a.cpp", "A.java", "a.js", "a.jsx", "a.py", "a.rb", "a.ts", "a.tsx"] {
+        let source = match filename.rsplit_once('.').unwrap().1 {
+            "c" | "cpp" => "int f(void) { return 0; }\n",
+            "java" => "class A { void f() {} }\n",
+            "py" => "def f():\n    pass\n",
+            "rb" => "def f\nend\n",
+            _ => "function f() {}\n",
+        };
+        let document = json_document::<TagsProcessor>(serde_json::json!({"files": [{"filename": filename, "contents": source}]})).await;
+        assert!(
+            document["tags"].as_array().unwrap().iter().any(|tag| tag["name"] == "f" && tag["is_definition"] == true),
+            "{filename}: {document}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_folds() {
+    let rust = "\
+use std::io;
+use std::fmt;
+
+// One comment
+// run.
+impl Point {
+    fn new() -> Self {
+        let x = 1; // trailing
+        Point { x }
+    }
+}
+";
+    let document = json_document::<FoldsProcessor>(serde_json::json!({"files": [{"filename": "main.rs", "contents": rust}]})).await;
+    assert_eq!(document["error_code"], "NoError");
+    let found: Vec<(u64, u64, &str)> = document["folds"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|fold| (fold["start_line"].as_u64().unwrap(), fold["end_line"].as_u64().unwrap(), fold["kind"].as_str().unwrap()))
+        .collect();
+    // The `impl` and its body start on the same line, as do the function and its block, so
+    // only the larger of each is kept. Single-line nodes can't be folded.
+    assert_eq!(found, [(0, 1, "Imports"), (3, 4, "Comment"), (5, 10, "Region"), (6, 9, "Region")]);
+
+    // Every language's folds query compiles against its grammar, and finds something.
+    for (filename, source) in [
+        ("a.agda", "module A where\n\ndata N : Set where\n  z : N\n  s : N -> N\n"),
+        ("a.sh", "f() {\n  echo hi\n}\n"),
+        ("a.c", "int f(void) {\n  return 0;\n}\n"),
+        ("a.cpp", "namespace a {\nint x;\n}\n"),
+        ("a.css", "a {\n  color: red;\n}\n"),
+        ("a.diff", "diff --git a/a b/a\n--- a/a\n+++ b/a\n@@ -1 +1 @@\n-a\n+b\n"),
+        ("a.go", "package a\n\nfunc f() {\n\treturn\n}\n"),
+        ("a.html", "<div>\n  <p>hi</p>\n</div>\n"),
+        ("A.java", "class A {\n  void f() {}\n}\n"),
+        ("a.js", "function f() {\n  return 1;\n}\n"),
+        ("a.json", "{\n  \"a\": 1\n}\n"),
+        ("a.jsx", "const a = (\n  <div>\n    hi\n  </div>\n);\n"),
+        ("a.md", "# Title\n\nSome text.\n\n## Part\n\nMore.\n"),
+        ("a.py", "def f():\n    pass\n"),
+        ("a.rb", "def f\n  1\nend\n"),
+        ("a.ts", "interface A {\n  a: number;\n}\n"),
+        ("a.tsx", "const a = (\n  <div>\n    hi\n  </div>\n);\n"),
+    ] {
+        let document = json_document::<FoldsProcessor>(serde_json::json!({"files": [{"filename": filename, "contents": source}]})).await;
+        assert!(!document["folds"].as_array().unwrap().is_empty(), "{filename}: {document}");
+    }
+
+    // Every known language has a folds query, so only a file in no language at all has none.
+    let document = json_document::<FoldsProcessor>(serde_json::json!({"files": [{"filename": "notes.unknown", "contents": "a {\n  b\n}\n"}]})).await;
+    assert_eq!(document["error_code"], "UnknownLanguage");
+    assert_eq!(document["folds"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_diagnostics() {
+    let document = json_document::<DiagnosticsProcessor>(serde_json::json!({"files": [{"filename": "main.rs", "contents": "fn main() {\n    let x = 1;\n}\n"}]})).await;
+    assert_eq!(document["error_code"], "NoError");
+    assert_eq!(document["diagnostics"], serde_json::json!([]));
+    assert_eq!(document["parse_quality"], 1.0);
+
+    let document = json_document::<DiagnosticsProcessor>(serde_json::json!({"files": [{"filename": "main.rs", "contents": "fn main() {\n    let x = 1\n}\n"}]})).await;
+    let missing = &document["diagnostics"][0];
+    assert_eq!(missing["kind"], "Missing");
+    assert_eq!(missing["expected"], serde_json::json!([";"]));
+    assert_eq!((missing["start_line"].as_u64(), missing["start_column"].as_u64()), (Some(1), Some(13)));
+    assert_eq!(missing["start"], missing["end"]);
+    assert_eq!(document["parse_quality"], 1.0);
+
+    let source = "fn main() { let = 1; }";
+    let document = json_document::<DiagnosticsProcessor>(serde_json::json!({"files": [{"filename": "main.rs", "contents": source}]})).await;
+    let error = &document["diagnostics"][0];
+    assert_eq!(error["kind"], "Error");
+    assert_eq!(&source[error["start"].as_u64().unwrap() as usize..error["end"].as_u64().unwrap() as usize], "=");
+    // A pattern should have followed `let`.
+    let expected = error["expected"].as_array().unwrap();
+    assert!(expected.contains(&serde_json::json!("identifier")));
+    assert!(!expected.contains(&serde_json::json!("let_declaration")));
+    assert!(document["parse_quality"].as_f64().unwrap() < 1.0);
+
+    // Python read as Rust barely parses at all.
+    let document = json_document::<DiagnosticsProcessor>(serde_json::json!({"files": [{"filename": "main.rs", "contents": "def main():\n    print('hello')\n"}]})).await;
+    assert_eq!(document["error_code"], "NoError");
+    assert!(document["parse_quality"].as_f64().unwrap() < 0.5);
+}
+
+#[tokio::test]
+async fn test_diagnostics_edge_cases() {
+    let diagnostics = async |filename: &str, contents: &str| {
+        json_document::<DiagnosticsProcessor>(serde_json::json!({"files": [{"filename": filename, "contents": contents}]})).await
+    };
+
+    // An empty file parses cleanly.
+    let document = diagnostics("main.rs", "").await;
+    assert_eq!(document["error_code"], "NoError");
+    assert_eq!(document["diagnostics"], serde_json::json!([]));
+    assert_eq!(document["parse_quality"], 1.0);
+
+    // When the root itself is an error, the whole file is one, and a value was expected.
+    let document = diagnostics("data.json", "@@@").await;
+    let diagnostics_found = document["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics_found.len(), 1);
+    assert_eq!(diagnostics_found[0]["kind"], "Error");
+    assert_eq!((diagnostics_found[0]["start"].as_u64(), diagnostics_found[0]["end"].as_u64()), (Some(0), Some(3)));
+    let expected = diagnostics_found[0]["expected"].as_array().unwrap();
+    assert!(expected.contains(&serde_json::json!("{")) && expected.contains(&serde_json::json!("number")));
+    assert_eq!(document["parse_quality"], 0.0);
+
+    // An error at the bottom of very deep nesting is found without running out of stack.
+    let deep = format!("x = {}1 +{}", "(".repeat(100_000), ")".repeat(100_000));
+    let document = diagnostics("deep.py", &deep).await;
+    assert_eq!(document["error_code"], "NoError");
+    assert_eq!(document["diagnostics"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_references() {
+    let source = "function greet(name) {\n  const shout = () => name + \"!\";\n  return shout() + other;\n}\n";
+    let document = json_document::<ReferencesProcessor>(serde_json::json!({"files": [{"filename": "greet.js", "contents": source}]})).await;
+    assert_eq!(document["error_code"], "NoError");
+    let definitions = document["definitions"].as_array().unwrap();
+    let names: Vec<_> = definitions.iter().map(|d| d["name"].as_str().unwrap()).collect();
+    assert_eq!(names, ["name", "shout"], "{document}");
+    let scopes = document["scopes"].as_array().unwrap();
+    assert_eq!(scopes[0]["parent"], -1);
+    assert!(scopes[1..].iter().all(|scope| scope["parent"].as_i64() >= Some(0)));
+
+    let resolved = |name: &str| {
+        let reference = document["references"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|r| r["name"] == name)
+            .unwrap_or_else(|| panic!("no reference to {name}: {document}"));
+        reference["definition"].as_i64().unwrap()
+    };
+    // `name` is used in a nested arrow function, and `other` is a global.
+    assert_eq!(resolved("name"), 0);
+    assert_eq!(resolved("shout"), 1);
+    assert_eq!(resolved("other"), -1);
+
+    // A Ruby method can't see the variables around it, though a block can.
+    let ruby = "x = 1\ndef f\n  x\nend\n[1].each { x }\n";
+    let document = json_document::<ReferencesProcessor>(serde_json::json!({"files": [{"filename": "f.rb", "contents": ruby}]})).await;
+    let scopes = document["scopes"].as_array().unwrap();
+    assert_eq!(scopes.iter().map(|s| s["isolated"].as_bool().unwrap()).collect::<Vec<_>>(), [false, true, false], "{document}");
+    let references: Vec<(u64, i64)> = document["references"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|r| r["name"] == "x")
+        .map(|r| (r["line"].as_u64().unwrap(), r["definition"].as_i64().unwrap()))
+        .collect();
+    assert_eq!(references, [(2, -1), (4, 0)], "{document}");
+
+    // Languages without a locals query have no graph.
+    let document = json_document::<ReferencesProcessor>(serde_json::json!({"files": [{"filename": "main.go", "contents": "package main\n"}]})).await;
+    assert_eq!(document["definitions"], serde_json::json!([]));
+
+    let file = serde_json::json!({"filename": "greet.js", "contents": source});
+    let html = html_lines(serde_json::json!({"files": [file], "link_references": true})).await;
+    assert!(html.contains("<span id=\"def-0\">name</span>"), "{html}");
+    assert!(html.contains("<a href=\"#def-0\">name</a>"), "{html}");
+    assert!(html.contains("<a href=\"#def-1\">shout</a>"), "{html}");
+    assert!(!html.contains("data-ref"), "{html}");
+    // Ids are unique, however many elements a definition is split across.
+    assert_eq!(html.matches("id=\"def-0\"").count(), 1, "{html}");
+    assert_eq!(html.matches("id=\"def-1\"").count(), 1, "{html}");
+    let plain = html_lines(serde_json::json!({"files": [file]})).await;
+    assert!(!plain.contains("def-"));
+}
+
+#[tokio::test]
+async fn test_tree() {
+    let file = serde_json::json!({"filename": "main.rs", "contents": "fn main() {}\nconst X: u8 = 1;\n"});
+    let document = json_document::<TreeProcessor>(serde_json::json!({"files": [file]})).await;
+    assert_eq!(document["error_code"], "NoError");
+    let nodes = document["nodes"].as_array().unwrap();
+    assert_eq!(nodes[0]["kind"], "source_file");
+    assert_eq!(nodes[0]["parent"], -1);
+    let name = nodes.iter().position(|n| n["kind"] == "identifier").unwrap();
+    assert_eq!(nodes[name]["field_name"], "name");
+    assert_eq!(nodes[name]["is_named"], true);
+    assert_eq!((nodes[name]["start"].as_u64(), nodes[name]["end"].as_u64()), (Some(3), Some(7)));
+    assert_eq!(nodes[nodes[name]["parent"].as_u64().unwrap() as usize]["kind"], "function_item");
+    assert!(nodes.iter().any(|n| n["kind"] == "fn" && n["is_named"] == false));
+    assert!(document.get("sexp").is_none());
+
+    let document = json_document::<TreeProcessor>(serde_json::json!({"files": [file], "format": "SExpression"})).await;
+    assert_eq!(
+        document["sexp"],
+        "(source_file (function_item name: (identifier) parameters: (parameters) body: (block)) \
+         (const_item name: (identifier) type: (primitive_type) value: (integer_literal)))"
+    );
+
+    // Only the nodes around the second line, and no deeper than the items.
+    let document = json_document::<TreeProcessor>(serde_json::json!({"files": [file], "format": "SExpression", "max_depth": 1, "byte_start": 13})).await;
+    assert_eq!(document["sexp"], "(source_file (const_item))");
+
+    // Both limits at once: only the nodes that overlap `main`, down to the depth that's asked
+    // for, with each one still pointing at its parent.
+    let nodes = async |max_depth: u32| -> serde_json::Value {
+        let request = serde_json::json!({"files": [file], "max_depth": max_depth, "byte_start": 3, "byte_end": 7});
+        let document = json_document::<TreeProcessor>(request).await;
+        document["nodes"].as_array().unwrap().iter().map(|n| serde_json::json!([n["kind"], n["parent"]])).collect()
+    };
+    assert_eq!(nodes(1).await, serde_json::json!([["source_file", -1], ["function_item", 0]]));
+    assert_eq!(nodes(2).await, serde_json::json!([["source_file", -1], ["function_item", 0], ["identifier", 1]]));
+    assert_eq!(nodes(5).await, nodes(2).await);
+
+    let file = serde_json::json!({"filename": "main.rs", "contents": "fn main() { let x = 1 }"});
+    let document = json_document::<TreeProcessor>(serde_json::json!({"files": [file], "format": "SExpression"})).await;
+    assert!(document["sexp"].as_str().unwrap().contains("(MISSING \";\")"), "{document}");
+}
+
+#[tokio::test]
+async fn test_query() {
+    let files = serde_json::json!([
+        {"ident": 0, "filename": "a.rs", "contents": "fn one() {}\nfn two() { one(); }\n"},
+        {"ident": 1, "filename": "b.py", "contents": "def three():\n    pass\n"},
+        {"ident": 2, "filename": "c.go", "contents": "package main\n"},
+    ]);
+    let request = serde_json::json!({
+        "files": files,
+        "query": "(function_item name: (identifier) @name (#eq? @name \"two\")) @function",
+        "language_queries": [{"language": "Python", "query": "(function_definition name: (identifier) @name)"}],
+    });
+    // Documents come back in the order they finish.
+    let query = async |request| {
+        let mut json = json_response::<QueryProcessor>(request).await;
+        json["documents"].as_array_mut().unwrap().sort_by_key(|d| d["ident"].as_u64());
+        json
+    };
+    let json = query(request.clone()).await;
+    let rust = &json["documents"][0];
+    assert_eq!(rust["error_code"], "NoError");
+    let matches = rust["matches"].as_array().unwrap();
+    assert_eq!(matches.len(), 1, "{rust}");
+    let captures = &matches[0]["captures"];
+    assert_eq!(captures[0]["name"], "function");
+    assert_eq!(captures[0]["text"], "fn two() { one(); }");
+    assert_eq!(captures[1]["name"], "name");
+    assert_eq!((captures[1]["start_line"].as_u64(), captures[1]["start_column"].as_u64()), (Some(1), Some(3)));
+    assert_eq!(json["documents"][1]["matches"][0]["captures"][0]["text"], "three");
+
+    // The general query names Rust nodes, so it doesn't compile for Go, but Rust and Python are
+    // unaffected. Running the request again gets the same answers from the cache.
+    for _ in 0..2 {
+        let json = query(request.clone()).await;
+        let go = &json["documents"][2];
+        assert_eq!(go["error_code"], "InvalidQuery");
+        assert_eq!(go["matches"], serde_json::json!([]));
+        let errors = json["query_errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(errors[0]["language"], "Go");
+        assert_eq!(errors[0]["kind"], "NodeType");
+        assert_eq!(json["documents"][0]["error_code"], "NoError");
+    }
+
+    let json = query(serde_json::json!({"files": files, "query": "(identifier"})).await;
+    let errors = json["query_errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 3);
+    assert!(errors.iter().all(|e| e["kind"] == "Syntax"), "{errors:?}");
+
+    // A query matching every node stops at the limit, and says so.
+    let file = serde_json::json!({"filename": "big.py", "contents": "x = 1\n".repeat(5000)});
+    let document = json_document::<QueryProcessor>(serde_json::json!({"files": [file], "query": "(_) @node"})).await;
+    assert_eq!(document["error_code"], "NoError");
+    assert_eq!(document["matches"].as_array().unwrap().len(), crate::processors::MATCH_LIMIT);
+    assert_eq!(document["truncated"], true);
+    // Exactly as many matches as the limit is all of them.
+    let file = serde_json::json!({"filename": "big.py", "contents": "x = 1\n".repeat(crate::processors::MATCH_LIMIT)});
+    let document = json_document::<QueryProcessor>(serde_json::json!({"files": [file], "query": "(integer) @n"})).await;
+    assert_eq!(document["matches"].as_array().unwrap().len(), crate::processors::MATCH_LIMIT);
+    assert_eq!(document["truncated"], false);
+}
+
+async fn session_request(path: Option<&str>, request: serde_json::Value) -> (StatusCode, serde_json::Value) {
+    use axum::response::IntoResponse;
+
+    let state = State(Server {
+        default_per_file_timeout: Duration::from_secs(30),
+        max_per_file_timeout: Duration::from_secs(60),
+    });
+    let headers = headers(&[(http::header::CONTENT_TYPE, "application/json")]);
+    let body = Bytes::from(request.to_string());
+    let response = match path {
+        None => open_session(state, headers, body).await,
+        Some(id) => edit_session(state, axum::extract::Path(id.to_string()), headers, body).await,
+    };
+    let response = response.into_response();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+#[tokio::test]
+async fn test_sessions() {
+    let keyword = ALL_HIGHLIGHT_NAMES.iter().position(|n| *n == "keyword").unwrap();
+    let string = ALL_HIGHLIGHT_NAMES.iter().position(|n| *n == "string").unwrap();
+    let comment = ALL_HIGHLIGHT_NAMES.iter().position(|n| *n == "comment").unwrap();
+    let source = "fn main() {\n    let x = 1;\n    x\n}\n";
+    let (status, opened) =
+        session_request(None, serde_json::json!({"file": {"filename": "main.rs", "contents": source}})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(opened["error_code"], "NoError");
+    assert_eq!(opened["language"], "Rust");
+    assert_eq!(opened["line_count"], 5);
+    let id = opened["session_id"].as_str().unwrap().to_string();
+    assert!(!id.is_empty());
+    let mut lines = opened["changes"][0]["lines"].as_array().unwrap().clone();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[0]["spans"][0], serde_json::json!({"index": keyword, "start": 0, "end": 2}));
+
+    // The client keeps its own copy of each line's highlights, patched by each change.
+    let mut text = source.to_string();
+    let mut edit = async |start: usize, old_end: usize, new: &str| {
+        let (status, edited) = session_request(
+            Some(&id),
+            serde_json::json!({"edits": [{"start": start, "old_end": old_end, "text": new}]}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{edited}");
+        assert_eq!(edited["error_code"], "NoError");
+        text.replace_range(start..old_end, new);
+        for change in edited["changes"].as_array().unwrap() {
+            let range = change["start_line"].as_u64().unwrap() as usize..change["old_end_line"].as_u64().unwrap() as usize;
+            lines.splice(range, change["lines"].as_array().unwrap().iter().cloned());
+        }
+        assert_eq!(edited["line_count"].as_u64(), Some(lines.len() as u64));
+        edited["changes"].clone()
+    };
+
+    // Changing the number to a string only re-highlights its line.
+    let changes = edit(24, 25, "\"a\"").await;
+    assert_eq!(changes.as_array().unwrap().len(), 1);
+    assert_eq!((changes[0]["start_line"].as_u64(), changes[0]["old_end_line"].as_u64()), (Some(1), Some(2)));
+    assert!(changes[0]["lines"][0]["spans"].as_array().unwrap().iter().any(|s| s["index"] == string && s["start"] == 12));
+
+    // Renaming a variable to a name of the same length changes no highlights at all.
+    let changes = edit(20, 21, "y").await;
+    assert_eq!(changes, serde_json::json!([]));
+
+    // Commenting out a line, and splitting another in two.
+    let changes = edit(16, 16, "// ").await;
+    assert_eq!(changes[0]["lines"][0]["spans"][0], serde_json::json!({"index": comment, "start": 4, "end": 19}));
+    edit(11, 11, "\n").await;
+
+    // An unclosed block comment changes every line after it (but not the empty last one), and
+    // closing it changes them back.
+    let changes = edit(0, 0, "/* ").await;
+    assert_eq!((changes[0]["start_line"].as_u64(), changes[0]["old_end_line"].as_u64()), (Some(0), Some(5)));
+    edit(0, 3, "").await;
+    assert_eq!(lines.len(), 6);
+
+    // After all that, the client's highlights match a fresh session's.
+    let (_, fresh) = session_request(None, serde_json::json!({"file": {"filename": "main.rs", "contents": text}})).await;
+    assert_eq!(serde_json::Value::from(lines), fresh["changes"][0]["lines"]);
+
+    // Edits that don't fit the document are rejected, and leave it alone.
+    let (status, _) = session_request(Some(&id), serde_json::json!({"edits": [{"start": 5, "old_end": 500, "text": ""}]})).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    assert_eq!(close_session(axum::extract::Path(id.clone())).await, StatusCode::NO_CONTENT);
+    let (status, _) = session_request(Some(&id), serde_json::json!({"edits": []})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(close_session(axum::extract::Path(id)).await, StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn test_session_limits() {
+    use crate::sessions::{Edit, EditError, Session, SessionLimits, Sessions};
+    use std::sync::atomic::AtomicUsize;
+
+    let open = |text: &str| {
+        let language = crate::languages::from_name("rust").unwrap();
+        Session::open(language, false, text.as_bytes().to_vec(), &AtomicUsize::new(0)).unwrap().0
+    };
+    let size = open("fn main() {}\n").size();
+
+    // Room for two sessions: opening a third closes the least recently used.
+    let sessions = Sessions::new(SessionLimits {
+        idle_timeout: Duration::from_secs(60),
+        max_memory: size * 2,
+    });
+    let first = sessions.insert(open("fn main() {}\n")).unwrap();
+    let second = sessions.insert(open("fn main() {}\n")).unwrap();
+    assert!(sessions.get(&first).is_some());
+    let third = sessions.insert(open("fn main() {}\n")).unwrap();
+    assert_eq!(sessions.count(), 2);
+    assert!(sessions.get(&second).is_none());
+    assert!(sessions.get(&first).is_some() && sessions.get(&third).is_some());
+    // A session too large to keep at all isn't opened.
+    assert!(sessions.insert(open(&"fn main() {}\n".repeat(10))).is_none());
+
+    let sessions = Sessions::new(SessionLimits {
+        idle_timeout: Duration::ZERO,
+        max_memory: usize::MAX,
+    });
+    let id = sessions.insert(open("fn main() {}\n")).unwrap();
+    assert!(sessions.get(&id).is_none());
+    assert_eq!(sessions.count(), 0);
+
+    // An edit that would take a session's document or its size past a limit is rejected, and
+    // leaves the session as it was.
+    let mut session = open("fn main() {}\n");
+    let grow = [Edit {
+        start: 0,
+        old_end: 0,
+        text: b"fn f() {}\n".repeat(10),
+    }];
+    let flag = AtomicUsize::new(0);
+    assert!(matches!(session.edit(&grow, 100, usize::MAX, &flag), Err(EditError::TooLarge)));
+    assert!(matches!(session.edit(&grow, usize::MAX, size * 2, &flag), Err(EditError::TooLarge)));
+    assert_eq!((session.size(), session.line_count()), (size, 2));
+    assert!(session.edit(&grow, usize::MAX, usize::MAX, &flag).unwrap().is_some());
+    assert_eq!(session.line_count(), 12);
+}
+
+/// Serve the router on a free port, and connect to its WebSocket.
+async fn connect_websocket() -> tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>> {
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let app = router(Duration::from_secs(30), Duration::from_secs(60));
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    let (socket, _) = tokio_tungstenite::connect_async(format!("ws://127.0.0.1:{port}/v1/ws")).await.unwrap();
+    socket
+}
+
+fn ws_message(id: u64, endpoint: &str, request: &[u8], cancel: bool) -> tokio_tungstenite::tungstenite::Message {
+    use crate::daylight_generated::daylight::ws;
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let endpoint = Some(builder.create_string(endpoint));
+    let request = Some(builder.create_vector(request));
+    let message = ws::ClientMessage::create(&mut builder, &ws::ClientMessageArgs { id, endpoint, request, cancel });
+    builder.finish(message, None);
+    tokio_tungstenite::tungstenite::Message::Binary(builder.finished_data().to_vec())
+}
+
+#[tokio::test]
+async fn test_websocket_json() {
+    use futures::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    let mut socket = connect_websocket().await;
+    let requests = [
+        serde_json::json!({"id": 1, "endpoint": "html", "request": {"files": [
+            {"ident": 0, "filename": "a.rs", "contents": "fn a() {}"},
+            {"ident": 1, "filename": "b.py", "contents": "def b(): pass"},
+        ]}}),
+        serde_json::json!({"id": 2, "endpoint": "/v1/spans", "request": {"files": [{"filename": "c.go", "contents": "package c"}]}}),
+        serde_json::json!({"id": 3, "endpoint": "nope", "request": {}}),
+        serde_json::json!({"id": 4, "endpoint": "html", "request": {"files": 7}}),
+    ];
+    for request in &requests {
+        socket.send(Message::Text(request.to_string())).await.unwrap();
+    }
+    socket.send(Message::Text("{".to_string())).await.unwrap();
+
+    // Requests finish in any order, but each one's documents come before its last message.
+    let mut documents: std::collections::HashMap<u64, Vec<serde_json::Value>> = Default::default();
+    let mut errors = std::collections::HashMap::new();
+    while errors.len() < 5 {
+        let Message::Text(text) = socket.next().await.unwrap().unwrap() else {
+            panic!("JSON requests get text messages");
+        };
+        let message: serde_json::Value = serde_json::from_str(&text).unwrap();
+        let id = message["id"].as_u64().unwrap();
+        assert!(!errors.contains_key(&id), "{message}");
+        if message["done"] == true {
+            assert!(message.get("response").is_none());
+            errors.insert(id, message.get("error").and_then(|e| e.as_str()).map(String::from));
+        } else {
+            let response = &message["response"]["documents"];
+            assert_eq!(response.as_array().unwrap().len(), 1);
+            documents.entry(id).or_default().push(response[0].clone());
+        }
+    }
+
+    let mut filenames: Vec<_> = documents[&1].iter().map(|doc| doc["filename"].as_str().unwrap()).collect();
+    filenames.sort();
+    assert_eq!(filenames, ["a.rs", "b.py"]);
+    assert!(documents[&1].iter().all(|doc| doc["error_code"] == "NoError" && !doc["lines"].as_array().unwrap().is_empty()));
+    assert_eq!(documents[&2].len(), 1);
+    assert_eq!(documents[&2][0]["language"], "Go");
+    assert!(!documents[&2][0]["spans"].as_array().unwrap().is_empty());
+    assert_eq!((errors[&1].as_deref(), errors[&2].as_deref()), (None, None));
+    assert_eq!(errors[&3].as_deref(), Some("Unknown endpoint: nope"));
+    assert!(errors[&4].as_deref().unwrap().starts_with("Invalid JSON request"));
+    // A message that can't be read at all is answered with an ID of zero.
+    assert!(errors[&0].as_deref().unwrap().starts_with("Invalid JSON request"));
+    assert!(!documents.contains_key(&3) && !documents.contains_key(&4));
+}
+
+#[tokio::test]
+async fn test_websocket_flatbuffers() {
+    use crate::daylight_generated::daylight::ws;
+    use futures::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    let (mut socket, mut messages) = connect_websocket().await.split();
+    let mut next_message = async || {
+        let Message::Binary(bytes) = messages.next().await.unwrap().unwrap() else {
+            panic!("Flatbuffers requests get binary messages");
+        };
+        bytes
+    };
+
+    let request = build_request(vec![(5, "main.rs", "fn main() {}", common::Language::Unspecified)]);
+    socket.send(ws_message(9, "html", &request, false)).await.unwrap();
+    let bytes = next_message().await;
+    let message = flatbuffers::root::<ws::ServerMessage>(&bytes).unwrap();
+    assert_eq!((message.id(), message.done()), (9, false));
+    let response = flatbuffers::root::<html::Response>(message.response().unwrap().bytes()).unwrap();
+    let document = response.documents().unwrap().get(0);
+    assert_eq!((document.ident(), document.language()), (5, common::Language::Rust));
+    assert!(document.lines().unwrap().get(0).contains("<span"));
+    let bytes = next_message().await;
+    let message = flatbuffers::root::<ws::ServerMessage>(&bytes).unwrap();
+    assert_eq!((message.id(), message.done(), message.error()), (9, true, None));
+    assert!(message.response().is_none());
+}
+
+#[tokio::test]
+async fn test_websocket_cancel() {
+    use crate::daylight_generated::daylight::ws;
+    use futures::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    let (mut socket, mut messages) = connect_websocket().await.split();
+    let mut next_message = async || {
+        let Message::Binary(bytes) = messages.next().await.unwrap().unwrap() else {
+            panic!("Flatbuffers requests get binary messages");
+        };
+        bytes
+    };
+
+    // A file that takes a while to highlight is still in progress when the cancellation arrives.
+    let contents = "let x = [1, 'two', `${3}`];\n".repeat(100_000);
+    let request = build_request(vec![(0, "big.js", &contents, common::Language::Unspecified)]);
+    socket.send(ws_message(10, "html", &request, false)).await.unwrap();
+    socket.send(ws_message(10, "", &[], true)).await.unwrap();
+    // Cancelling a request that doesn't exist does nothing.
+    socket.send(ws_message(11, "", &[], true)).await.unwrap();
+    let bytes = next_message().await;
+    let message = flatbuffers::root::<ws::ServerMessage>(&bytes).unwrap();
+    assert_eq!((message.id(), message.done(), message.error()), (10, true, Some("Cancelled")));
+
+    // The ID is free to use again. Reusing it while it's in flight is rejected without ending
+    // the request that has it.
+    socket.send(ws_message(10, "spans", &request, false)).await.unwrap();
+    socket.send(ws_message(10, "spans", &request, false)).await.unwrap();
+    let bytes = next_message().await;
+    let message = flatbuffers::root::<ws::ServerMessage>(&bytes).unwrap();
+    assert_eq!((message.id(), message.done()), (10, false));
+    assert_eq!(message.error(), Some("Request ID already in use: 10"));
+    socket.send(ws_message(10, "", &[], true)).await.unwrap();
+    let bytes = next_message().await;
+    let message = flatbuffers::root::<ws::ServerMessage>(&bytes).unwrap();
+    assert_eq!((message.id(), message.done(), message.error()), (10, true, Some("Cancelled")));
+}
+
+#[tokio::test]
+async fn test_websocket_reuse_after_cancel() {
+    use futures::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    let mut socket = connect_websocket().await;
+    let files = serde_json::json!([{"filename": "main.rs", "contents": "fn main() {}"}]);
+    // Each time, the first request may or may not have finished by the time it's cancelled, but
+    // either way its end mustn't take the second request down with it.
+    for _ in 0..20 {
+        let html = serde_json::json!({"id": 5, "endpoint": "html", "request": {"files": files}});
+        let spans = serde_json::json!({"id": 5, "endpoint": "spans", "request": {"files": files}});
+        socket.send(Message::Text(html.to_string())).await.unwrap();
+        socket.send(Message::Text(serde_json::json!({"id": 5, "cancel": true}).to_string())).await.unwrap();
+        socket.send(Message::Text(spans.to_string())).await.unwrap();
+
+        let (mut spans, mut done) = (0, vec![]);
+        while done.len() < 2 {
+            let Message::Text(text) = socket.next().await.unwrap().unwrap() else {
+                panic!("JSON requests get text messages");
+            };
+            let message: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(message["id"], 5);
+            if message["done"] == true {
+                done.push(message.get("error").cloned());
+            } else if message["response"]["documents"][0].get("spans").is_some() {
+                spans += 1;
+            }
+        }
+        assert_eq!(spans, 1);
+        assert!(done.contains(&None), "{done:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_websocket_max_in_flight() {
+    use crate::daylight_generated::daylight::ws;
+    use futures::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    let (mut socket, mut messages) = connect_websocket().await.split();
+    // These are small enough to send quickly, but still take a while to highlight.
+    let contents = "let x = [1, 'two', `${3}`];\n".repeat(5_000);
+    let request = build_request(vec![(0, "slow.js", &contents, common::Language::Unspecified)]);
+    for id in 0..crate::websocket::MAX_IN_FLIGHT as u64 {
+        socket.send(ws_message(100 + id, "html", &request, false)).await.unwrap();
+    }
+    socket.send(ws_message(200, "html", &request, false)).await.unwrap();
+    let error = loop {
+        let Message::Binary(bytes) = messages.next().await.unwrap().unwrap() else {
+            panic!("Flatbuffers requests get binary messages");
+        };
+        let message = flatbuffers::root::<ws::ServerMessage>(&bytes).unwrap();
+        if message.id() == 200 {
+            assert!(message.done());
+            break message.error().map(String::from);
+        }
+    };
+    assert_eq!(error.as_deref(), Some("Too many requests in flight (max supported: 32)"));
+    for id in 0..crate::websocket::MAX_IN_FLIGHT as u64 {
+        socket.send(ws_message(100 + id, "", &[], true)).await.unwrap();
+    }
+}
+
+#[test]
+fn test_streaming_negotiation() {
+    use crate::encoding::Streaming;
+    let streaming = |accept: &'static str| Streaming::of_request(&headers(&[(http::header::ACCEPT, accept)]));
+    assert_eq!(Streaming::of_request(&HeaderMap::new()), None);
+    assert_eq!(streaming("application/x-ndjson"), Some(Streaming::Ndjson));
+    assert_eq!(streaming("application/json, application/x-ndjson"), None);
+    assert_eq!(streaming("application/json;q=0.4, text/event-stream;q=0.5"), Some(Streaming::EventStream));
+    assert_eq!(streaming("text/html, application/x-flatbuffers-stream"), Some(Streaming::Flatbuffers));
+    assert_eq!(streaming("application/x-ndjson;q=0"), None);
+}
+
+#[tokio::test]
+async fn test_streamed_json() {
+    let request = r#"{"files": [
+        {"ident": 0, "filename": "a.rs", "contents": "fn a() {}"},
+        {"ident": 1, "filename": "b.py", "contents": "def b(): pass"},
+        {"ident": 2, "filename": "mystery", "contents": "???"}
+    ]}"#;
+    for (accept, prefix, terminator) in [("application/x-ndjson", "", "\n"), ("text/event-stream", "data: ", "\n\n")] {
+        let response = generic_handler::<HtmlProcessor>(
+            State(Server {
+                default_per_file_timeout: Duration::from_secs(30),
+                max_per_file_timeout: Duration::from_secs(60),
+            }),
+            headers(&[(http::header::CONTENT_TYPE, "application/json"), (http::header::ACCEPT, accept)]),
+            Bytes::from(request),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.headers()[http::header::CONTENT_TYPE], accept);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        let frames: Vec<serde_json::Value> = body
+            .split_terminator(terminator)
+            .map(|frame| serde_json::from_str(frame.strip_prefix(prefix).unwrap()).unwrap())
+            .collect();
+        assert_eq!(frames.len(), 4, "{body}");
+
+        let mut idents: Vec<_> = frames[..3]
+            .iter()
+            .map(|frame| {
+                let documents = frame["response"]["documents"].as_array().unwrap();
+                assert_eq!(documents.len(), 1);
+                documents[0]["ident"].as_u64().unwrap()
+            })
+            .collect();
+        idents.sort();
+        assert_eq!(idents, [0, 1, 2]);
+        assert_eq!(
+            frames[3],
+            serde_json::json!({"trailer": {"documents": 3, "succeeded": 2, "failed": 1, "timed_out": 0}})
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_streamed_flatbuffers() {
+    use crate::daylight_generated::daylight::stream;
+    use futures::StreamExt;
+
+    // The small file is written while the large one is still being highlighted.
+    let contents = "let x = [1, 'two', `${3}`];\n".repeat(20_000);
+    let request_bytes = build_request(vec![
+        (0, "big.js", &contents, common::Language::Unspecified),
+        (1, "small.rs", "fn main() {}", common::Language::Unspecified),
+    ]);
+    let response = generic_handler::<HtmlProcessor>(
+        State(Server {
+            default_per_file_timeout: Duration::from_secs(30),
+            max_per_file_timeout: Duration::from_secs(60),
+        }),
+        headers(&[(http::header::ACCEPT, "application/x-flatbuffers-stream")]),
+        Bytes::from(request_bytes),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.headers()[http::header::CONTENT_TYPE], "application/x-flatbuffers-stream");
+
+    let mut body = response.into_body().into_data_stream();
+    let mut buffer = vec![];
+    let mut frames = vec![];
+    while let Some(chunk) = body.next().await {
+        buffer.extend_from_slice(&chunk.unwrap());
+        while buffer.len() >= 4 {
+            let len = u32::from_le_bytes(buffer[..4].try_into().unwrap()) as usize;
+            if buffer.len() < 4 + len {
+                break;
+            }
+            frames.push(buffer.drain(..4 + len).collect::<Vec<u8>>());
+        }
+    }
+    assert!(buffer.is_empty());
+    assert_eq!(frames.len(), 3);
+
+    let document = |frame: &[u8]| {
+        let frame = flatbuffers::size_prefixed_root::<stream::Frame>(frame).unwrap();
+        assert!(frame.trailer().is_none());
+        let response = flatbuffers::root::<html::Response>(frame.response().unwrap().bytes()).unwrap();
+        let documents = response.documents().unwrap();
+        assert_eq!(documents.len(), 1);
+        let document = documents.get(0);
+        assert_eq!(document.error_code(), common::ErrorCode::NoError);
+        (document.ident(), document.lines().unwrap().len())
+    };
+    assert_eq!(document(&frames[0]), (1, 1));
+    assert_eq!(document(&frames[1]), (0, 20_000));
+    let trailer = flatbuffers::size_prefixed_root::<stream::Frame>(&frames[2]).unwrap();
+    assert!(trailer.response().is_none());
+    let trailer = trailer.trailer().unwrap();
+    assert_eq!((trailer.documents(), trailer.succeeded(), trailer.failed(), trailer.timed_out()), (2, 2, 0, 0));
+}
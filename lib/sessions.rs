@@ -0,0 +1,518 @@
+//! Incremental highlighting sessions, for editors that would otherwise send a whole document on
+//! every keystroke. A session keeps a document, its syntax tree and the highlights of each of its
+//! lines. Edits are applied to the tree as well as the text, so that re-parsing reuses what
+//! didn't change and the tree's changed ranges say which lines need highlighting again.
+//!
+//! tree-sitter-highlight always starts from the top of a file, so highlighting stops after the
+//! last affected line rather than running to the end.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use tree_sitter::{InputEdit, Point, Tree};
+use tree_sitter_highlight as ts;
+
+use crate::daylight_generated::daylight::{common, session};
+use crate::languages;
+use crate::processors::{highlight, reparse};
+use crate::thread_locals::ThreadState;
+
+/// Roughly how many bytes a syntax tree takes for each of its nodes.
+const NODE_SIZE: usize = 32;
+
+/// Limits on the sessions a server keeps open.
+#[derive(Clone, Copy, Debug)]
+pub struct SessionLimits {
+    /// How long a session may go unused before it's closed.
+    pub idle_timeout: Duration,
+    /// Roughly how many bytes all sessions together may take. The least recently used sessions
+    /// are closed to stay under it.
+    pub max_memory: usize,
+}
+
+impl Default for SessionLimits {
+    fn default() -> Self {
+        SessionLimits {
+            idle_timeout: Duration::from_secs(10 * 60),
+            max_memory: 512 * 1024 * 1024,
+        }
+    }
+}
+
+static SESSIONS: LazyLock<Sessions> = LazyLock::new(|| Sessions::new(SessionLimits::default()));
+
+/// The server's sessions.
+pub fn sessions() -> &'static Sessions {
+    &SESSIONS
+}
+
+/// Set the limits on the server's sessions.
+pub fn set_limits(limits: SessionLimits) {
+    SESSIONS.lock().limits = limits;
+}
+
+/// A highlighted range within a line, in byte columns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineSpan {
+    /// An index into `ALL_HIGHLIGHT_NAMES`.
+    pub index: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Lines `start_line..old_end_line` of the previous version of a document were replaced by
+/// `lines`, the highlights of each new line.
+#[derive(Debug)]
+pub struct Change {
+    pub start_line: usize,
+    pub old_end_line: usize,
+    pub lines: Vec<Vec<LineSpan>>,
+}
+
+/// An edit, replacing the bytes in `start..old_end` with `text`.
+#[derive(Debug)]
+pub struct Edit {
+    pub start: usize,
+    pub old_end: usize,
+    pub text: Vec<u8>,
+}
+
+/// Why edits couldn't be applied.
+#[derive(Debug)]
+pub enum EditError {
+    /// The edits don't fit the document.
+    Invalid(String),
+    /// The edited document, or the session as a whole, would be over its limit.
+    TooLarge,
+    Failed(ts::Error),
+}
+
+/// What opening or editing a session gives back.
+pub struct SessionUpdate {
+    pub session_id: String,
+    pub language: common::Language,
+    pub changes: Vec<Change>,
+    pub line_count: usize,
+    pub error_code: common::ErrorCode,
+}
+
+impl SessionUpdate {
+    pub fn to_flatbuffers(&self) -> Vec<u8> {
+        ThreadState::build_flatbuffers(|builder| {
+            builder.reset();
+            let session_id = Some(builder.create_string(&self.session_id));
+            let changes: Vec<_> = self
+                .changes
+                .iter()
+                .map(|change| {
+                    let lines: Vec<_> = change
+                        .lines
+                        .iter()
+                        .map(|line| {
+                            let spans: Vec<_> = line
+                                .iter()
+                                .map(|span| {
+                                    session::Span::create(
+                                        builder,
+                                        &session::SpanArgs {
+                                            index: span.index as u16,
+                                            start: span.start as u32,
+                                            end: span.end as u32,
+                                        },
+                                    )
+                                })
+                                .collect();
+                            let spans = Some(builder.create_vector(&spans));
+                            session::Line::create(builder, &session::LineArgs { spans })
+                        })
+                        .collect();
+                    let lines = Some(builder.create_vector(&lines));
+                    session::Change::create(
+                        builder,
+                        &session::ChangeArgs {
+                            start_line: change.start_line as u32,
+                            old_end_line: change.old_end_line as u32,
+                            lines,
+                        },
+                    )
+                })
+                .collect();
+            let changes = Some(builder.create_vector(&changes));
+            let response = session::Response::create(
+                builder,
+                &session::ResponseArgs {
+                    session_id,
+                    language: self.language,
+                    changes,
+                    line_count: self.line_count as u32,
+                    error_code: self.error_code,
+                },
+            );
+            builder.finish(response, None);
+            builder.finished_data().to_vec()
+        })
+    }
+}
+
+/// A document being edited.
+pub struct Session {
+    language: languages::SharedConfig,
+    include_injections: bool,
+    text: Vec<u8>,
+    tree: Tree,
+    /// The highlights of each line.
+    lines: Vec<Vec<LineSpan>>,
+}
+
+impl Session {
+    /// Parse and highlight a document, returning the session and a change with all its lines.
+    pub fn open(
+        language: languages::SharedConfig,
+        include_injections: bool,
+        text: Vec<u8>,
+        cancellation_flag: &AtomicUsize,
+    ) -> Result<(Session, Change), ts::Error> {
+        let tree = ThreadState::parse_with_tree_sitter(|parser| reparse(parser, language, &text, None, cancellation_flag))?;
+        let starts = line_starts(&text);
+        let lines = highlight_lines(language, include_injections, &text, &starts, 0..starts.len(), cancellation_flag)?;
+        let change = Change {
+            start_line: 0,
+            old_end_line: 0,
+            lines: lines.clone(),
+        };
+        let session = Session {
+            language,
+            include_injections,
+            text,
+            tree,
+            lines,
+        };
+        Ok((session, change))
+    }
+
+    pub fn language(&self) -> languages::SharedConfig {
+        self.language
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Roughly how many bytes the session takes: its text, its tree and its highlights.
+    pub fn size(&self) -> usize {
+        size(&self.text, &self.tree, self.lines.len(), span_count(&self.lines))
+    }
+
+    /// Apply edits, in order, and re-highlight the lines they affect. The change covers the
+    /// lines whose highlights changed, and is `None` if none did. Edits that would leave the
+    /// document longer than `max_len` bytes, or the session larger than `max_size`, are
+    /// rejected. If anything fails, the session is left as it was.
+    pub fn edit(
+        &mut self,
+        edits: &[Edit],
+        max_len: usize,
+        max_size: usize,
+        cancellation_flag: &AtomicUsize,
+    ) -> Result<Option<Change>, EditError> {
+        let mut text = self.text.clone();
+        let mut tree = self.tree.clone();
+        // The bytes each edit inserted, where they are after all the edits.
+        let mut edited: Vec<Range<usize>> = vec![];
+        for edit in edits {
+            if edit.start > edit.old_end || edit.old_end > text.len() {
+                return Err(EditError::Invalid(format!(
+                    "edit of bytes {}..{} doesn't fit a document of {} bytes",
+                    edit.start,
+                    edit.old_end,
+                    text.len()
+                )));
+            }
+            let new_end = edit.start + edit.text.len();
+            let start_position = point(&text, edit.start);
+            tree.edit(&InputEdit {
+                start_byte: edit.start,
+                old_end_byte: edit.old_end,
+                new_end_byte: new_end,
+                start_position,
+                old_end_position: point(&text, edit.old_end),
+                new_end_position: advance(start_position, &edit.text),
+            });
+            text.splice(edit.start..edit.old_end, edit.text.iter().copied());
+            let moved = |offset: usize| match offset {
+                offset if offset <= edit.start => offset,
+                offset if offset >= edit.old_end => offset - edit.old_end + new_end,
+                _ => new_end,
+            };
+            for range in &mut edited {
+                *range = moved(range.start)..moved(range.end);
+            }
+            edited.push(edit.start..new_end);
+        }
+        if text.len() > max_len {
+            return Err(EditError::TooLarge);
+        }
+
+        let new_tree = ThreadState::parse_with_tree_sitter(|parser| {
+            reparse(parser, self.language, &text, Some(&tree), cancellation_flag)
+        })
+        .map_err(EditError::Failed)?;
+        let starts = line_starts(&text);
+        let line = |offset: usize| starts.partition_point(|&start| start <= offset) - 1;
+        let changed = tree.changed_ranges(&new_tree).map(|range| range.start_byte..range.end_byte);
+        let Some((first, last)) = edited
+            .into_iter()
+            .chain(changed)
+            .map(|range| (line(range.start), line(range.end)))
+            .reduce(|(a, b), (c, d)| (a.min(c), b.max(d)))
+        else {
+            return Ok(None);
+        };
+
+        // The lines after the affected ones haven't changed, so they're counted from the end.
+        let unchanged_after = starts.len() - (last + 1);
+        let old_end = self.lines.len().saturating_sub(unchanged_after).max(first);
+        let lines = highlight_lines(
+            self.language,
+            self.include_injections,
+            &text,
+            &starts,
+            first..last + 1,
+            cancellation_flag,
+        )
+        .map_err(EditError::Failed)?;
+
+        // Editors already have the text, so lines whose highlights are the same are left out.
+        let old = &self.lines[first..old_end];
+        let same_before = old.iter().zip(&lines).take_while(|(a, b)| a == b).count();
+        let same_after = old[same_before..]
+            .iter()
+            .rev()
+            .zip(lines[same_before..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let change = Change {
+            start_line: first + same_before,
+            old_end_line: old_end - same_after,
+            lines: lines[same_before..lines.len() - same_after].to_vec(),
+        };
+        let line_count = self.lines.len() - old.len() + lines.len();
+        let spans = span_count(&self.lines) - span_count(old) + span_count(&lines);
+        if size(&text, &new_tree, line_count, spans) > max_size {
+            return Err(EditError::TooLarge);
+        }
+
+        self.text = text;
+        self.tree = new_tree;
+        self.lines.splice(first..old_end, lines);
+        let unchanged = change.lines.is_empty() && change.start_line == change.old_end_line;
+        Ok((!unchanged).then_some(change))
+    }
+}
+
+/// Roughly how many bytes a session with these parts takes.
+fn size(text: &[u8], tree: &Tree, lines: usize, spans: usize) -> usize {
+    text.len()
+        + tree.root_node().descendant_count() * NODE_SIZE
+        + lines * size_of::<Vec<LineSpan>>()
+        + spans * size_of::<LineSpan>()
+}
+
+fn span_count(lines: &[Vec<LineSpan>]) -> usize {
+    lines.iter().map(Vec::len).sum()
+}
+
+/// The byte offset at which each line starts.
+fn line_starts(text: &[u8]) -> Vec<usize> {
+    let newlines = text.iter().enumerate().filter(|(_, b)| **b == b'\n');
+    std::iter::once(0).chain(newlines.map(|(i, _)| i + 1)).collect()
+}
+
+/// The row and byte column of an offset.
+fn point(text: &[u8], offset: usize) -> Point {
+    advance(Point::new(0, 0), &text[..offset])
+}
+
+/// Where a point ends up after some text.
+fn advance(point: Point, text: &[u8]) -> Point {
+    match text.iter().rposition(|b| *b == b'\n') {
+        Some(last) => Point::new(point.row + text.iter().filter(|b| **b == b'\n').count(), text.len() - last - 1),
+        None => Point::new(point.row, point.column + text.len()),
+    }
+}
+
+/// Highlight some of a document's lines. Line endings aren't part of any span.
+fn highlight_lines(
+    language: languages::SharedConfig,
+    include_injections: bool,
+    text: &[u8],
+    starts: &[usize],
+    lines: Range<usize>,
+    cancellation_flag: &AtomicUsize,
+) -> Result<Vec<Vec<LineSpan>>, ts::Error> {
+    let start_byte = starts[lines.start];
+    let end_byte = starts.get(lines.end).copied().unwrap_or(text.len());
+    let mut highlighted: Vec<Vec<LineSpan>> = vec![vec![]; lines.len()];
+    ThreadState::highlight_with_tree_sitter(|highlighter| {
+        let events = highlight(highlighter, language, text, include_injections, cancellation_flag)?;
+        let mut stack = vec![];
+        for event in events {
+            match event? {
+                ts::HighlightEvent::HighlightStart(highlight) => stack.push(highlight.0),
+                ts::HighlightEvent::HighlightEnd => {
+                    stack.pop();
+                }
+                // Nothing after the last line matters, so the rest of the file isn't highlighted.
+                ts::HighlightEvent::Source { start, .. } if start >= end_byte => break,
+                ts::HighlightEvent::Source { start, end } => {
+                    let Some(&index) = stack.last() else {
+                        continue;
+                    };
+                    let mut offset = start.max(start_byte);
+                    while offset < end.min(end_byte) {
+                        let line = starts.partition_point(|&s| s <= offset) - 1;
+                        let next_line = starts.get(line + 1).copied().unwrap_or(text.len());
+                        let content_end = if line + 1 < starts.len() { next_line - 1 } else { next_line };
+                        let stop = end.min(content_end);
+                        let spans = &mut highlighted[line - lines.start];
+                        match spans.last_mut() {
+                            Some(last) if last.index == index && last.end == offset - starts[line] => {
+                                last.end = stop - starts[line];
+                            }
+                            _ if stop > offset => spans.push(LineSpan {
+                                index,
+                                start: offset - starts[line],
+                                end: stop - starts[line],
+                            }),
+                            _ => {}
+                        }
+                        offset = next_line.min(end);
+                    }
+                }
+            }
+        }
+        Ok(highlighted)
+    })
+}
+
+/// Open sessions, closed when they go unused for too long or to keep memory under a limit.
+pub struct Sessions {
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    limits: SessionLimits,
+    entries: HashMap<String, Entry>,
+}
+
+struct Entry {
+    language: languages::SharedConfig,
+    session: Arc<Mutex<Session>>,
+    last_used: Instant,
+    size: usize,
+}
+
+impl Inner {
+    /// Close the sessions that have gone unused for too long, then the least recently used ones
+    /// until memory is under the limit. The session being used is kept either way.
+    fn evict(&mut self, keep: &str) {
+        let now = Instant::now();
+        let idle_timeout = self.limits.idle_timeout;
+        self.entries
+            .retain(|id, entry| id == keep || now.duration_since(entry.last_used) < idle_timeout);
+        while self.entries.values().map(|entry| entry.size).sum::<usize>() > self.limits.max_memory {
+            let oldest = self
+                .entries
+                .iter()
+                .filter(|(id, _)| *id != keep)
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(id, _)| id.clone());
+            match oldest {
+                Some(id) => self.entries.remove(&id),
+                None => break,
+            };
+        }
+    }
+}
+
+impl Sessions {
+    pub fn new(limits: SessionLimits) -> Self {
+        Sessions {
+            inner: Mutex::new(Inner {
+                limits,
+                entries: HashMap::new(),
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn limits(&self) -> SessionLimits {
+        self.lock().limits
+    }
+
+    /// Keep a new session, returning its ID, or `None` if it's too large to keep even alone.
+    pub fn insert(&self, session: Session) -> Option<String> {
+        let mut inner = self.lock();
+        let size = session.size();
+        if size > inner.limits.max_memory {
+            return None;
+        }
+        let id = new_id();
+        let entry = Entry {
+            language: session.language(),
+            session: Arc::new(Mutex::new(session)),
+            last_used: Instant::now(),
+            size,
+        };
+        inner.entries.insert(id.clone(), entry);
+        inner.evict(&id);
+        Some(id)
+    }
+
+    /// Find a session by ID, marking it as used.
+    pub fn get(&self, id: &str) -> Option<(languages::SharedConfig, Arc<Mutex<Session>>)> {
+        let mut inner = self.lock();
+        inner.evict(id);
+        let idle_timeout = inner.limits.idle_timeout;
+        let entry = inner.entries.get_mut(id)?;
+        if entry.last_used.elapsed() >= idle_timeout {
+            inner.entries.remove(id);
+            return None;
+        }
+        entry.last_used = Instant::now();
+        Some((entry.language, entry.session.clone()))
+    }
+
+    /// Record a session's size after an edit, closing others if it's grown past the limit. Edits
+    /// never take a session past the limit on its own.
+    pub fn resize(&self, id: &str, size: usize) {
+        let mut inner = self.lock();
+        if let Some(entry) = inner.entries.get_mut(id) {
+            entry.size = size;
+            inner.evict(id);
+        }
+    }
+
+    /// Close a session, returning whether it was open.
+    pub fn remove(&self, id: &str) -> bool {
+        self.lock().entries.remove(id).is_some()
+    }
+
+    /// How many sessions are open.
+    pub fn count(&self) -> usize {
+        self.lock().entries.len()
+    }
+}
+
+/// A session ID that's hard to guess: 128 bits from the operating system's random source, since
+/// knowing the ID is all it takes to edit a session.
+fn new_id() -> String {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).expect("The operating system's random source failed");
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,114 @@
+//! Streamed batch responses, for clients that would rather not wait on a batch's slowest file.
+//!
+//! Asked for with `Accept`, a streamed response writes each document in a frame of its own as
+//! soon as it's done, so nothing finished is held back and the batch is never all in memory at
+//! once. Each frame holds the endpoint's response with a single document, and a last frame holds
+//! a trailer counting how the batch went. See `daylight.stream` in the schema, and `Streaming`
+//! for the formats.
+
+use std::future::Future;
+
+use axum::body::{Body, Bytes};
+use axum::response::IntoResponse;
+use flatbuffers::FlatBufferBuilder;
+use futures::stream::{self, FuturesUnordered};
+use futures::StreamExt;
+use http::{header, StatusCode};
+
+use crate::daylight_generated::daylight::{common, stream as fb};
+use crate::encoding::{Encoding, Streaming};
+use crate::errors::FatalError;
+use crate::processors::{Outcome, Processor};
+use crate::server::document_flatbuffers;
+use crate::wire::stream::{Frame, Trailer};
+
+impl Trailer {
+    fn count<T>(&mut self, outcome: &Outcome<T>) {
+        self.documents += 1;
+        match outcome.error_code() {
+            common::ErrorCode::NoError => self.succeeded += 1,
+            common::ErrorCode::TimedOut => {
+                self.failed += 1;
+                self.timed_out += 1;
+            }
+            _ => self.failed += 1,
+        }
+    }
+}
+
+/// Build a streamed response, writing each document's frame as soon as its task finishes.
+pub(crate) fn respond<P: Processor>(
+    streaming: Streaming,
+    tasks: FuturesUnordered<impl Future<Output = Outcome<P::Output>> + Send + 'static>,
+) -> axum::response::Response {
+    let frames = stream::unfold(Some((tasks, Trailer::default())), move |state| async move {
+        let (mut tasks, mut trailer) = state?;
+        match tasks.next().await {
+            Some(outcome) => {
+                trailer.count(&outcome);
+                Some((document_frame::<P>(streaming, outcome).await, Some((tasks, trailer))))
+            }
+            None => Some((Ok(frame(streaming, None, Some(trailer))), None)),
+        }
+    });
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, streaming.media_type())],
+        Body::from_stream(frames),
+    )
+        .into_response()
+}
+
+async fn document_frame<P: Processor>(streaming: Streaming, outcome: Outcome<P::Output>) -> Result<Bytes, FatalError> {
+    let response = match streaming {
+        Streaming::Flatbuffers => Response::Flatbuffers(document_flatbuffers::<P>(outcome).await?),
+        _ => serde_json::to_value(P::WireResponse::from(vec![outcome]))
+            .map(Response::Json)
+            .map_err(|e| FatalError::EncodeError(Encoding::Json, e.to_string()))?,
+    };
+    Ok(frame(streaming, Some(response), None))
+}
+
+/// A single-document response, in the encoding of the stream it's going in.
+enum Response {
+    Flatbuffers(Bytes),
+    Json(serde_json::Value),
+}
+
+/// Write a frame: a single-document response or, at the end, the trailer.
+fn frame(streaming: Streaming, response: Option<Response>, trailer: Option<Trailer>) -> Bytes {
+    match streaming {
+        Streaming::Flatbuffers => {
+            let mut builder = FlatBufferBuilder::new();
+            let response = match response {
+                Some(Response::Flatbuffers(bytes)) => Some(builder.create_vector(&bytes)),
+                _ => None,
+            };
+            let trailer = trailer.map(|trailer| {
+                fb::Trailer::create(
+                    &mut builder,
+                    &fb::TrailerArgs {
+                        documents: trailer.documents,
+                        succeeded: trailer.succeeded,
+                        failed: trailer.failed,
+                        timed_out: trailer.timed_out,
+                    },
+                )
+            });
+            let frame = fb::Frame::create(&mut builder, &fb::FrameArgs { response, trailer });
+            builder.finish_size_prefixed(frame, None);
+            Bytes::copy_from_slice(builder.finished_data())
+        }
+        Streaming::Ndjson | Streaming::EventStream => {
+            let response = match response {
+                Some(Response::Json(value)) => Some(value),
+                _ => None,
+            };
+            let json = serde_json::to_string(&Frame { response, trailer }).expect("JSON values always serialize");
+            match streaming {
+                Streaming::EventStream => Bytes::from(format!("data: {json}\n\n")),
+                _ => Bytes::from(json + "\n"),
+            }
+        }
+    }
+}
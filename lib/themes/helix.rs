@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+
+use toml::{Table, Value};
+
+use super::{Color, Style, Theme, ThemeError};
+use crate::languages::ALL_HIGHLIGHT_NAMES;
+
+/// Helix scopes that cover a capture under a different name. Everything else is looked up
+/// under its own name, since Helix scopes follow tree-sitter's capture naming.
+const ALIASES: &[(&str, &[&str])] = &[
+    ("comment.documentation", &["comment.block.documentation", "comment.line.documentation"]),
+    ("constant.macro", &["constant.builtin"]),
+    ("delimiter", &["punctuation.delimiter"]),
+    ("escape", &["constant.character.escape"]),
+    ("function.special", &["function.macro"]),
+    ("include", &["keyword.control.import", "keyword.directive"]),
+    ("module", &["namespace"]),
+    ("number", &["constant.numeric"]),
+    ("property", &["variable.other.member"]),
+    ("string.escape", &["constant.character.escape"]),
+    ("string.special.key", &["variable.other.member"]),
+    ("string.special.regex", &["string.regexp"]),
+    ("tag.error", &["error"]),
+];
+
+/// Parse a Helix theme: a TOML table of scopes, each either a color or a table of
+/// `fg`, `bg` and `modifiers`, plus an optional `[palette]` of named colors.
+pub(super) fn parse(name: &str, source: &str) -> Result<Theme, ThemeError> {
+    let table: Table = source.parse()?;
+    let palette: BTreeMap<&str, &str> = table
+        .get("palette")
+        .and_then(Value::as_table)
+        .map(|p| p.iter().filter_map(|(k, v)| Some((k.as_str(), v.as_str()?))).collect())
+        .unwrap_or_default();
+    let color = |value: &Value| {
+        let value = value.as_str()?;
+        Color::parse(palette.get(value).copied().unwrap_or(value))
+    };
+    let style = |value: &Value| match value {
+        Value::String(_) => Style {
+            foreground: color(value),
+            ..Default::default()
+        },
+        Value::Table(t) => {
+            let modifiers = t
+                .get("modifiers")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str);
+            let mut style = Style {
+                foreground: t.get("fg").and_then(color),
+                background: t.get("bg").and_then(color),
+                ..Default::default()
+            }
+            .with_modifiers(modifiers);
+            style.underline |= t.contains_key("underline");
+            style
+        }
+        _ => Style::default(),
+    };
+
+    let rules = ALL_HIGHLIGHT_NAMES.iter().filter_map(|capture| {
+        let aliases = ALIASES
+            .iter()
+            .find(|(c, _)| c == capture)
+            .map(|(_, a)| *a)
+            .unwrap_or_default();
+        std::iter::once(*capture)
+            .chain(aliases.iter().copied())
+            .find_map(|scope| table.get(scope))
+            .map(|value| (*capture, style(value)))
+    });
+    let foreground = table.get("ui.text").map(style).and_then(|s| s.foreground);
+    let background = table.get("ui.background").map(style).and_then(|s| s.background);
+    Ok(Theme::new(name, foreground, background, rules.collect::<Vec<_>>()))
+}
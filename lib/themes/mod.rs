@@ -0,0 +1,403 @@
+mod helix;
+mod textmate;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::{LazyLock, RwLock};
+
+use thiserror::Error;
+
+use crate::classes::ClassNames;
+use crate::languages::ALL_HIGHLIGHT_NAMES;
+
+/// The theme used when a request doesn't name one.
+pub const DEFAULT_THEME: &str = "daylight";
+
+/// Layout for HTML rendered as a full document: a grid whose rows are lines, each led by a
+/// line number. These are the same in every theme.
+pub const CODE_CSS: &str = "display:grid;grid-template-columns:auto 1fr";
+pub const LINE_CSS: &str = "display:contents";
+pub const LINE_NUMBER_CSS: &str =
+    "color:inherit;opacity:0.5;padding-right:1em;text-align:right;text-decoration:none;user-select:none";
+pub const EMPHASIZED_CSS: &str = "background-color:rgba(250,200,40,0.25)";
+
+/// A 24-bit RGB color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const fn hex(rgb: u32) -> Self {
+        Self {
+            r: (rgb >> 16) as u8,
+            g: (rgb >> 8) as u8,
+            b: rgb as u8,
+        }
+    }
+
+    /// Parse a `#rrggbb` or `#rgb` string. A trailing alpha channel (`#rrggbbaa`) is ignored.
+    pub fn parse(s: &str) -> Option<Self> {
+        let digits = s.trim().strip_prefix('#')?;
+        if !digits.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize, width: usize| u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).ok();
+        match digits.len() {
+            3 | 4 => Some(Self {
+                r: channel(0, 1)? * 17,
+                g: channel(1, 1)? * 17,
+                b: channel(2, 1)? * 17,
+            }),
+            6 | 8 => Some(Self {
+                r: channel(0, 2)?,
+                g: channel(1, 2)?,
+                b: channel(2, 2)?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// How a single highlight capture should be drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Style {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl Style {
+    pub const fn fg(rgb: u32) -> Self {
+        Self {
+            foreground: Some(Color::hex(rgb)),
+            background: None,
+            bold: false,
+            italic: false,
+            underline: false,
+        }
+    }
+
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Apply a space- or comma-separated list of modifiers, as found in
+    /// TextMate's `fontStyle` and Helix's `modifiers`.
+    fn with_modifiers<'a>(mut self, modifiers: impl IntoIterator<Item = &'a str>) -> Self {
+        for modifier in modifiers {
+            match modifier {
+                "bold" => self.bold = true,
+                "italic" => self.italic = true,
+                "underline" | "underlined" => self.underline = true,
+                _ => {}
+            }
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// CSS property/value pairs that draw this style.
+    pub fn declarations(&self) -> Vec<(&'static str, String)> {
+        let mut out = vec![];
+        if let Some(fg) = self.foreground {
+            out.push(("color", fg.to_string()));
+        }
+        if let Some(bg) = self.background {
+            out.push(("background-color", bg.to_string()));
+        }
+        if self.bold {
+            out.push(("font-weight", "bold".into()));
+        }
+        if self.italic {
+            out.push(("font-style", "italic".into()));
+        }
+        if self.underline {
+            out.push(("text-decoration", "underline".into()));
+        }
+        out
+    }
+
+    /// The declarations for this style, formatted for a `style="..."` attribute.
+    pub fn inline_css(&self) -> String {
+        self.declarations()
+            .iter()
+            .map(|(property, value)| format!("{property}:{value}"))
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+}
+
+pub type SharedTheme = &'static Theme;
+
+/// A set of styles, resolved against `ALL_HIGHLIGHT_NAMES`.
+pub struct Theme {
+    pub name: String,
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    styles: Vec<Style>,
+}
+
+impl Theme {
+    /// Build a theme from rules keyed by capture name. Captures without a rule of their own
+    /// inherit from their dotted parent, so a `function` rule also covers `function.method`.
+    pub fn new<'a>(
+        name: impl Into<String>,
+        foreground: Option<Color>,
+        background: Option<Color>,
+        rules: impl IntoIterator<Item = (&'a str, Style)>,
+    ) -> Self {
+        let rules: BTreeMap<&str, Style> = rules.into_iter().collect();
+        let styles = ALL_HIGHLIGHT_NAMES
+            .iter()
+            .map(|name| {
+                let mut name = *name;
+                loop {
+                    if let Some(style) = rules.get(name) {
+                        break *style;
+                    }
+                    match name.rsplit_once('.') {
+                        Some((parent, _)) => name = parent,
+                        None => break Style::default(),
+                    }
+                }
+            })
+            .collect();
+        Theme {
+            name: name.into(),
+            foreground,
+            background,
+            styles,
+        }
+    }
+
+    /// The style for an index into `ALL_HIGHLIGHT_NAMES`.
+    pub fn style(&self, highlight: usize) -> Style {
+        self.styles.get(highlight).copied().unwrap_or_default()
+    }
+
+    /// Render a stylesheet for the `class="..."` attributes that `HtmlProcessor` emits when
+    /// naming them with `classes`. The theme's own foreground and background are attached to a
+    /// `.daylight` class, which is meant to go on whatever element contains the highlighted lines.
+    pub fn stylesheet(&self, classes: &ClassNames) -> String {
+        let rule = |css: &mut String, selector: &str, style: &Style| {
+            let _ = write!(css, "{selector} {{");
+            for (property, value) in style.declarations() {
+                let _ = write!(css, " {property}: {value};");
+            }
+            css.push_str(" }\n");
+        };
+        let mut css = format!("/* daylight theme: {} */\n", self.name);
+        let root = self.base_style();
+        if !root.is_empty() {
+            rule(&mut css, ".daylight", &root);
+        }
+        // Some naming schemes give several captures the same classes. The first of them, which
+        // is the most general, wins.
+        let mut selectors = BTreeSet::new();
+        for (i, style) in self.styles.iter().enumerate() {
+            let selector = classes.selector(i);
+            if !style.is_empty() && !selector.is_empty() && selectors.insert(selector.clone()) {
+                rule(&mut css, &selector, style);
+            }
+        }
+        for (selector, declarations) in [
+            (".daylight code", CODE_CSS),
+            (".daylight .line", LINE_CSS),
+            (".daylight .line-number", LINE_NUMBER_CSS),
+            (".daylight .emphasized > *", EMPHASIZED_CSS),
+        ] {
+            let _ = writeln!(css, "{selector} {{ {declarations}; }}");
+        }
+        css
+    }
+
+    /// The theme's foreground and background, as a style for a containing element.
+    pub fn base_style(&self) -> Style {
+        Style {
+            foreground: self.foreground,
+            background: self.background,
+            ..Default::default()
+        }
+    }
+}
+
+/// Errors encountered while loading theme files.
+#[derive(Debug, Error)]
+pub enum ThemeError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Invalid Helix theme: {0}")]
+    Helix(#[from] toml::de::Error),
+    #[error("Invalid VS Code theme: {0}")]
+    VsCode(#[from] serde_json::Error),
+    #[error("Invalid TextMate theme: {0}")]
+    TextMate(#[from] plist::Error),
+    #[error("Unrecognized theme format: {0}")]
+    UnknownFormat(String),
+}
+
+/// Parse a theme file, choosing a format from its extension: `.toml` for Helix,
+/// `.json` for VS Code, and `.tmTheme` or `.plist` for TextMate. The theme is named
+/// after the file's stem, so `themes/gruvbox.toml` becomes `gruvbox`.
+pub fn load_file(path: &Path) -> Result<Theme, ThemeError> {
+    let name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| ThemeError::UnknownFormat(path.display().to_string()))?;
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+    match extension {
+        "toml" => helix::parse(name, &std::fs::read_to_string(path)?),
+        "json" => textmate::parse_vscode(name, &std::fs::read_to_string(path)?),
+        "tmTheme" | "plist" => textmate::parse_tmtheme(name, &std::fs::read(path)?),
+        _ => Err(ThemeError::UnknownFormat(path.display().to_string())),
+    }
+}
+
+/// Load and register every theme file in a directory. Files that fail to parse are
+/// logged and skipped, so one bad theme doesn't keep the server from starting.
+pub fn load_directory(dir: &Path) -> Result<Vec<SharedTheme>, ThemeError> {
+    let mut entries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    let mut loaded = vec![];
+    for path in entries.iter().filter(|p| p.is_file()) {
+        match load_file(path) {
+            Ok(theme) => loaded.push(register(theme)),
+            Err(err) => tracing::warn!("Skipping theme {}: {err}", path.display()),
+        }
+    }
+    Ok(loaded)
+}
+
+static DAYLIGHT: LazyLock<Theme> = LazyLock::new(|| {
+    Theme::new(
+        "daylight",
+        Some(Color::hex(0x24292f)),
+        Some(Color::hex(0xffffff)),
+        [
+            ("attribute", Style::fg(0x953800)),
+            ("comment", Style::fg(0x6e7781).italic()),
+            ("constant", Style::fg(0x0550ae)),
+            ("constructor", Style::fg(0x8250df)),
+            ("embedded", Style::fg(0x24292f)),
+            ("escape", Style::fg(0x0a3069).bold()),
+            ("function", Style::fg(0x8250df)),
+            ("function.builtin", Style::fg(0x0550ae)),
+            ("function.macro", Style::fg(0x0550ae)),
+            ("include", Style::fg(0xcf222e)),
+            ("keyword", Style::fg(0xcf222e)),
+            ("label", Style::fg(0x953800)),
+            ("module", Style::fg(0x953800)),
+            ("number", Style::fg(0x0550ae)),
+            ("operator", Style::fg(0xcf222e)),
+            ("property", Style::fg(0x0550ae)),
+            ("punctuation", Style::fg(0x57606a)),
+            ("string", Style::fg(0x0a3069)),
+            ("string.escape", Style::fg(0x0a3069).bold()),
+            ("string.special", Style::fg(0x116329)),
+            ("tag", Style::fg(0x116329)),
+            ("tag.error", Style::fg(0x82071e).bold()),
+            ("type", Style::fg(0x953800)),
+            ("variable.builtin", Style::fg(0x0550ae)),
+            ("variable.parameter", Style::fg(0x953800)),
+        ],
+    )
+});
+
+static DUSK: LazyLock<Theme> = LazyLock::new(|| {
+    Theme::new(
+        "dusk",
+        Some(Color::hex(0xc9d1d9)),
+        Some(Color::hex(0x0d1117)),
+        [
+            ("attribute", Style::fg(0xffa657)),
+            ("comment", Style::fg(0x8b949e).italic()),
+            ("constant", Style::fg(0x79c0ff)),
+            ("constructor", Style::fg(0xd2a8ff)),
+            ("embedded", Style::fg(0xc9d1d9)),
+            ("escape", Style::fg(0xa5d6ff).bold()),
+            ("function", Style::fg(0xd2a8ff)),
+            ("function.builtin", Style::fg(0x79c0ff)),
+            ("function.macro", Style::fg(0x79c0ff)),
+            ("include", Style::fg(0xff7b72)),
+            ("keyword", Style::fg(0xff7b72)),
+            ("label", Style::fg(0xffa657)),
+            ("module", Style::fg(0xffa657)),
+            ("number", Style::fg(0x79c0ff)),
+            ("operator", Style::fg(0xff7b72)),
+            ("property", Style::fg(0x79c0ff)),
+            ("punctuation", Style::fg(0x8b949e)),
+            ("string", Style::fg(0xa5d6ff)),
+            ("string.escape", Style::fg(0xa5d6ff).bold()),
+            ("string.special", Style::fg(0x7ee787)),
+            ("tag", Style::fg(0x7ee787)),
+            ("tag.error", Style::fg(0xffa198).bold()),
+            ("type", Style::fg(0xffa657)),
+            ("variable.builtin", Style::fg(0x79c0ff)),
+            ("variable.parameter", Style::fg(0xffa657)),
+        ],
+    )
+});
+
+static NAME_MAP: LazyLock<RwLock<BTreeMap<String, SharedTheme>>> = LazyLock::new(|| {
+    let mut map = BTreeMap::new();
+    for theme in builtin_themes() {
+        map.insert(theme.name.clone(), theme);
+    }
+    RwLock::new(map)
+});
+
+fn builtin_themes() -> impl Iterator<Item = SharedTheme> {
+    [&*DAYLIGHT, &*DUSK].into_iter()
+}
+
+/// Make a theme available by name, replacing any existing theme with the same name.
+/// Themes are expected to be registered at startup and live for the rest of the process.
+pub fn register(theme: Theme) -> SharedTheme {
+    let theme: SharedTheme = Box::leak(Box::new(theme));
+    NAME_MAP
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(theme.name.clone(), theme);
+    theme
+}
+
+pub fn from_name(name: &str) -> Option<SharedTheme> {
+    NAME_MAP
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(name)
+        .copied()
+}
+
+/// The names of all registered themes, in sorted order.
+pub fn names() -> Vec<String> {
+    NAME_MAP
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .keys()
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use super::{Color, Style, Theme, ThemeError};
+use crate::languages::ALL_HIGHLIGHT_NAMES;
+
+/// TextMate scopes that correspond to each capture, most preferred first. Captures
+/// missing from this list fall back to their dotted parent when the theme is built.
+const SCOPES: &[(&str, &[&str])] = &[
+    ("attribute", &["entity.other.attribute-name"]),
+    ("comment", &["comment"]),
+    ("comment.documentation", &["comment.block.documentation"]),
+    ("constant", &["variable.other.constant", "constant"]),
+    ("constant.builtin", &["constant.language", "support.constant"]),
+    ("constant.macro", &["entity.name.function.preprocessor"]),
+    ("constructor", &["entity.name.function.constructor", "entity.name.type.class", "support.class"]),
+    ("delimiter", &["punctuation.separator"]),
+    ("embedded", &["meta.embedded"]),
+    ("escape", &["constant.character.escape"]),
+    ("function", &["entity.name.function", "support.function"]),
+    ("function.builtin", &["support.function"]),
+    ("function.macro", &["entity.name.function.macro", "entity.name.function.preprocessor"]),
+    ("function.method", &["entity.name.function.member", "entity.name.function"]),
+    ("function.method.builtin", &["support.function"]),
+    ("function.special", &["entity.name.function.preprocessor"]),
+    ("include", &["keyword.control.import", "keyword.other.import", "keyword.control"]),
+    ("keyword", &["keyword", "storage.type", "storage.modifier"]),
+    ("label", &["entity.name.label"]),
+    ("module", &["entity.name.namespace", "entity.name.module"]),
+    ("number", &["constant.numeric"]),
+    ("operator", &["keyword.operator"]),
+    ("property", &["variable.other.property", "variable.other.object.property", "support.type.property-name"]),
+    ("property.builtin", &["support.variable.property"]),
+    ("punctuation", &["punctuation"]),
+    ("punctuation.bracket", &["punctuation.section", "meta.brace"]),
+    ("punctuation.delimiter", &["punctuation.separator", "punctuation.terminator"]),
+    ("punctuation.special", &["punctuation.definition.template-expression"]),
+    ("string", &["string"]),
+    ("string.escape", &["constant.character.escape"]),
+    ("string.special", &["string.other", "string.unquoted"]),
+    ("string.special.key", &["support.type.property-name"]),
+    ("string.special.regex", &["string.regexp"]),
+    ("string.special.symbol", &["constant.other.symbol"]),
+    ("tag", &["entity.name.tag"]),
+    ("tag.error", &["invalid.illegal", "invalid"]),
+    ("type", &["entity.name.type", "support.type", "storage.type"]),
+    ("type.builtin", &["support.type.primitive", "support.type"]),
+    ("variable", &["variable.other", "variable"]),
+    ("variable.builtin", &["variable.language", "support.variable"]),
+    ("variable.parameter", &["variable.parameter"]),
+];
+
+/// A scope rule, shared by VS Code's `tokenColors` and `.tmTheme`'s `settings`.
+#[derive(Deserialize)]
+struct Rule {
+    #[serde(default)]
+    scope: Option<Scopes>,
+    #[serde(default)]
+    settings: Settings,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Scopes {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Default, Deserialize)]
+struct Settings {
+    foreground: Option<String>,
+    background: Option<String>,
+    #[serde(rename = "fontStyle")]
+    font_style: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct VsCodeTheme {
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+    #[serde(default, rename = "tokenColors")]
+    token_colors: Vec<Rule>,
+}
+
+#[derive(Deserialize)]
+struct TmTheme {
+    #[serde(default)]
+    settings: Vec<Rule>,
+}
+
+impl Settings {
+    fn style(&self) -> Style {
+        let color = |c: &Option<String>| c.as_deref().and_then(Color::parse);
+        Style {
+            foreground: color(&self.foreground),
+            background: color(&self.background),
+            ..Default::default()
+        }
+        .with_modifiers(self.font_style.iter().flat_map(|s| s.split_whitespace()))
+    }
+}
+
+impl Rule {
+    /// The selectors in this rule. Descendant selectors (`meta.class entity.name`) are
+    /// reduced to their last, most specific, element.
+    fn selectors(&self) -> Vec<&str> {
+        let scopes: Vec<&str> = match &self.scope {
+            Some(Scopes::One(s)) => s.split(',').collect(),
+            Some(Scopes::Many(v)) => v.iter().flat_map(|s| s.split(',')).collect(),
+            None => vec![],
+        };
+        scopes
+            .into_iter()
+            .filter_map(|s| s.split_whitespace().last())
+            .collect()
+    }
+}
+
+/// Pick a style for each capture from TextMate scope rules. A selector matches a scope
+/// if it is equal to it or a dotted prefix of it; longer selectors win, and later rules
+/// win ties, as they do in TextMate.
+fn resolve(rules: &[Rule]) -> Vec<(&'static str, Style)> {
+    let matching = |scope: &str| {
+        let mut best: Option<(usize, Style)> = None;
+        for rule in rules {
+            for selector in rule.selectors() {
+                let matches = scope == selector
+                    || scope.strip_prefix(selector).is_some_and(|rest| rest.starts_with('.'));
+                let specificity = selector.split('.').count();
+                if matches && best.is_none_or(|(s, _)| specificity >= s) {
+                    best = Some((specificity, rule.settings.style()));
+                }
+            }
+        }
+        best.map(|(_, style)| style)
+    };
+    ALL_HIGHLIGHT_NAMES
+        .iter()
+        .filter_map(|capture| {
+            let (_, scopes) = SCOPES.iter().find(|(c, _)| c == capture)?;
+            scopes
+                .iter()
+                .find_map(|scope| matching(scope))
+                .map(|style| (*capture, style))
+        })
+        .collect()
+}
+
+/// Parse a VS Code color theme. Comments and trailing commas, which VS Code tolerates,
+/// are stripped before parsing.
+pub(super) fn parse_vscode(name: &str, source: &str) -> Result<Theme, ThemeError> {
+    let theme: VsCodeTheme = serde_json::from_str(&strip_jsonc(source))?;
+    let color = |key: &str| theme.colors.get(key).and_then(|c| Color::parse(c));
+    let foreground = color("editor.foreground").or_else(|| color("foreground"));
+    let background = color("editor.background");
+    Ok(Theme::new(name, foreground, background, resolve(&theme.token_colors)))
+}
+
+/// Parse a TextMate `.tmTheme` property list. The first rule without a scope carries
+/// the theme's global foreground and background.
+pub(super) fn parse_tmtheme(name: &str, source: &[u8]) -> Result<Theme, ThemeError> {
+    let theme: TmTheme = plist::from_bytes(source)?;
+    let global = theme
+        .settings
+        .iter()
+        .find(|r| r.scope.is_none())
+        .map(|r| r.settings.style())
+        .unwrap_or_default();
+    Ok(Theme::new(name, global.foreground, global.background, resolve(&theme.settings)))
+}
+
+/// Remove `//` and `/* */` comments and trailing commas from JSON, leaving strings alone.
+fn strip_jsonc(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    match c {
+                        '\\' => out.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = '\0';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            ']' | '}' => {
+                let trimmed = out.trim_end().len();
+                if out[..trimmed].ends_with(',') {
+                    out.truncate(trimmed - 1);
+                }
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -5,7 +5,7 @@ use tree_sitter_highlight as ts;
 thread_local! {
     // Has to be a RefCell because we need &muts for the tree-sitter
     static HIGHLIGHTER: RefCell<ts::Highlighter> = RefCell::default();
-    static HTML_RENDERER: RefCell<ts::HtmlRenderer> = RefCell::default();
+    static PARSER: RefCell<tree_sitter::Parser> = RefCell::default();
     static RESPONSE_BUILDER: RefCell<flatbuffers::FlatBufferBuilder<'static>> = RefCell::default();
 }
 
@@ -24,11 +24,11 @@ impl ThreadState {
     }
 
     #[instrument(skip(func))]
-    pub fn render_with_tree_sitter<T, F>(func: F) -> T
+    pub fn parse_with_tree_sitter<T, F>(func: F) -> T
     where
-        F: FnOnce(&mut ts::HtmlRenderer) -> T,
+        F: FnOnce(&mut tree_sitter::Parser) -> T,
     {
-        HTML_RENDERER.with_borrow_mut(func)
+        PARSER.with_borrow_mut(func)
     }
 
     #[instrument(skip(func))]
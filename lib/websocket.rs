@@ -0,0 +1,317 @@
+//! The `/v1/ws` WebSocket, which carries requests for the batch endpoints over one connection.
+//!
+//! Each request is tagged with an ID chosen by the client, so any number can be in flight at
+//! once. As each file finishes, the server pushes a response from the request's endpoint holding
+//! just that file's document, and once they all have, a last message with `done` set. A client
+//! can cancel a request by sending its ID with `cancel` set, and closing the connection cancels
+//! everything still running. See `daylight.ws` in the schema.
+//!
+//! Responses wait in a bounded queue for the client to read them. When it fills up, each request
+//! waits to send its next document, so a client that doesn't keep up slows its own requests down
+//! rather than growing the server's memory.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::body::Bytes;
+use axum::extract::{
+    self,
+    ws::{Message, WebSocket, WebSocketUpgrade},
+};
+use flatbuffers::FlatBufferBuilder;
+use futures::channel::{mpsc, oneshot};
+use futures::{SinkExt, StreamExt};
+use tracing::instrument;
+
+use crate::daylight_generated::daylight::ws;
+use crate::encoding::Encoding;
+use crate::errors::{FatalError, NonFatalError};
+use crate::processors::{
+    AnsiProcessor, DiagnosticsProcessor, DiffProcessor, FoldsProcessor, HtmlProcessor, ImageProcessor, LatexProcessor, MarkdownProcessor, Outcome, PatchProcessor, Processor, QueryProcessor, ReferencesProcessor, RtfProcessor, SemanticTokensProcessor, SpansProcessor, TagsProcessor, TreeProcessor, TypstProcessor,
+};
+use crate::server::{Server, document_flatbuffers, process_files};
+use crate::wire::{self, Request as _};
+
+/// A request or a response, as it's carried in a message: Flatbuffers in a binary message, or
+/// JSON in a text message.
+enum Payload {
+    Flatbuffers(Bytes),
+    Json(serde_json::Value),
+}
+
+impl Payload {
+    fn encoding(&self) -> Encoding {
+        match self {
+            Payload::Flatbuffers(_) => Encoding::Flatbuffers,
+            Payload::Json(_) => Encoding::Json,
+        }
+    }
+}
+
+struct ClientMessage {
+    id: u64,
+    endpoint: String,
+    request: Payload,
+    cancel: bool,
+}
+
+impl ClientMessage {
+    fn from_flatbuffers(bytes: Bytes) -> Result<Self, FatalError> {
+        let message = flatbuffers::root::<ws::ClientMessage>(&bytes)?;
+        // Nested buffers are read in place, like file contents.
+        let request = message.request().map(|r| bytes.slice_ref(r.bytes())).unwrap_or_default();
+        Ok(ClientMessage {
+            id: message.id(),
+            endpoint: message.endpoint().unwrap_or_default().to_string(),
+            request: Payload::Flatbuffers(request),
+            cancel: message.cancel(),
+        })
+    }
+
+    fn from_json(text: &str) -> Result<Self, FatalError> {
+        let message: wire::ws::ClientMessage = Encoding::Json.decode(text.as_bytes())?;
+        Ok(ClientMessage {
+            id: message.id,
+            endpoint: message.endpoint,
+            request: Payload::Json(message.request),
+            cancel: message.cancel,
+        })
+    }
+}
+
+/// How many messages can wait to be sent on a connection before senders have to wait.
+const OUTBOX_SIZE: usize = 64;
+
+/// How many requests a connection can have in flight at once.
+pub const MAX_IN_FLIGHT: usize = 32;
+
+/// Build a message from the server, in the encoding of the request it's about.
+fn server_message(encoding: Encoding, id: u64, response: Option<Payload>, done: bool, error: Option<String>) -> Message {
+    match encoding {
+        Encoding::Flatbuffers => {
+            let mut builder = FlatBufferBuilder::new();
+            let response = match response {
+                Some(Payload::Flatbuffers(bytes)) => Some(builder.create_vector(&bytes)),
+                _ => None,
+            };
+            let error = error.map(|e| builder.create_string(&e));
+            let message = ws::ServerMessage::create(
+                &mut builder,
+                &ws::ServerMessageArgs {
+                    id,
+                    response,
+                    done,
+                    error,
+                },
+            );
+            builder.finish(message, None);
+            Message::Binary(builder.finished_data().to_vec())
+        }
+        _ => {
+            let response = match response {
+                Some(Payload::Json(value)) => Some(value),
+                _ => None,
+            };
+            let message = wire::ws::ServerMessage {
+                id,
+                response,
+                done,
+                error,
+            };
+            Message::Text(serde_json::to_string(&message).expect("JSON values always serialize"))
+        }
+    }
+}
+
+/// A response holding a single document, in the given encoding.
+async fn document_response<P: Processor>(encoding: Encoding, outcome: Outcome<P::Output>) -> Result<Payload, FatalError> {
+    match encoding {
+        Encoding::Flatbuffers => Ok(Payload::Flatbuffers(document_flatbuffers::<P>(outcome).await?)),
+        _ => serde_json::to_value(P::WireResponse::from(vec![outcome]))
+            .map(Payload::Json)
+            .map_err(|e| FatalError::EncodeError(encoding, e.to_string())),
+    }
+}
+
+/// A request that hasn't finished yet.
+struct InFlight {
+    /// Tells this request apart from earlier and later ones with the same ID.
+    generation: u64,
+    /// Cancels the request. Dropping it cancels the request too.
+    cancel: oneshot::Sender<()>,
+}
+
+/// The state of one WebSocket connection.
+#[derive(Clone)]
+struct Connection {
+    state: Server,
+    outbox: mpsc::Sender<Message>,
+    /// The unfinished requests, by ID.
+    in_flight: Arc<Mutex<HashMap<u64, InFlight>>>,
+    next_generation: Arc<AtomicU64>,
+}
+
+impl Connection {
+    /// Queue a message, waiting for room if the client is behind.
+    async fn send(&mut self, message: Message) {
+        // The connection closing cancels everything, so there's nobody left to tell.
+        let _ = self.outbox.send(message).await;
+    }
+
+    fn in_flight(&self) -> std::sync::MutexGuard<'_, HashMap<u64, InFlight>> {
+        self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Act on a message from the client. Errors end the request the message was about.
+    fn receive(&self, message: ClientMessage) -> Result<(), FatalError> {
+        if message.cancel {
+            // Requests that have already finished have nothing left to cancel.
+            if let Some(request) = self.in_flight().remove(&message.id) {
+                let _ = request.cancel.send(());
+            }
+            return Ok(());
+        }
+        let (id, request) = (message.id, message.request);
+        match message.endpoint.strip_prefix("/v1/").unwrap_or(&message.endpoint) {
+            "html" => self.start::<HtmlProcessor>(id, request),
+            "spans" => self.start::<SpansProcessor>(id, request),
+            "ansi" => self.start::<AnsiProcessor>(id, request),
+            "semantic-tokens" => self.start::<SemanticTokensProcessor>(id, request),
+            "latex" => self.start::<LatexProcessor>(id, request),
+            "typst" => self.start::<TypstProcessor>(id, request),
+            "image" => self.start::<ImageProcessor>(id, request),
+            "rtf" => self.start::<RtfProcessor>(id, request),
+            "markdown" => self.start::<MarkdownProcessor>(id, request),
+            "diff" => self.start::<DiffProcessor>(id, request),
+            "patch" => self.start::<PatchProcessor>(id, request),
+            "tags" => self.start::<TagsProcessor>(id, request),
+            "folds" => self.start::<FoldsProcessor>(id, request),
+            "diagnostics" => self.start::<DiagnosticsProcessor>(id, request),
+            "references" => self.start::<ReferencesProcessor>(id, request),
+            "tree" => self.start::<TreeProcessor>(id, request),
+            "query" => self.start::<QueryProcessor>(id, request),
+            _ => Err(FatalError::UnknownEndpoint(message.endpoint)),
+        }
+    }
+
+    /// Start a request, which sends a message for each document as it finishes, and a last one
+    /// when they all have or the request is cancelled. The ID is claimed before any work starts,
+    /// and given up again if the request can't be started.
+    fn start<P: Processor>(&self, id: u64, request: Payload) -> Result<(), FatalError> {
+        let (cancel, mut cancelled) = oneshot::channel();
+        let generation = self.next_generation.fetch_add(1, Ordering::Relaxed);
+        {
+            let mut in_flight = self.in_flight();
+            if in_flight.contains_key(&id) {
+                Err(FatalError::RequestIdInUse(id))?
+            }
+            if in_flight.len() >= MAX_IN_FLIGHT {
+                Err(FatalError::TooManyRequests(MAX_IN_FLIGHT))?
+            }
+            in_flight.insert(id, InFlight { generation, cancel });
+        }
+        let encoding = request.encoding();
+        let timeout_flag: Arc<AtomicUsize> = Arc::default();
+        let tasks = match request {
+            Payload::Flatbuffers(body) => Ok(body),
+            Payload::Json(value) => serde_json::from_value::<P::WireRequest>(value)
+                .map(|request| Bytes::from(request.to_flatbuffers()))
+                .map_err(|e| FatalError::InvalidRequest(encoding, e.to_string())),
+        }
+        .and_then(|body| process_files::<P>(&self.state, body, timeout_flag.clone()));
+        let mut tasks = match tasks {
+            Ok(tasks) => tasks,
+            Err(err) => {
+                self.finish(id, generation);
+                return Err(err);
+            }
+        };
+
+        let mut connection = self.clone();
+        tokio::spawn(async move {
+            let mut error = None;
+            loop {
+                tokio::select! {
+                    // A cancelled request says so, even if its last document is ready too.
+                    biased;
+                    // Whoever cancelled this request has already forgotten it.
+                    _ = &mut cancelled => {
+                        timeout_flag.store(1, Ordering::SeqCst);
+                        let cancelled = Some(NonFatalError::Cancelled.to_string());
+                        connection.send(server_message(encoding, id, None, true, cancelled)).await;
+                        return;
+                    }
+                    outcome = tasks.next() => match outcome {
+                        Some(outcome) => match document_response::<P>(encoding, outcome).await {
+                            Ok(response) => connection.send(server_message(encoding, id, Some(response), false, None)).await,
+                            Err(reason) => {
+                                error = Some(reason.to_string());
+                                break;
+                            }
+                        },
+                        None => break,
+                    },
+                }
+            }
+            connection.finish(id, generation);
+            connection.send(server_message(encoding, id, None, true, error)).await;
+        });
+        Ok(())
+    }
+
+    /// Forget a request, unless its ID has since been taken by a newer one.
+    fn finish(&self, id: u64, generation: u64) {
+        let mut in_flight = self.in_flight();
+        if in_flight.get(&id).is_some_and(|request| request.generation == generation) {
+            in_flight.remove(&id);
+        }
+    }
+}
+
+/// Upgrade a request to the `/v1/ws` WebSocket.
+#[instrument(skip(state, upgrade))]
+pub async fn handler(
+    extract::State(state): extract::State<Server>,
+    upgrade: WebSocketUpgrade,
+) -> axum::response::Response {
+    upgrade.on_upgrade(move |socket| serve(state, socket))
+}
+
+async fn serve(state: Server, socket: WebSocket) {
+    let (mut sink, mut stream) = socket.split();
+    let (outbox, mut outgoing) = mpsc::channel(OUTBOX_SIZE);
+    tokio::spawn(async move {
+        while let Some(message) = outgoing.next().await {
+            if sink.send(message).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut connection = Connection {
+        state,
+        outbox,
+        in_flight: Arc::default(),
+        next_generation: Arc::default(),
+    };
+    while let Some(Ok(message)) = stream.next().await {
+        let (encoding, message) = match message {
+            Message::Binary(bytes) => (Encoding::Flatbuffers, ClientMessage::from_flatbuffers(Bytes::from(bytes))),
+            Message::Text(text) => (Encoding::Json, ClientMessage::from_json(&text)),
+            Message::Close(_) => break,
+            // Pings are answered for us.
+            Message::Ping(_) | Message::Pong(_) => continue,
+        };
+        // A message that can't be read is answered as if its ID were zero.
+        let id = message.as_ref().map_or(0, |m| m.id);
+        if let Err(reason) = message.and_then(|m| connection.receive(m)) {
+            // A request with an ID that's in use was never started, and the request that does
+            // have that ID is still running, so the rejection mustn't end it.
+            let done = !matches!(reason, FatalError::RequestIdInUse(_));
+            connection.send(server_message(encoding, id, None, done, Some(reason.to_string()))).await;
+        }
+    }
+    // Dropping every request's way to cancel it cancels it.
+    connection.in_flight().clear();
+}
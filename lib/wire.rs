@@ -0,0 +1,2003 @@
+//! Serde mirrors of the tables in `daylight.fbs`, for clients that send JSON, CBOR or
+//! MessagePack instead of Flatbuffers.
+//!
+//! The mapping is mechanical: every table is a map with the same field names as the schema,
+//! and every field may be left out, taking the schema's default. Enums (`Language`,
+//! `ErrorCode`, `ColorMode`, `OffsetEncoding`, `LatexStyle`, `ImageFormat`, `ClassStyle`,
+//! `Layout`, `Markup`, `LineKind`, `FoldKind`, `DiagnosticKind`, `TreeFormat`,
+//! `QueryErrorKind`) are written as their names in the schema, like `"Rust"` or `"TimedOut"`.
+//! File `contents` may be a string or a byte string, and images are byte strings. For example:
+//!
+//! ```json
+//! {"files": [{"ident": 0, "filename": "main.rs", "contents": "fn main() {}", "language": "Rust"}]}
+//! ```
+//!
+//! A `/v1/spans` response then looks like:
+//!
+//! ```json
+//! {"highlight_names": ["attribute", "..."], "documents": [{"ident": 0, "filename": "main.rs",
+//!   "language": "Rust", "spans": [{"index": 17, "start": 0, "end": 2}], "error_code": "NoError"}]}
+//! ```
+//!
+//! Documents that fail to highlight have an empty `lines` or `spans` list and an `error_code`
+//! other than `NoError`, exactly as they do in Flatbuffers.
+
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Vector, WIPOffset};
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::daylight_generated::daylight as fb;
+use crate::processors::Outcome;
+
+/// A request that can be re-encoded as Flatbuffers, so that it can be handled exactly like one
+/// that arrived that way.
+pub trait Request: DeserializeOwned + Send {
+    fn to_flatbuffers(&self) -> Vec<u8>;
+}
+
+/// Write Flatbuffers enums as their names in the schema.
+macro_rules! named_enum {
+    ($ty:ty) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.variant_name().unwrap_or_default())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let name = String::deserialize(deserializer)?;
+                <$ty>::ENUM_VALUES
+                    .iter()
+                    .copied()
+                    .find(|value| value.variant_name() == Some(name.as_str()))
+                    .ok_or_else(|| D::Error::custom(format!("unknown {} `{name}`", stringify!($ty))))
+            }
+        }
+    };
+}
+
+named_enum!(fb::common::Language);
+named_enum!(fb::common::ErrorCode);
+named_enum!(fb::common::OffsetEncoding);
+named_enum!(fb::ansi::ColorMode);
+named_enum!(fb::latex::LatexStyle);
+named_enum!(fb::image::ImageFormat);
+named_enum!(fb::html::ClassStyle);
+named_enum!(fb::diff::Layout);
+named_enum!(fb::diff::Markup);
+named_enum!(fb::diff::LineKind);
+named_enum!(fb::folds::FoldKind);
+named_enum!(fb::diagnostics::DiagnosticKind);
+named_enum!(fb::tree::TreeFormat);
+named_enum!(fb::query::QueryErrorKind);
+
+/// The fields every document carries, whatever its payload.
+fn document_header<T>(outcome: &Outcome<T>) -> (u16, String, fb::common::Language, fb::common::ErrorCode) {
+    (outcome.ident(), outcome.filename().to_string(), outcome.language(), outcome.error_code())
+}
+
+fn into_contents<T: Default>(outcome: Outcome<T>) -> T {
+    match outcome {
+        Outcome::Success { contents, .. } => contents,
+        Outcome::Failure { .. } => T::default(),
+    }
+}
+
+pub mod common {
+    use super::*;
+
+    /// A file to be highlighted.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct File {
+        pub ident: u16,
+        pub filename: Option<String>,
+        pub contents: serde_bytes::ByteBuf,
+        pub language: fb::common::Language,
+        pub include_injections: bool,
+        pub options: Vec<String>,
+        pub start_line: u32,
+        pub end_line: u32,
+    }
+
+    /// A range of lines, numbered from 1. Both ends are inclusive.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct LineRange {
+        pub start: u32,
+        pub end: u32,
+    }
+
+    pub(super) fn create_line_ranges<'a>(
+        builder: &mut FlatBufferBuilder<'a>,
+        ranges: &[LineRange],
+    ) -> WIPOffset<Vector<'a, ForwardsUOffset<fb::common::LineRange<'a>>>> {
+        let ranges = ranges
+            .iter()
+            .map(|range| {
+                fb::common::LineRange::create(
+                    builder,
+                    &fb::common::LineRangeArgs {
+                        start: range.start,
+                        end: range.end,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        builder.create_vector(&ranges)
+    }
+
+    pub(super) fn create_file<'a>(
+        builder: &mut FlatBufferBuilder<'a>,
+        file: &File,
+    ) -> WIPOffset<fb::common::File<'a>> {
+        let filename = file.filename.as_deref().map(|f| builder.create_string(f));
+        let contents = Some(builder.create_vector(&file.contents));
+        let options: Vec<_> = file.options.iter().map(|o| builder.create_string(o)).collect();
+        let options = Some(builder.create_vector(&options));
+        fb::common::File::create(
+            builder,
+            &fb::common::FileArgs {
+                ident: file.ident,
+                filename,
+                contents,
+                language: file.language,
+                include_injections: file.include_injections,
+                options,
+                start_line: file.start_line,
+                end_line: file.end_line,
+            },
+        )
+    }
+
+    pub(super) fn create_files<'a>(
+        builder: &mut FlatBufferBuilder<'a>,
+        files: &[File],
+    ) -> WIPOffset<Vector<'a, ForwardsUOffset<fb::common::File<'a>>>> {
+        let files = files.iter().map(|file| create_file(builder, file)).collect::<Vec<_>>();
+        builder.create_vector(&files)
+    }
+}
+
+pub mod html {
+    use super::*;
+    use crate::processors::HtmlDocument;
+
+    /// A request to highlight some files as HTML.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub theme: Option<String>,
+        pub inline_styles: bool,
+        pub wrap_in_pre: bool,
+        pub full_document: bool,
+        pub emphasized_lines: Vec<common::LineRange>,
+        pub class_style: fb::html::ClassStyle,
+        pub class_prefix: Option<String>,
+        pub link_references: bool,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let theme = self.theme.as_deref().map(|t| builder.create_string(t));
+            let emphasized_lines = Some(common::create_line_ranges(&mut builder, &self.emphasized_lines));
+            let class_prefix = self.class_prefix.as_deref().map(|p| builder.create_string(p));
+            let request = fb::html::Request::create(
+                &mut builder,
+                &fb::html::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    theme,
+                    inline_styles: self.inline_styles,
+                    wrap_in_pre: self.wrap_in_pre,
+                    full_document: self.full_document,
+                    emphasized_lines,
+                    class_style: self.class_style,
+                    class_prefix,
+                    link_references: self.link_references,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing highlighted documents.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A highlighted document.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub lines: Vec<String>,
+        pub error_code: fb::common::ErrorCode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rendered: Option<String>,
+    }
+
+    impl From<Vec<Outcome<HtmlDocument>>> for Response {
+        fn from(outputs: Vec<Outcome<HtmlDocument>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let document = into_contents(outcome);
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        lines: document.lines,
+                        error_code,
+                        rendered: document.rendered,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod spans {
+    use super::*;
+    use crate::languages::ALL_HIGHLIGHT_NAMES;
+    use crate::processors::SpansDocument;
+
+    /// A request to highlight documents returning only spans.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub offset_encoding: fb::common::OffsetEncoding,
+        pub include_positions: bool,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::spans::Request::create(
+                &mut builder,
+                &fb::spans::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    offset_encoding: self.offset_encoding,
+                    include_positions: self.include_positions,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing highlighted documents and capture name information.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub highlight_names: &'static [&'static str],
+        pub documents: Vec<Document>,
+    }
+
+    /// Range and highlight-capture information about a particular identifier. Lines and
+    /// columns are left out unless the request asked for `include_positions`.
+    #[derive(Serialize)]
+    pub struct Span {
+        pub index: u16,
+        pub start: u64,
+        pub end: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub start_line: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub start_column: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub end_line: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub end_column: Option<u32>,
+        pub scopes: Vec<u16>,
+    }
+
+    impl From<crate::processors::Span> for Span {
+        fn from(span: crate::processors::Span) -> Self {
+            let (start, end) = span.positions.unzip();
+            Span {
+                index: span.index as u16,
+                start: span.start as u64,
+                end: span.end as u64,
+                start_line: start.map(|(line, _)| line as u32),
+                start_column: start.map(|(_, column)| column as u32),
+                end_line: end.map(|(line, _)| line as u32),
+                end_column: end.map(|(_, column)| column as u32),
+                scopes: span.scopes,
+            }
+        }
+    }
+
+    /// A document for span-based highlighting.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub spans: Vec<Span>,
+        pub error_code: fb::common::ErrorCode,
+        pub line_starts: Vec<u64>,
+    }
+
+    impl From<Vec<Outcome<SpansDocument>>> for Response {
+        fn from(outputs: Vec<Outcome<SpansDocument>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let document = into_contents(outcome);
+                    let spans = document.spans.into_iter().map(Span::from).collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        spans,
+                        error_code,
+                        line_starts: document.line_starts.into_iter().map(|s| s as u64).collect(),
+                    }
+                })
+                .collect();
+            Self {
+                highlight_names: &ALL_HIGHLIGHT_NAMES,
+                documents,
+            }
+        }
+    }
+}
+
+pub mod ansi {
+    use super::*;
+
+    /// A request to highlight some files as text containing ANSI escape sequences.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub theme: Option<String>,
+        pub color_mode: fb::ansi::ColorMode,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let theme = self.theme.as_deref().map(|t| builder.create_string(t));
+            let request = fb::ansi::Request::create(
+                &mut builder,
+                &fb::ansi::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    theme,
+                    color_mode: self.color_mode,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing documents highlighted for display in a terminal.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A document highlighted for display in a terminal.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub lines: Vec<String>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<Vec<String>>>> for Response {
+        fn from(outputs: Vec<Outcome<Vec<String>>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        lines: into_contents(outcome),
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod semantic_tokens {
+    use super::*;
+    use crate::processors::{TOKEN_MODIFIERS, TOKEN_TYPES};
+
+    /// A request to highlight some files as LSP semantic tokens.
+    #[derive(Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub position_encoding: fb::common::OffsetEncoding,
+    }
+
+    impl Default for Request {
+        fn default() -> Self {
+            Self {
+                files: vec![],
+                timeout_ms: 0,
+                position_encoding: fb::common::OffsetEncoding::Utf16,
+            }
+        }
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::semantic_tokens::Request::create(
+                &mut builder,
+                &fb::semantic_tokens::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    position_encoding: self.position_encoding,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// The token types and modifiers to announce in the server's `SemanticTokensLegend`.
+    #[derive(Serialize)]
+    pub struct Legend {
+        pub token_types: &'static [&'static str],
+        pub token_modifiers: &'static [&'static str],
+    }
+
+    /// A response containing semantic tokens and the legend they are encoded against.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub legend: Legend,
+        pub documents: Vec<Document>,
+    }
+
+    /// A document's semantic tokens.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub data: Vec<u32>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<Vec<u32>>>> for Response {
+        fn from(outputs: Vec<Outcome<Vec<u32>>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        data: into_contents(outcome),
+                        error_code,
+                    }
+                })
+                .collect();
+            Self {
+                legend: Legend {
+                    token_types: &TOKEN_TYPES,
+                    token_modifiers: &TOKEN_MODIFIERS,
+                },
+                documents,
+            }
+        }
+    }
+}
+
+pub mod latex {
+    use super::*;
+
+    /// A request to highlight some files as LaTeX.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub style: fb::latex::LatexStyle,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::latex::Request::create(
+                &mut builder,
+                &fb::latex::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    style: self.style,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing highlighted documents.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A document highlighted as LaTeX.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub lines: Vec<String>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<Vec<String>>>> for Response {
+        fn from(outputs: Vec<Outcome<Vec<String>>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        lines: into_contents(outcome),
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod typst {
+    use super::*;
+
+    /// A request to highlight some files as Typst.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::typst::Request::create(
+                &mut builder,
+                &fb::typst::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing highlighted documents.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A document highlighted as Typst.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub lines: Vec<String>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<Vec<String>>>> for Response {
+        fn from(outputs: Vec<Outcome<Vec<String>>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        lines: into_contents(outcome),
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod image {
+    use super::*;
+    use crate::processors::CodeImage;
+
+    /// A request to draw some files as images.
+    #[derive(Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub theme: Option<String>,
+        pub format: fb::image::ImageFormat,
+        pub font_size: f32,
+        pub padding: u16,
+        pub window_chrome: bool,
+        pub line_numbers: bool,
+    }
+
+    impl Default for Request {
+        fn default() -> Self {
+            Self {
+                files: vec![],
+                timeout_ms: 0,
+                theme: None,
+                format: fb::image::ImageFormat::Svg,
+                font_size: 14.0,
+                padding: 32,
+                window_chrome: false,
+                line_numbers: false,
+            }
+        }
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let theme = self.theme.as_deref().map(|t| builder.create_string(t));
+            let request = fb::image::Request::create(
+                &mut builder,
+                &fb::image::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    theme,
+                    format: self.format,
+                    font_size: self.font_size,
+                    padding: self.padding,
+                    window_chrome: self.window_chrome,
+                    line_numbers: self.line_numbers,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing images of documents.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// An image of a highlighted document.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub image: serde_bytes::ByteBuf,
+        pub width: u32,
+        pub height: u32,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<CodeImage>>> for Response {
+        fn from(outputs: Vec<Outcome<CodeImage>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let image = into_contents(outcome);
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        image: serde_bytes::ByteBuf::from(image.data),
+                        width: image.width,
+                        height: image.height,
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod rtf {
+    use super::*;
+
+    /// A request to highlight some files as RTF.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub theme: Option<String>,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let theme = self.theme.as_deref().map(|t| builder.create_string(t));
+            let request = fb::rtf::Request::create(
+                &mut builder,
+                &fb::rtf::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    theme,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing highlighted documents.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A document highlighted as RTF.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub rtf: String,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<String>>> for Response {
+        fn from(outputs: Vec<Outcome<String>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        rtf: into_contents(outcome),
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod markdown {
+    use super::*;
+
+    /// A request to highlight the code blocks in some Markdown files.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub theme: Option<String>,
+        pub inline_styles: bool,
+        pub render_prose: bool,
+        pub class_style: fb::html::ClassStyle,
+        pub class_prefix: Option<String>,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let theme = self.theme.as_deref().map(|t| builder.create_string(t));
+            let class_prefix = self.class_prefix.as_deref().map(|p| builder.create_string(p));
+            let request = fb::markdown::Request::create(
+                &mut builder,
+                &fb::markdown::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    theme,
+                    inline_styles: self.inline_styles,
+                    render_prose: self.render_prose,
+                    class_style: self.class_style,
+                    class_prefix,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing rendered documents.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A Markdown document with its code blocks highlighted.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub rendered: String,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<String>>> for Response {
+        fn from(outputs: Vec<Outcome<String>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        rendered: into_contents(outcome),
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod diff {
+    use super::*;
+    use crate::processors::{DiffDocument, DiffLine};
+
+    /// A request to diff old and new versions of some files.
+    #[derive(Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub old_files: Vec<common::File>,
+        pub layout: fb::diff::Layout,
+        pub markup: fb::diff::Markup,
+        pub word_diff: bool,
+        pub context_lines: i32,
+    }
+
+    impl Default for Request {
+        fn default() -> Self {
+            Self {
+                files: vec![],
+                timeout_ms: 0,
+                old_files: vec![],
+                layout: fb::diff::Layout::Unified,
+                markup: fb::diff::Markup::Html,
+                word_diff: false,
+                context_lines: -1,
+            }
+        }
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let old_files = Some(common::create_files(&mut builder, &self.old_files));
+            let request = fb::diff::Request::create(
+                &mut builder,
+                &fb::diff::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    old_files,
+                    layout: self.layout,
+                    markup: self.markup,
+                    word_diff: self.word_diff,
+                    context_lines: self.context_lines,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing diffed documents.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A range of bytes within a line.
+    #[derive(Serialize)]
+    pub struct ByteRange {
+        pub start: u32,
+        pub end: u32,
+    }
+
+    /// One line of a diff. Fields for the markup that wasn't asked for are left out.
+    #[derive(Serialize)]
+    pub struct Line {
+        pub kind: fb::diff::LineKind,
+        pub old_number: u32,
+        pub new_number: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub html: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub text: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub spans: Vec<spans::Span>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub changes: Vec<ByteRange>,
+    }
+
+    impl From<DiffLine> for Line {
+        fn from(line: DiffLine) -> Self {
+            Line {
+                kind: line.kind,
+                old_number: line.old_number as u32,
+                new_number: line.new_number as u32,
+                html: line.html,
+                text: line.text,
+                spans: line.spans.into_iter().map(spans::Span::from).collect(),
+                changes: line
+                    .changes
+                    .into_iter()
+                    .map(|range| ByteRange {
+                        start: range.start as u32,
+                        end: range.end as u32,
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    /// A row of a side-by-side diff.
+    #[derive(Serialize)]
+    pub struct Row {
+        pub old: Option<Line>,
+        pub new: Option<Line>,
+    }
+
+    /// A run of changes and the context around them.
+    #[derive(Serialize)]
+    pub struct Hunk {
+        pub old_start: u32,
+        pub old_lines: u32,
+        pub new_start: u32,
+        pub new_lines: u32,
+        pub lines: Vec<Line>,
+        pub rows: Vec<Row>,
+    }
+
+    /// The diff of one file.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub hunks: Vec<Hunk>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<DiffDocument>>> for Response {
+        fn from(outputs: Vec<Outcome<DiffDocument>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let hunks = into_contents(outcome)
+                        .hunks
+                        .into_iter()
+                        .map(|hunk| Hunk {
+                            old_start: hunk.old_start as u32,
+                            old_lines: hunk.old_lines as u32,
+                            new_start: hunk.new_start as u32,
+                            new_lines: hunk.new_lines as u32,
+                            lines: hunk.lines.into_iter().map(Line::from).collect(),
+                            rows: hunk
+                                .rows
+                                .into_iter()
+                                .map(|(old, new)| Row {
+                                    old: old.map(Line::from),
+                                    new: new.map(Line::from),
+                                })
+                                .collect(),
+                        })
+                        .collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        hunks,
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod patch {
+    use super::*;
+    use crate::processors::PatchDocument;
+
+    /// A request to highlight some unified diffs.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::patch::Request::create(
+                &mut builder,
+                &fb::patch::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing highlighted patches.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A file changed by a patch.
+    #[derive(Serialize)]
+    pub struct PatchFile {
+        pub old_path: String,
+        pub new_path: String,
+        pub language: fb::common::Language,
+    }
+
+    /// A highlighted patch.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub lines: Vec<String>,
+        pub files: Vec<PatchFile>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<PatchDocument>>> for Response {
+        fn from(outputs: Vec<Outcome<PatchDocument>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let document = into_contents(outcome);
+                    let files = document
+                        .files
+                        .into_iter()
+                        .map(|file| PatchFile {
+                            old_path: file.old_path,
+                            new_path: file.new_path,
+                            language: file.language.map(|l| l.fb_language).unwrap_or_default(),
+                        })
+                        .collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        lines: document.lines,
+                        files,
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod tags {
+    use super::*;
+
+    /// A request for the symbols in some files.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::tags::Request::create(
+                &mut builder,
+                &fb::tags::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing the tags of each file.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A symbol defined or referred to in a file.
+    #[derive(Serialize)]
+    pub struct Tag {
+        pub kind: String,
+        pub name: String,
+        pub is_definition: bool,
+        pub start: u64,
+        pub end: u64,
+        pub name_start: u64,
+        pub name_end: u64,
+        pub start_line: u32,
+        pub end_line: u32,
+        pub docs: Option<String>,
+    }
+
+    /// The tags of a file.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub tags: Vec<Tag>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<Vec<crate::processors::Tag>>>> for Response {
+        fn from(outputs: Vec<Outcome<Vec<crate::processors::Tag>>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let tags = into_contents(outcome)
+                        .into_iter()
+                        .map(|tag| Tag {
+                            kind: tag.kind.to_string(),
+                            name: tag.name,
+                            is_definition: tag.is_definition,
+                            start: tag.start as u64,
+                            end: tag.end as u64,
+                            name_start: tag.name_start as u64,
+                            name_end: tag.name_end as u64,
+                            start_line: tag.start_line as u32,
+                            end_line: tag.end_line as u32,
+                            docs: tag.docs,
+                        })
+                        .collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        tags,
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod folds {
+    use super::*;
+
+    /// A request for the folding ranges of some files.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::folds::Request::create(
+                &mut builder,
+                &fb::folds::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing the folding ranges of each file.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// The folding ranges of a file.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub folds: Vec<Fold>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    /// A region that can be folded away.
+    #[derive(Serialize)]
+    pub struct Fold {
+        pub start_line: u32,
+        pub end_line: u32,
+        pub kind: fb::folds::FoldKind,
+    }
+
+    impl From<Vec<Outcome<Vec<crate::processors::Fold>>>> for Response {
+        fn from(outputs: Vec<Outcome<Vec<crate::processors::Fold>>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let folds = into_contents(outcome)
+                        .into_iter()
+                        .map(|fold| Fold {
+                            start_line: fold.start_line as u32,
+                            end_line: fold.end_line as u32,
+                            kind: fold.kind,
+                        })
+                        .collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        folds,
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod diagnostics {
+    use super::*;
+    use crate::processors::DiagnosticsDocument;
+
+    /// A request for the diagnostics of some files.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::diagnostics::Request::create(
+                &mut builder,
+                &fb::diagnostics::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing the diagnostics of each file.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A place where a file doesn't parse.
+    #[derive(Serialize)]
+    pub struct Diagnostic {
+        pub kind: fb::diagnostics::DiagnosticKind,
+        pub start: u64,
+        pub end: u64,
+        pub start_line: u32,
+        pub start_column: u32,
+        pub end_line: u32,
+        pub end_column: u32,
+        pub expected: Vec<String>,
+    }
+
+    /// The diagnostics of a file.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub diagnostics: Vec<Diagnostic>,
+        pub parse_quality: f32,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<DiagnosticsDocument>>> for Response {
+        fn from(outputs: Vec<Outcome<DiagnosticsDocument>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let document = into_contents(outcome);
+                    let diagnostics = document
+                        .diagnostics
+                        .into_iter()
+                        .map(|diagnostic| Diagnostic {
+                            kind: diagnostic.kind,
+                            start: diagnostic.start as u64,
+                            end: diagnostic.end as u64,
+                            start_line: diagnostic.start_line as u32,
+                            start_column: diagnostic.start_column as u32,
+                            end_line: diagnostic.end_line as u32,
+                            end_column: diagnostic.end_column as u32,
+                            expected: diagnostic.expected.into_iter().map(String::from).collect(),
+                        })
+                        .collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        diagnostics,
+                        parse_quality: document.parse_quality,
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod references {
+    use super::*;
+    use crate::processors::ScopeGraph;
+
+    /// A request for the scope graphs of some files.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::references::Request::create(
+                &mut builder,
+                &fb::references::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing the scope graph of each file.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A region that definitions are local to. `parent` is -1 for the whole file.
+    #[derive(Serialize)]
+    pub struct Scope {
+        pub start: u64,
+        pub end: u64,
+        pub start_line: u32,
+        pub end_line: u32,
+        pub parent: i32,
+        pub isolated: bool,
+    }
+
+    /// Where a variable is defined, and the index of its scope.
+    #[derive(Serialize)]
+    pub struct Definition {
+        pub name: String,
+        pub kind: String,
+        pub start: u64,
+        pub end: u64,
+        pub line: u32,
+        pub column: u32,
+        pub scope: u32,
+    }
+
+    /// A use of a variable, and the index of its definition, or -1 if it wasn't found.
+    #[derive(Serialize)]
+    pub struct Reference {
+        pub name: String,
+        pub start: u64,
+        pub end: u64,
+        pub line: u32,
+        pub column: u32,
+        pub definition: i32,
+    }
+
+    /// The scope graph of a file.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub scopes: Vec<Scope>,
+        pub definitions: Vec<Definition>,
+        pub references: Vec<Reference>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<ScopeGraph>>> for Response {
+        fn from(outputs: Vec<Outcome<ScopeGraph>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let graph = into_contents(outcome);
+                    let scopes = graph
+                        .scopes
+                        .into_iter()
+                        .map(|scope| Scope {
+                            start: scope.start as u64,
+                            end: scope.end as u64,
+                            start_line: scope.start_line as u32,
+                            end_line: scope.end_line as u32,
+                            parent: scope.parent.map_or(-1, |i| i as i32),
+                            isolated: scope.isolated,
+                        })
+                        .collect();
+                    let definitions = graph
+                        .definitions
+                        .into_iter()
+                        .map(|definition| Definition {
+                            name: definition.name,
+                            kind: definition.kind.to_string(),
+                            start: definition.start as u64,
+                            end: definition.end as u64,
+                            line: definition.line as u32,
+                            column: definition.column as u32,
+                            scope: definition.scope as u32,
+                        })
+                        .collect();
+                    let references = graph
+                        .references
+                        .into_iter()
+                        .map(|reference| Reference {
+                            name: reference.name,
+                            start: reference.start as u64,
+                            end: reference.end as u64,
+                            line: reference.line as u32,
+                            column: reference.column as u32,
+                            definition: reference.definition.map_or(-1, |i| i as i32),
+                        })
+                        .collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        scopes,
+                        definitions,
+                        references,
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod tree {
+    use super::*;
+    use crate::processors::TreeDocument;
+
+    /// A request for the syntax trees of some files.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub format: fb::tree::TreeFormat,
+        pub max_depth: u32,
+        pub byte_start: u64,
+        pub byte_end: u64,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let request = fb::tree::Request::create(
+                &mut builder,
+                &fb::tree::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    format: self.format,
+                    max_depth: self.max_depth,
+                    byte_start: self.byte_start,
+                    byte_end: self.byte_end,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing the syntax tree of each file.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+    }
+
+    /// A node of a syntax tree. `parent` is -1 for the root.
+    #[derive(Serialize)]
+    pub struct Node {
+        pub kind: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub field_name: Option<String>,
+        pub is_named: bool,
+        pub start: u64,
+        pub end: u64,
+        pub start_line: u32,
+        pub start_column: u32,
+        pub end_line: u32,
+        pub end_column: u32,
+        pub parent: i32,
+    }
+
+    /// The syntax tree of a file.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub nodes: Vec<Node>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub sexp: Option<String>,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<Vec<Outcome<TreeDocument>>> for Response {
+        fn from(outputs: Vec<Outcome<TreeDocument>>) -> Self {
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, error_code) = document_header(&outcome);
+                    let document = into_contents(outcome);
+                    let nodes = document
+                        .nodes
+                        .into_iter()
+                        .map(|node| Node {
+                            kind: node.kind.to_string(),
+                            field_name: node.field_name.map(String::from),
+                            is_named: node.is_named,
+                            start: node.start as u64,
+                            end: node.end as u64,
+                            start_line: node.start_line as u32,
+                            start_column: node.start_column as u32,
+                            end_line: node.end_line as u32,
+                            end_column: node.end_column as u32,
+                            parent: node.parent.map_or(-1, |i| i as i32),
+                        })
+                        .collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        nodes,
+                        sexp: document.sexp,
+                        error_code,
+                    }
+                })
+                .collect();
+            Self { documents }
+        }
+    }
+}
+
+pub mod query {
+    use super::*;
+    use crate::processors::{QueryDocument, query_error_code, query_errors};
+
+    /// A query to run over the files in one language.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct LanguageQuery {
+        pub language: fb::common::Language,
+        pub query: String,
+    }
+
+    /// A request to run a tree-sitter query over some files.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Request {
+        pub files: Vec<common::File>,
+        pub timeout_ms: u64,
+        pub query: Option<String>,
+        pub language_queries: Vec<LanguageQuery>,
+    }
+
+    impl super::Request for Request {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let files = Some(common::create_files(&mut builder, &self.files));
+            let query = self.query.as_deref().map(|q| builder.create_string(q));
+            let language_queries: Vec<_> = self
+                .language_queries
+                .iter()
+                .map(|q| {
+                    let query = Some(builder.create_string(&q.query));
+                    fb::query::LanguageQuery::create(
+                        &mut builder,
+                        &fb::query::LanguageQueryArgs {
+                            language: q.language,
+                            query,
+                        },
+                    )
+                })
+                .collect();
+            let language_queries = Some(builder.create_vector(&language_queries));
+            let request = fb::query::Request::create(
+                &mut builder,
+                &fb::query::RequestArgs {
+                    files,
+                    timeout_ms: self.timeout_ms,
+                    query,
+                    language_queries,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A response containing each file's matches, and why any language's query didn't compile.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub documents: Vec<Document>,
+        pub query_errors: Vec<QueryError>,
+    }
+
+    /// A node a pattern captured.
+    #[derive(Serialize)]
+    pub struct Capture {
+        pub name: String,
+        pub start: u64,
+        pub end: u64,
+        pub start_line: u32,
+        pub start_column: u32,
+        pub end_line: u32,
+        pub end_column: u32,
+        pub text: String,
+    }
+
+    /// A match of one of the query's patterns.
+    #[derive(Serialize)]
+    pub struct Match {
+        pub pattern: u32,
+        pub captures: Vec<Capture>,
+    }
+
+    /// Why a language's query didn't compile.
+    #[derive(Serialize)]
+    pub struct QueryError {
+        pub language: fb::common::Language,
+        pub kind: fb::query::QueryErrorKind,
+        pub message: String,
+        pub row: u32,
+        pub column: u32,
+        pub offset: u64,
+    }
+
+    /// The matches in a file.
+    #[derive(Serialize)]
+    pub struct Document {
+        pub ident: u16,
+        pub filename: String,
+        pub language: fb::common::Language,
+        pub matches: Vec<Match>,
+        pub error_code: fb::common::ErrorCode,
+        pub truncated: bool,
+    }
+
+    impl From<Vec<Outcome<QueryDocument>>> for Response {
+        fn from(outputs: Vec<Outcome<QueryDocument>>) -> Self {
+            let query_errors = query_errors(&outputs)
+                .into_iter()
+                .map(|error| QueryError {
+                    language: error.language,
+                    kind: error.kind,
+                    message: error.message,
+                    row: error.row as u32,
+                    column: error.column as u32,
+                    offset: error.offset as u64,
+                })
+                .collect();
+            let documents = outputs
+                .into_iter()
+                .map(|outcome| {
+                    let (ident, filename, language, _) = document_header(&outcome);
+                    let error_code = query_error_code(&outcome);
+                    let document = into_contents(outcome);
+                    let truncated = document.truncated;
+                    let matches = document
+                        .matches
+                        .into_iter()
+                        .map(|m| Match {
+                            pattern: m.pattern as u32,
+                            captures: m
+                                .captures
+                                .into_iter()
+                                .map(|capture| Capture {
+                                    name: capture.name,
+                                    start: capture.start as u64,
+                                    end: capture.end as u64,
+                                    start_line: capture.start_line as u32,
+                                    start_column: capture.start_column as u32,
+                                    end_line: capture.end_line as u32,
+                                    end_column: capture.end_column as u32,
+                                    text: capture.text,
+                                })
+                                .collect(),
+                        })
+                        .collect();
+                    Document {
+                        ident,
+                        filename,
+                        language,
+                        matches,
+                        error_code,
+                        truncated,
+                    }
+                })
+                .collect();
+            Self { documents, query_errors }
+        }
+    }
+}
+
+pub mod session {
+    use super::*;
+    use crate::sessions::SessionUpdate;
+
+    /// A request to open an editing session on a document.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct OpenRequest {
+        pub file: common::File,
+        pub timeout_ms: u64,
+    }
+
+    impl super::Request for OpenRequest {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let file = Some(common::create_file(&mut builder, &self.file));
+            let request = fb::session::OpenRequest::create(
+                &mut builder,
+                &fb::session::OpenRequestArgs {
+                    file,
+                    timeout_ms: self.timeout_ms,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// An edit, replacing the bytes from `start` to `old_end` with `text`.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct Edit {
+        pub start: u64,
+        pub old_end: u64,
+        pub text: String,
+    }
+
+    /// A request to edit a session's document.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct EditRequest {
+        pub edits: Vec<Edit>,
+        pub timeout_ms: u64,
+    }
+
+    impl super::Request for EditRequest {
+        fn to_flatbuffers(&self) -> Vec<u8> {
+            let mut builder = FlatBufferBuilder::new();
+            let edits: Vec<_> = self
+                .edits
+                .iter()
+                .map(|edit| {
+                    let text = Some(builder.create_string(&edit.text));
+                    fb::session::Edit::create(
+                        &mut builder,
+                        &fb::session::EditArgs {
+                            start: edit.start,
+                            old_end: edit.old_end,
+                            text,
+                        },
+                    )
+                })
+                .collect();
+            let edits = Some(builder.create_vector(&edits));
+            let request = fb::session::EditRequest::create(
+                &mut builder,
+                &fb::session::EditRequestArgs {
+                    edits,
+                    timeout_ms: self.timeout_ms,
+                },
+            );
+            builder.finish(request, None);
+            builder.finished_data().to_vec()
+        }
+    }
+
+    /// A highlighted range within a line.
+    #[derive(Serialize)]
+    pub struct Span {
+        pub index: u16,
+        pub start: u32,
+        pub end: u32,
+    }
+
+    /// The highlights of a line.
+    #[derive(Serialize)]
+    pub struct Line {
+        pub spans: Vec<Span>,
+    }
+
+    /// Lines that replaced others.
+    #[derive(Serialize)]
+    pub struct Change {
+        pub start_line: u32,
+        pub old_end_line: u32,
+        pub lines: Vec<Line>,
+    }
+
+    /// A response to opening or editing a session.
+    #[derive(Serialize)]
+    pub struct Response {
+        pub session_id: String,
+        pub language: fb::common::Language,
+        pub changes: Vec<Change>,
+        pub line_count: u32,
+        pub error_code: fb::common::ErrorCode,
+    }
+
+    impl From<SessionUpdate> for Response {
+        fn from(update: SessionUpdate) -> Self {
+            let changes = update
+                .changes
+                .into_iter()
+                .map(|change| Change {
+                    start_line: change.start_line as u32,
+                    old_end_line: change.old_end_line as u32,
+                    lines: change
+                        .lines
+                        .into_iter()
+                        .map(|line| Line {
+                            spans: line
+                                .into_iter()
+                                .map(|span| Span {
+                                    index: span.index as u16,
+                                    start: span.start as u32,
+                                    end: span.end as u32,
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                })
+                .collect();
+            Response {
+                session_id: update.session_id,
+                language: update.language,
+                changes,
+                line_count: update.line_count as u32,
+                error_code: update.error_code,
+            }
+        }
+    }
+}
+
+pub mod ws {
+    use super::*;
+
+    /// A message from a client on the WebSocket. `request` is the endpoint's request, as it
+    /// would be POSTed.
+    #[derive(Default, Deserialize)]
+    #[serde(default)]
+    pub struct ClientMessage {
+        pub id: u64,
+        pub endpoint: String,
+        pub request: serde_json::Value,
+        pub cancel: bool,
+    }
+
+    /// A message from the server about a request.
+    #[derive(Serialize)]
+    pub struct ServerMessage {
+        pub id: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub response: Option<serde_json::Value>,
+        pub done: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub error: Option<String>,
+    }
+}
+
+pub mod stream {
+    use super::*;
+
+    /// How a streamed batch went.
+    #[derive(Default, Serialize)]
+    pub struct Trailer {
+        pub documents: u32,
+        pub succeeded: u32,
+        pub failed: u32,
+        pub timed_out: u32,
+    }
+
+    /// A frame of a streamed batch response: a response with a single document, or the trailer.
+    #[derive(Serialize)]
+    pub struct Frame {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub response: Option<serde_json::Value>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub trailer: Option<Trailer>,
+    }
+}
@@ -0,0 +1,18 @@
+[
+  (module)
+  (data)
+  (record)
+  (function)
+  (postulate)
+  (mutual)
+  (abstract)
+  (private)
+  (instance)
+] @fold
+
+(comment) @fold.comment
+
+[
+  (import)
+  (open)
+] @fold.imports
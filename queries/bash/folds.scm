@@ -0,0 +1,15 @@
+[
+  (function_definition)
+  (compound_statement)
+  (if_statement)
+  (case_statement)
+  (case_item)
+  (for_statement)
+  (c_style_for_statement)
+  (while_statement)
+  (subshell)
+  (heredoc_body)
+  (array)
+] @fold
+
+(comment) @fold.comment
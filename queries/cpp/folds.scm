@@ -0,0 +1,26 @@
+[
+  (function_definition)
+  (compound_statement)
+  (field_declaration_list)
+  (enumerator_list)
+  (initializer_list)
+  (argument_list)
+  (parameter_list)
+  (case_statement)
+  (preproc_if)
+  (preproc_ifdef)
+  (preproc_elif)
+  (preproc_else)
+  (preproc_function_def)
+  (namespace_definition)
+  (declaration_list)
+  (template_declaration)
+  (lambda_expression)
+] @fold
+
+(comment) @fold.comment
+
+[
+  (preproc_include)
+  (using_declaration)
+] @fold.imports
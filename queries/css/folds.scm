@@ -0,0 +1,13 @@
+[
+  (rule_set)
+  (media_statement)
+  (supports_statement)
+  (keyframes_statement)
+  (at_rule)
+  (block)
+  (keyframe_block_list)
+] @fold
+
+(comment) @fold.comment
+
+(import_statement) @fold.imports
@@ -0,0 +1,4 @@
+[
+  (block)
+  (hunk)
+] @fold
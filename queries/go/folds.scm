@@ -0,0 +1,25 @@
+[
+  (function_declaration)
+  (method_declaration)
+  (func_literal)
+  (type_declaration)
+  (const_declaration)
+  (var_declaration)
+  (block)
+  (field_declaration_list)
+  (interface_type)
+  (literal_value)
+  (argument_list)
+  (parameter_list)
+  (expression_switch_statement)
+  (type_switch_statement)
+  (select_statement)
+  (expression_case)
+  (type_case)
+  (communication_case)
+  (default_case)
+] @fold
+
+(comment) @fold.comment
+
+(import_declaration) @fold.imports
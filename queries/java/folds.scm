@@ -0,0 +1,27 @@
+[
+  (class_declaration)
+  (interface_declaration)
+  (enum_declaration)
+  (record_declaration)
+  (annotation_type_declaration)
+  (method_declaration)
+  (constructor_declaration)
+  (class_body)
+  (interface_body)
+  (enum_body)
+  (block)
+  (constructor_body)
+  (switch_block)
+  (switch_block_statement_group)
+  (lambda_expression)
+  (array_initializer)
+  (argument_list)
+  (formal_parameters)
+] @fold
+
+[
+  (line_comment)
+  (block_comment)
+] @fold.comment
+
+(import_declaration) @fold.imports
@@ -0,0 +1,22 @@
+[
+  (function_declaration)
+  (function_expression)
+  (generator_function_declaration)
+  (arrow_function)
+  (method_definition)
+  (class_declaration)
+  (class_body)
+  (statement_block)
+  (object)
+  (object_pattern)
+  (array)
+  (arguments)
+  (formal_parameters)
+  (switch_body)
+  (switch_case)
+  (template_string)
+] @fold
+
+(comment) @fold.comment
+
+(import_statement) @fold.imports
@@ -0,0 +1,5 @@
+[
+  (jsx_element)
+  (jsx_self_closing_element)
+  (jsx_expression)
+] @fold
@@ -0,0 +1,9 @@
+[
+  (section)
+  (fenced_code_block)
+  (indented_code_block)
+  (list_item)
+  (block_quote)
+  (html_block)
+  (pipe_table)
+] @fold
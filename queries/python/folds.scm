@@ -0,0 +1,31 @@
+[
+  (function_definition)
+  (class_definition)
+  (decorated_definition)
+  (if_statement)
+  (elif_clause)
+  (else_clause)
+  (for_statement)
+  (while_statement)
+  (try_statement)
+  (except_clause)
+  (finally_clause)
+  (with_statement)
+  (match_statement)
+  (case_clause)
+  (dictionary)
+  (list)
+  (tuple)
+  (set)
+  (argument_list)
+  (parameters)
+  (string)
+] @fold
+
+(comment) @fold.comment
+
+[
+  (import_statement)
+  (import_from_statement)
+  (future_import_statement)
+] @fold.imports
@@ -0,0 +1,27 @@
+[
+  (method)
+  (singleton_method)
+  (class)
+  (singleton_class)
+  (module)
+  (do_block)
+  (block)
+  (if)
+  (unless)
+  (case)
+  (when)
+  (while)
+  (until)
+  (for)
+  (begin)
+  (hash)
+  (array)
+  (argument_list)
+  (heredoc_body)
+] @fold
+
+(comment) @fold.comment
+
+((call
+  method: (identifier) @_method) @fold.imports
+  (#any-of? @_method "require" "require_relative" "load"))
@@ -0,0 +1,34 @@
+[
+  (mod_item)
+  (foreign_mod_item)
+  (function_item)
+  (impl_item)
+  (trait_item)
+  (struct_item)
+  (enum_item)
+  (union_item)
+  (macro_definition)
+  (macro_invocation)
+  (block)
+  (match_block)
+  (match_arm)
+  (closure_expression)
+  (field_declaration_list)
+  (enum_variant_list)
+  (declaration_list)
+  (field_initializer_list)
+  (array_expression)
+  (arguments)
+  (parameters)
+  (token_tree)
+] @fold
+
+[
+  (line_comment)
+  (block_comment)
+] @fold.comment
+
+[
+  (use_declaration)
+  (extern_crate_declaration)
+] @fold.imports
@@ -0,0 +1,11 @@
+[
+  (abstract_class_declaration)
+  (interface_declaration)
+  (interface_body)
+  (enum_declaration)
+  (enum_body)
+  (internal_module)
+  (module)
+  (type_alias_declaration)
+  (object_type)
+] @fold